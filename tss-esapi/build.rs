@@ -44,5 +44,30 @@ fn main() {
         if has_esys_tr_get_tpm_handle_req.matches(&tss_version) {
             println!("cargo:rustc-cfg=has_esys_tr_get_tpm_handle")
         }
+
+        let has_esys_certify_x509_req = VersionReq::parse(">=3.0.0").unwrap();
+        if has_esys_certify_x509_req.matches(&tss_version) {
+            println!("cargo:rustc-cfg=has_esys_certify_x509")
+        }
+
+        let has_esys_act_set_timeout_req = VersionReq::parse(">=3.0.0").unwrap();
+        if has_esys_act_set_timeout_req.matches(&tss_version) {
+            println!("cargo:rustc-cfg=has_esys_act_set_timeout")
+        }
+
+        let has_esys_attached_components_req = VersionReq::parse(">=3.0.0").unwrap();
+        if has_esys_attached_components_req.matches(&tss_version) {
+            println!("cargo:rustc-cfg=has_esys_attached_components")
+        }
+
+        let has_tpms_act_data_req = VersionReq::parse(">=3.0.0").unwrap();
+        if has_tpms_act_data_req.matches(&tss_version) {
+            println!("cargo:rustc-cfg=has_tpms_act_data")
+        }
+
+        let has_tpms_tagged_policy_req = VersionReq::parse(">=3.0.0").unwrap();
+        if has_tpms_tagged_policy_req.matches(&tss_version) {
+            println!("cargo:rustc-cfg=has_tpms_tagged_policy")
+        }
     }
 }
@@ -1,14 +1,20 @@
 // Copyright 2019 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
-    constants::AlgorithmIdentifier,
+    constants::{AlgorithmIdentifier, CapabilityType, CommandCode},
+    handles::KeyHandle,
     interface_types::{
         algorithm::{HashingAlgorithm, SymmetricAlgorithm, SymmetricMode, SymmetricObject},
         key_bits::{AesKeyBits, CamelliaKeyBits, Sm4KeyBits},
     },
-    structures::{SymmetricCipherParameters, SymmetricDefinition, SymmetricDefinitionObject},
-    Error, Result, WrapperErrorKind,
+    structures::{
+        CapabilityData, InitialValue, MaxBuffer, SymmetricCipherParameters, SymmetricDefinition,
+        SymmetricDefinitionObject,
+    },
+    tss2_esys::TPM2_CC,
+    Context, Error, Result, WrapperErrorKind,
 };
+use log::error;
 use std::convert::{TryFrom, TryInto};
 /// Block cipher identifiers
 ///
@@ -239,3 +245,60 @@ impl TryFrom<Cipher> for SymmetricCipherParameters {
         Ok(SymmetricCipherParameters::new(cipher.try_into()?))
     }
 }
+
+/// Performs symmetric encryption or decryption of the data using the
+/// key associated with the `key_handle`.
+///
+/// # Details
+/// The TPM is asked if it implements TPM2_EncryptDecrypt2, in which case
+/// [Context::encrypt_decrypt_2] is used. Otherwise the deprecated
+/// [Context::encrypt_decrypt] is used.
+///
+/// Please beware that this function requires an authorization
+/// session handle to be present.
+///
+/// # Arguments
+/// * `context` - The [Context] that is used to communicate with the TPM.
+/// * `key_handle` -  A [KeyHandle] to the key to be used.
+/// * `decrypt` - A boolean indicating if the data should be decrypted or encrypted.
+///   If set to true the data will be decrypted else encrypted.
+/// * `mode` - The [SymmetricMode] to be used.
+/// * `in_data` - The data that is going to be decrypted or encrypted.
+/// * `initial_value_in` - An initial value as required by the algorithm.
+pub fn encrypt_decrypt(
+    context: &mut Context,
+    key_handle: KeyHandle,
+    decrypt: bool,
+    mode: SymmetricMode,
+    in_data: MaxBuffer,
+    initial_value_in: InitialValue,
+) -> Result<(MaxBuffer, InitialValue)> {
+    // The sessions are cleared while querying the capability
+    // because TPM2_GetCapability does not accept an authorization session.
+    if context
+        .execute_without_session(|ctx| is_command_supported(ctx, CommandCode::EncryptDecrypt2))?
+    {
+        context.encrypt_decrypt_2(key_handle, decrypt, mode, in_data, initial_value_in)
+    } else {
+        context.encrypt_decrypt(key_handle, decrypt, mode, in_data, initial_value_in)
+    }
+}
+
+/// Checks if the TPM implements the command.
+fn is_command_supported(context: &mut Context, command_code: CommandCode) -> Result<bool> {
+    let tpm2_cc = TPM2_CC::from(command_code);
+    match context.get_capability(CapabilityType::Command, tpm2_cc, 1)? {
+        (CapabilityData::Commands(command_code_attributes_list), _) => {
+            Ok(command_code_attributes_list
+                .find((tpm2_cc & 0xFFFF) as u16)
+                .is_some())
+        }
+        (capability_data, _) => {
+            error!(
+                "Unexpected capability data returned from the TPM: {:?}",
+                capability_data
+            );
+            Err(Error::local_error(WrapperErrorKind::WrongValueFromTpm))
+        }
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::structures::ClockInfo;
+
+/// The events that have happened between two [ClockInfo] snapshots.
+///
+/// # Details
+/// The snapshots can be obtained from [crate::Context::read_clock] or
+/// from the attestation structures returned by the TPM, e.g. from a quote.
+/// This makes it possible to detect if the TPM has been reset or restarted
+/// between two quotes.
+///
+/// # Example
+///
+/// ```rust
+/// # use tss_esapi::{Context, TctiNameConf};
+/// # // Create context
+/// # let mut context =
+/// #     Context::new(
+/// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+/// #     ).expect("Failed to create Context");
+/// use tss_esapi::abstraction::clock::ClockEvents;
+///
+/// let before = *context.read_clock().expect("Failed to read clock").clock_info();
+/// let after = *context.read_clock().expect("Failed to read clock").clock_info();
+/// let events = ClockEvents::between(&before, &after);
+/// assert!(!events.tpm_reset());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClockEvents {
+    tpm_reset: bool,
+    tpm_restart: bool,
+    clock_rollback: bool,
+}
+
+impl ClockEvents {
+    /// Compares two [ClockInfo] snapshots.
+    ///
+    /// # Arguments
+    /// * `earlier` - The snapshot that was taken first.
+    /// * `later` - The snapshot that was taken last.
+    pub fn between(earlier: &ClockInfo, later: &ClockInfo) -> Self {
+        // A TPM Reset changes the reset count and sets the restart count
+        // to zero so a restart is only reported if no reset happened.
+        let tpm_reset = earlier.reset_count() != later.reset_count();
+        ClockEvents {
+            tpm_reset,
+            tpm_restart: !tpm_reset && earlier.restart_count() != later.restart_count(),
+            clock_rollback: later.clock() < earlier.clock(),
+        }
+    }
+
+    /// Indicates if a TPM Reset happened between the snapshots.
+    pub fn tpm_reset(&self) -> bool {
+        self.tpm_reset
+    }
+
+    /// Indicates if a TPM Restart or TPM Resume happened between the snapshots
+    /// without any TPM Reset.
+    pub fn tpm_restart(&self) -> bool {
+        self.tpm_restart
+    }
+
+    /// Indicates if the clock value of the later snapshot is lower than
+    /// the clock value of the earlier one.
+    ///
+    /// # Details
+    /// This can happen if the TPM was not shut down in an orderly manner,
+    /// in which case the clock value might not have been saved.
+    pub fn clock_rollback(&self) -> bool {
+        self.clock_rollback
+    }
+
+    /// Indicates if any event happened between the snapshots.
+    pub fn any(&self) -> bool {
+        self.tpm_reset || self.tpm_restart || self.clock_rollback
+    }
+}
@@ -0,0 +1,320 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::convert::TryFrom;
+
+use log::error;
+
+use super::software_hash::SoftwareHashingAlgorithm;
+use crate::{
+    constants::CommandCode,
+    handles::{KeyHandle, ObjectHandle},
+    interface_types::algorithm::HashingAlgorithm,
+    structures::{Attest, AttestInfo, CommandAuditInfo, Digest, Name, Signature},
+    traits::Marshall,
+    tss2_esys::TPM2_CC,
+    Context, Error, Result, WrapperErrorKind,
+};
+
+/// A command that has been recorded by a [CommandAuditLog].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditedCommand {
+    command_code: CommandCode,
+    names: Vec<Name>,
+    parameters: Vec<u8>,
+    response_parameters: Vec<u8>,
+}
+
+impl AuditedCommand {
+    /// Returns the command code of the command.
+    pub const fn command_code(&self) -> CommandCode {
+        self.command_code
+    }
+
+    /// Returns the names of the handles used by the command.
+    pub fn names(&self) -> &[Name] {
+        &self.names
+    }
+
+    /// Returns the marshalled command parameters.
+    pub fn parameters(&self) -> &[u8] {
+        &self.parameters
+    }
+
+    /// Returns the marshalled response parameters.
+    pub fn response_parameters(&self) -> &[u8] {
+        &self.response_parameters
+    }
+
+    /// Computes the command parameter hash (cpHash) of the command.
+    ///
+    /// # Details
+    /// cpHash := H(commandCode || names || parameters)
+    ///
+    /// # Errors
+    /// * if the hash algorithm can not be computed in software, an
+    ///   `UnsupportedParam` wrapper error is returned.
+    pub fn cp_hash(&self, hashing_algorithm: HashingAlgorithm) -> Result<Digest> {
+        let command_code = TPM2_CC::from(self.command_code).to_be_bytes();
+        let mut data: Vec<&[u8]> = vec![&command_code];
+        data.extend(self.names.iter().map(|name| name.value()));
+        data.push(&self.parameters);
+        Digest::try_from(SoftwareHashingAlgorithm::try_from(hashing_algorithm)?.hash(&data))
+    }
+
+    /// Computes the response parameter hash (rpHash) of the command.
+    ///
+    /// # Details
+    /// rpHash := H(responseCode || commandCode || parameters)
+    ///
+    /// Only successful commands are audited so the response code
+    /// is always TPM2_RC_SUCCESS.
+    ///
+    /// # Errors
+    /// * if the hash algorithm can not be computed in software, an
+    ///   `UnsupportedParam` wrapper error is returned.
+    pub fn rp_hash(&self, hashing_algorithm: HashingAlgorithm) -> Result<Digest> {
+        Digest::try_from(
+            SoftwareHashingAlgorithm::try_from(hashing_algorithm)?.hash(&[
+                &0u32.to_be_bytes(),
+                &TPM2_CC::from(self.command_code).to_be_bytes(),
+                &self.response_parameters,
+            ]),
+        )
+    }
+}
+
+/// Log of audited commands that can be used to verify the
+/// command audit digest of the TPM.
+///
+/// # Details
+/// The TPM extends the audit digest for every audited command
+/// that completes successfully:
+///
+/// auditDigest := H(auditDigest || cpHash || rpHash)
+///
+/// The log records the data needed to compute the cpHash and the rpHash
+/// of the commands that are executed through [CommandAuditLog::execute] and
+/// can then recompute the expected audit digest and check it against the
+/// [CommandAuditInfo] signed by the TPM in [Context::get_command_audit_digest].
+///
+/// The log is expected to be started when the audit digest of the TPM is
+/// empty, i.e. after the digest has been reset by a call to
+/// [Context::get_command_audit_digest] with a signing key, and it has to contain
+/// all the audited commands that have been executed since then. The hashes
+/// are computed in software, so the [HashingAlgorithm::Sm3_256] algorithm
+/// is not supported.
+///
+/// Commands that use sessions which encrypt the response parameters are not
+/// supported because the response parameters are recorded after they have been
+/// decrypted.
+///
+/// # Example
+///
+/// ```rust
+/// # use tss_esapi::{Context, TctiNameConf};
+/// # // Create context
+/// # let mut context =
+/// #     Context::new(
+/// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+/// #     ).expect("Failed to create Context");
+/// use tss_esapi::{
+///     abstraction::command_audit::CommandAuditLog, constants::CommandCode,
+///     interface_types::algorithm::HashingAlgorithm,
+/// };
+///
+/// let mut audit_log = CommandAuditLog::new(HashingAlgorithm::Sha256);
+/// let bytes_requested: u16 = 16;
+/// let random = audit_log
+///     .execute(
+///         &mut context,
+///         CommandCode::GetRandom,
+///         &[],
+///         &bytes_requested.to_be_bytes(),
+///         |ctx| ctx.get_random(bytes_requested.into()),
+///     )
+///     .expect("Failed to get random bytes");
+/// assert_eq!(audit_log.commands().len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CommandAuditLog {
+    hashing_algorithm: HashingAlgorithm,
+    commands: Vec<AuditedCommand>,
+}
+
+impl CommandAuditLog {
+    /// Creates an empty log.
+    ///
+    /// # Arguments
+    /// * `hashing_algorithm` - The hash algorithm used by the TPM for the
+    ///   command audit digest.
+    pub fn new(hashing_algorithm: HashingAlgorithm) -> Self {
+        CommandAuditLog {
+            hashing_algorithm,
+            commands: Vec::new(),
+        }
+    }
+
+    /// Returns the hash algorithm used for the audit digest.
+    pub const fn hashing_algorithm(&self) -> HashingAlgorithm {
+        self.hashing_algorithm
+    }
+
+    /// Returns the recorded commands.
+    pub fn commands(&self) -> &[AuditedCommand] {
+        &self.commands
+    }
+
+    /// Removes all the recorded commands.
+    ///
+    /// # Details
+    /// This should be called when the audit digest of the TPM is reset.
+    pub fn clear(&mut self) {
+        self.commands.clear();
+    }
+
+    /// Executes an audited command and records it in the log.
+    ///
+    /// # Arguments
+    /// * `context` - The [Context] that is used to execute the command.
+    /// * `command_code` - The [CommandCode] of the command.
+    /// * `handles` - The handles in the handle area of the command.
+    /// * `parameters` - The marshalled parameters of the command.
+    /// * `f` - A closure that sends exactly one command, the one described by
+    ///   the previous arguments, to the TPM.
+    ///
+    /// # Details
+    /// The names of the handles are read before the command is executed and the
+    /// response parameters are read directly after `f` has returned. The command
+    /// is only recorded if `f` succeeds.
+    ///
+    /// The parameters can not be read back once the command has been sent, so
+    /// only the command code of the executed command is checked against
+    /// `command_code`.
+    ///
+    /// # Errors
+    /// * if the last command sent by `f` is not the command with the code
+    ///   `command_code`, an `InvalidParam` wrapper error is returned and the
+    ///   command is not recorded.
+    pub fn execute<F, T>(
+        &mut self,
+        context: &mut Context,
+        command_code: CommandCode,
+        handles: &[ObjectHandle],
+        parameters: &[u8],
+        f: F,
+    ) -> Result<T>
+    where
+        F: FnOnce(&mut Context) -> Result<T>,
+    {
+        let names = handles
+            .iter()
+            .map(|handle| context.tr_get_name(*handle))
+            .collect::<Result<Vec<Name>>>()?;
+        let result = f(context)?;
+        let response_parameters = context.last_response_parameters()?;
+        let executed_command_code = context.last_command_code()?;
+        if executed_command_code != TPM2_CC::from(command_code) {
+            error!(
+                "Expected the command {:?} to be executed but the command code was {:#010X}",
+                command_code, executed_command_code
+            );
+            return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+        }
+        self.commands.push(AuditedCommand {
+            command_code,
+            names,
+            parameters: parameters.to_vec(),
+            response_parameters,
+        });
+        Ok(result)
+    }
+
+    /// Computes the audit digest that the TPM is expected to have
+    /// after executing the recorded commands.
+    ///
+    /// # Details
+    /// The digest is empty if no commands have been recorded.
+    ///
+    /// # Errors
+    /// * if the hash algorithm can not be computed in software, an
+    ///   `UnsupportedParam` wrapper error is returned.
+    pub fn expected_audit_digest(&self) -> Result<Digest> {
+        let hashing_algorithm = SoftwareHashingAlgorithm::try_from(self.hashing_algorithm)?;
+        let mut audit_digest: Option<Vec<u8>> = None;
+        for command in &self.commands {
+            let cp_hash = command.cp_hash(self.hashing_algorithm)?;
+            let rp_hash = command.rp_hash(self.hashing_algorithm)?;
+            // The audit digest is initialised to zeros by the first audited command.
+            let previous = audit_digest.unwrap_or_else(|| vec![0; cp_hash.len()]);
+            audit_digest =
+                Some(hashing_algorithm.hash(&[&previous, cp_hash.as_bytes(), rp_hash.as_bytes()]));
+        }
+        Digest::try_from(audit_digest.unwrap_or_default())
+    }
+
+    /// Checks if the audit digest in `command_audit_info` matches the
+    /// recorded commands.
+    pub fn matches(&self, command_audit_info: &CommandAuditInfo) -> Result<bool> {
+        if command_audit_info.hashing_algorithm() != self.hashing_algorithm {
+            return Ok(false);
+        }
+        Ok(
+            self.expected_audit_digest()?.as_bytes()
+                == command_audit_info.audit_digest().as_bytes(),
+        )
+    }
+
+    /// Verifies the signature of a command audit attestation and checks
+    /// that the attested audit digest matches the recorded commands.
+    ///
+    /// # Arguments
+    /// * `context` - The [Context] that is used to verify the signature. The
+    ///   digests are computed in software, only TPM2_VerifySignature is sent
+    ///   to the TPM.
+    /// * `signing_key_handle` - The handle of the key that signed the attestation.
+    /// * `attest` - The attestation returned by [Context::get_command_audit_digest].
+    /// * `signature` - The signature returned by [Context::get_command_audit_digest].
+    ///
+    /// # Errors
+    /// * if the attestation does not contain a [CommandAuditInfo] or if the
+    ///   signature is not an asymmetric signature, an `InvalidParam` wrapper
+    ///   error is returned.
+    /// * if the hash algorithm of the log or of the signature can not be computed
+    ///   in software, an `UnsupportedParam` wrapper error is returned.
+    /// * if the signature is invalid, the TPM error is returned.
+    pub fn verify(
+        &self,
+        context: &mut Context,
+        signing_key_handle: KeyHandle,
+        attest: &Attest,
+        signature: Signature,
+    ) -> Result<bool> {
+        let command_audit_info = match attest.attested() {
+            AttestInfo::CommandAudit { info } => info,
+            _ => {
+                error!("The attestation does not contain command audit information");
+                return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+            }
+        };
+        let signature_hashing_algorithm = match &signature {
+            Signature::RsaSsa(rsa_signature) | Signature::RsaPss(rsa_signature) => {
+                rsa_signature.hashing_algorithm()
+            }
+            Signature::EcDsa(ecc_signature)
+            | Signature::EcDaa(ecc_signature)
+            | Signature::Sm2(ecc_signature)
+            | Signature::EcSchnorr(ecc_signature) => ecc_signature.hashing_algorithm(),
+            _ => {
+                error!("Only asymmetric signatures can be verified");
+                return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+            }
+        };
+        let attest_digest = Digest::try_from(
+            SoftwareHashingAlgorithm::try_from(signature_hashing_algorithm)?
+                .hash(&[&attest.marshall()?]),
+        )?;
+        let _ = context.verify_signature(signing_key_handle, attest_digest, signature)?;
+        self.matches(command_audit_info)
+    }
+}
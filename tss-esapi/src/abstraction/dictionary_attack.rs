@@ -0,0 +1,118 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use log::error;
+
+use crate::{
+    constants::{
+        tss::{TPM2_PT_LOCKOUT_RECOVERY, TPM2_PT_PERMANENT, TPMA_PERMANENT_INLOCKOUT},
+        CapabilityType, PropertyTag,
+    },
+    structures::{CapabilityData, TaggedTpmPropertyList},
+    Context, Error, Result, WrapperErrorKind,
+};
+
+/// A snapshot of the dictionary attack state of the TPM.
+///
+/// # Details
+/// The values correspond to the TPM properties TPM2_PT_LOCKOUT_COUNTER,
+/// TPM2_PT_MAX_AUTH_FAIL, TPM2_PT_LOCKOUT_INTERVAL, TPM2_PT_LOCKOUT_RECOVERY
+/// and to the inLockout attribute of TPM2_PT_PERMANENT.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DaStatus {
+    lockout_counter: u32,
+    max_tries: u32,
+    recovery_time: u32,
+    lockout_recovery: u32,
+    in_lockout: bool,
+}
+
+impl DaStatus {
+    /// Reads the dictionary attack state of the TPM.
+    ///
+    /// # Details
+    /// The properties are read directly from the TPM instead of using
+    /// [Context::get_tpm_property], which caches the values, because
+    /// the dictionary attack state changes over time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use tss_esapi::{Context, TctiNameConf};
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// use tss_esapi::abstraction::dictionary_attack::DaStatus;
+    ///
+    /// let da_status = DaStatus::read(&mut context).expect("Failed to read DA status");
+    /// if da_status.in_lockout() {
+    ///     // A dictionary attack lock reset is needed.
+    /// }
+    /// ```
+    pub fn read(context: &mut Context) -> Result<Self> {
+        let (capability_data, _) = context.execute_without_session(|ctx| {
+            ctx.get_capability(
+                CapabilityType::TpmProperties,
+                TPM2_PT_PERMANENT,
+                TPM2_PT_LOCKOUT_RECOVERY - TPM2_PT_PERMANENT + 1,
+            )
+        })?;
+        let properties = match capability_data {
+            CapabilityData::TpmProperties(properties) => properties,
+            _ => {
+                error!("Unexpected capability data returned from TPM");
+                return Err(Error::local_error(WrapperErrorKind::WrongValueFromTpm));
+            }
+        };
+        Ok(DaStatus {
+            lockout_counter: property_value(&properties, PropertyTag::LockoutCounter)?,
+            max_tries: property_value(&properties, PropertyTag::MaxAuthFail)?,
+            recovery_time: property_value(&properties, PropertyTag::LockoutInterval)?,
+            lockout_recovery: property_value(&properties, PropertyTag::LockoutRecovery)?,
+            in_lockout: property_value(&properties, PropertyTag::Permanent)?
+                & TPMA_PERMANENT_INLOCKOUT
+                != 0,
+        })
+    }
+
+    /// Returns the number of authorization failures that have not
+    /// yet been recovered.
+    pub fn lockout_counter(&self) -> u32 {
+        self.lockout_counter
+    }
+
+    /// Returns the number of authorization failures before the TPM
+    /// enters lockout.
+    pub fn max_tries(&self) -> u32 {
+        self.max_tries
+    }
+
+    /// Returns the time in seconds after which the lockout counter
+    /// is decremented.
+    pub fn recovery_time(&self) -> u32 {
+        self.recovery_time
+    }
+
+    /// Returns the time in seconds after a failed authorization with
+    /// the lockout authorization before it can be used again.
+    pub fn lockout_recovery(&self) -> u32 {
+        self.lockout_recovery
+    }
+
+    /// Indicates if the TPM is in lockout.
+    pub fn in_lockout(&self) -> bool {
+        self.in_lockout
+    }
+}
+
+fn property_value(properties: &TaggedTpmPropertyList, property_tag: PropertyTag) -> Result<u32> {
+    properties
+        .find(property_tag)
+        .map(|tagged_property| tagged_property.value())
+        .ok_or_else(|| {
+            error!("Property {:?} was not returned by the TPM", property_tag);
+            Error::local_error(WrapperErrorKind::WrongValueFromTpm)
+        })
+}
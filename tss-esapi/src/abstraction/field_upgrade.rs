@@ -0,0 +1,179 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{convert::TryFrom, io::Read};
+
+use log::error;
+
+use crate::{
+    abstraction::hash::max_input_buffer_size,
+    handles::KeyHandle,
+    interface_types::resource_handles::Platform,
+    structures::{Digest, HashAgile, MaxBuffer, Signature},
+    Context, Error, Result, WrapperErrorKind,
+};
+
+/// Reads the complete firmware image from the TPM.
+///
+/// # Details
+/// The blocks are read using [Context::firmware_read] with increasing
+/// sequence numbers, starting at zero, until the TPM returns an empty block.
+pub fn read_firmware(context: &mut Context) -> Result<Vec<u8>> {
+    let mut firmware = Vec::new();
+    for sequence_number in 0..=u32::MAX {
+        let fu_data = context.execute_without_session(|ctx| ctx.firmware_read(sequence_number))?;
+        if fu_data.is_empty() {
+            break;
+        }
+        firmware.extend_from_slice(fu_data.as_bytes());
+    }
+    Ok(firmware)
+}
+
+/// Performs a field upgrade of the TPM firmware.
+///
+/// # Details
+/// The field upgrade is started using [Context::field_upgrade_start], which
+/// requires an authorization session for the platform hierarchy to be present.
+/// The image is then read from `firmware` and sent to the TPM using
+/// [Context::field_upgrade_data] in blocks that are as large as the TPM input
+/// buffer allows, until the TPM indicates that the upgrade is complete.
+///
+/// # Arguments
+/// * `context` - The [Context] that is used to communicate with the TPM.
+/// * `authorization` - The [Platform] authorization handle.
+/// * `key_handle` - The handle of the public key used to verify the signature.
+/// * `fu_digest` - The digest of the first block of the field upgrade sequence.
+/// * `manifest_signature` - The signature over `fu_digest`.
+/// * `firmware` - The reader that provides the firmware image.
+///
+/// # Returns
+/// The digest of the first block of the field upgrade sequence.
+///
+/// # Errors
+/// * if reading the firmware image fails or if the image ends before the TPM
+///   indicates that the upgrade is complete, an `InvalidParam` wrapper error
+///   is returned.
+/// * if the TPM does not support field upgrades, the TPM error is returned.
+pub fn upgrade_firmware<R: Read>(
+    context: &mut Context,
+    authorization: Platform,
+    key_handle: KeyHandle,
+    fu_digest: Digest,
+    manifest_signature: Signature,
+    firmware: R,
+) -> Result<HashAgile> {
+    context.field_upgrade_start(authorization, key_handle, fu_digest, manifest_signature)?;
+
+    let block_size = max_input_buffer_size(context)?;
+    send_firmware(firmware, block_size, |fu_data| {
+        context.execute_without_session(|ctx| ctx.field_upgrade_data(fu_data))
+    })
+}
+
+/// Sends the firmware image in blocks of `block_size` bytes using `send_block`
+/// until it returns no next digest, and returns the first digest returned
+/// by the last call.
+///
+/// `send_block` stands in for [Context::field_upgrade_data].
+fn send_firmware<R, F>(mut firmware: R, block_size: usize, mut send_block: F) -> Result<HashAgile>
+where
+    R: Read,
+    F: FnMut(MaxBuffer) -> Result<(Option<HashAgile>, HashAgile)>,
+{
+    let mut next_digest = None;
+    loop {
+        let block = read_block(&mut firmware, block_size)?;
+        if block.is_empty() {
+            error!(
+                "The firmware image ended before the field upgrade was complete (next digest: {:?})",
+                next_digest
+            );
+            return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+        }
+        let (returned_next_digest, first_digest) = send_block(MaxBuffer::try_from(block)?)?;
+        if returned_next_digest.is_none() {
+            return Ok(first_digest);
+        }
+        next_digest = returned_next_digest;
+    }
+}
+
+/// Reads a block of at most `block_size` bytes.
+///
+/// The returned block is only shorter than `block_size`
+/// if the end of the data has been reached.
+fn read_block<R: Read>(reader: &mut R, block_size: usize) -> Result<Vec<u8>> {
+    let mut block = Vec::with_capacity(block_size);
+    let _ = reader
+        .take(block_size as u64)
+        .read_to_end(&mut block)
+        .map_err(|e| {
+            error!("Failed to read the firmware image: {}", e);
+            Error::local_error(WrapperErrorKind::InvalidParam)
+        })?;
+    Ok(block)
+}
+
+// /////////////////////////////////////////////////////////////////////////
+// UNIT TESTS FOR THE FIRMWARE UPGRADE
+//
+// These unit tests needs to be here because the block loop is internal to
+// the crate and the TPM used by the integration tests does not support
+// field upgrades.
+// /////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+fn hash_agile(value: u8) -> HashAgile {
+    HashAgile::new(
+        crate::interface_types::algorithm::HashingAlgorithm::Sha256,
+        Digest::try_from(vec![value; 32]).expect("Failed to create digest"),
+    )
+}
+
+#[test]
+fn test_send_firmware_in_blocks() {
+    let firmware: Vec<u8> = (0..10).collect();
+    let mut blocks = Vec::new();
+    let first_digest = send_firmware(firmware.as_slice(), 4, |fu_data| {
+        blocks.push(fu_data.as_bytes().to_vec());
+        // The TPM expects three blocks.
+        let next_digest = if blocks.len() < 3 {
+            Some(hash_agile(blocks.len() as u8))
+        } else {
+            None
+        };
+        Ok((next_digest, hash_agile(0xff)))
+    })
+    .expect("Failed to send the firmware");
+    assert_eq!(first_digest, hash_agile(0xff));
+    assert_eq!(blocks, [vec![0, 1, 2, 3], vec![4, 5, 6, 7], vec![8, 9]]);
+}
+
+#[test]
+fn test_send_firmware_stops_without_next_digest() {
+    let firmware = vec![0xaa; 10];
+    let mut sent = 0;
+    let _ = send_firmware(firmware.as_slice(), 4, |_| {
+        sent += 1;
+        Ok((None, hash_agile(0xff)))
+    })
+    .expect("Failed to send the firmware");
+    // The rest of the image is not sent once the upgrade is complete.
+    assert_eq!(sent, 1);
+}
+
+#[test]
+fn test_send_firmware_image_ended_early() {
+    // The image ends exactly at a block boundary.
+    let firmware = vec![0xaa; 8];
+    let mut sent = 0;
+    let result = send_firmware(firmware.as_slice(), 4, |_| {
+        sent += 1;
+        Ok((Some(hash_agile(0x01)), hash_agile(0xff)))
+    });
+    assert_eq!(
+        result,
+        Err(Error::local_error(WrapperErrorKind::InvalidParam))
+    );
+    assert_eq!(sent, 2);
+}
@@ -0,0 +1,231 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::convert::TryFrom;
+
+use crate::{
+    constants::PropertyTag,
+    handles::{KeyHandle, ObjectHandle},
+    interface_types::{
+        algorithm::HashingAlgorithm, resource_handles::Hierarchy, session_handles::AuthSession,
+    },
+    structures::{Digest, HashcheckTicket, MaxBuffer},
+    Context, Error, Result, WrapperErrorKind,
+};
+
+/// Get the maximum size of the data buffer that can be sent to the TPM
+/// in a single command.
+pub fn max_input_buffer_size(context: &mut Context) -> Result<usize> {
+    Ok(context
+        .get_tpm_property(PropertyTag::InputBuffer)?
+        .map(usize::try_from)
+        .transpose()
+        .map_err(|_| {
+            log::error!("Failed to obtain valid maximum input buffer size");
+            Error::local_error(WrapperErrorKind::InternalError)
+        })?
+        .map_or(MaxBuffer::MAX_SIZE, |size| {
+            std::cmp::min(size, MaxBuffer::MAX_SIZE)
+        }))
+}
+
+/// Buffers data written to a sequence object and sends it to
+/// the TPM in chunks that fit in a [MaxBuffer].
+///
+/// # Details
+/// The sequence objects are created with an empty auth value so
+/// the updates are authorized using a password session.
+///
+/// If the writer is dropped before being completed the sequence object
+/// is flushed from the TPM.
+#[derive(Debug)]
+pub(crate) struct SequenceWriter<'a> {
+    context: &'a mut Context,
+    sequence_handle: Option<ObjectHandle>,
+    buffer: Vec<u8>,
+    buffer_size: usize,
+}
+
+impl<'a> SequenceWriter<'a> {
+    /// Creates a new writer for the sequence object associated with `sequence_handle`.
+    pub(crate) fn new(context: &'a mut Context, sequence_handle: ObjectHandle) -> Result<Self> {
+        match max_input_buffer_size(context) {
+            Ok(buffer_size) => Ok(SequenceWriter {
+                context,
+                sequence_handle: Some(sequence_handle),
+                buffer: Vec::with_capacity(buffer_size),
+                buffer_size,
+            }),
+            Err(e) => {
+                let _ = context.flush_context(sequence_handle);
+                Err(e)
+            }
+        }
+    }
+
+    /// Completes the sequence by calling `f` with the remaining buffered data.
+    ///
+    /// # Details
+    /// If `f` fails then the sequence object is flushed.
+    pub(crate) fn complete<F, T>(mut self, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut Context, ObjectHandle, MaxBuffer) -> Result<T>,
+    {
+        let sequence_handle = self.sequence_handle()?;
+        self.send_buffered(self.buffer_size)?;
+        let data = MaxBuffer::try_from(std::mem::take(&mut self.buffer))?;
+        let _ = self.sequence_handle.take();
+        f(self.context, sequence_handle, data).map_err(|e| {
+            let _ = self.context.flush_context(sequence_handle);
+            e
+        })
+    }
+
+    /// Returns the handle of the sequence object if the sequence is still active.
+    fn sequence_handle(&self) -> Result<ObjectHandle> {
+        self.sequence_handle.ok_or_else(|| {
+            log::error!("The sequence has already been completed");
+            Error::local_error(WrapperErrorKind::InvalidHandleState)
+        })
+    }
+
+    /// Sends buffered data to the TPM until no more than `keep`
+    /// bytes remain in the buffer.
+    fn send_buffered(&mut self, keep: usize) -> Result<()> {
+        let sequence_handle = self.sequence_handle()?;
+        while self.buffer.len() > keep {
+            let chunk_size = std::cmp::min(self.buffer.len(), self.buffer_size);
+            let data = MaxBuffer::try_from(self.buffer.drain(..chunk_size).collect::<Vec<u8>>())?;
+            self.context
+                .execute_with_session(Some(AuthSession::Password), |ctx| {
+                    ctx.sequence_update(sequence_handle, data)
+                })?;
+        }
+        Ok(())
+    }
+}
+
+impl std::io::Write for SequenceWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        self.send_buffered(self.buffer_size)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.send_buffered(0)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))
+    }
+}
+
+impl Drop for SequenceWriter<'_> {
+    fn drop(&mut self) {
+        if let Some(sequence_handle) = self.sequence_handle.take() {
+            let _ = self.context.flush_context(sequence_handle);
+        }
+    }
+}
+
+/// Hash or HMAC sequence that can be fed arbitrary amounts of data.
+///
+/// # Details
+/// The data that is written to the sequence, using the [std::io::Write]
+/// implementation, is sent to the TPM in chunks through `SequenceUpdate`.
+/// This makes it possible to hash data that does not fit in a single
+/// [MaxBuffer].
+///
+/// # Example
+///
+/// ```rust
+/// # use tss_esapi::{Context, TctiNameConf};
+/// # // Create context
+/// # let mut context =
+/// #     Context::new(
+/// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+/// #     ).expect("Failed to create Context");
+/// use std::io::Write;
+/// use tss_esapi::{
+///     abstraction::hash::HashSequence,
+///     interface_types::{algorithm::HashingAlgorithm, resource_handles::Hierarchy},
+/// };
+///
+/// let data = vec![0xa5; 4096];
+/// let mut hash_sequence =
+///     HashSequence::start(&mut context, HashingAlgorithm::Sha256, Hierarchy::Owner)
+///         .expect("Failed to start hash sequence");
+/// hash_sequence.write_all(&data).expect("Failed to write data to hash sequence");
+/// let (digest, _ticket) = hash_sequence.finish().expect("Failed to finish hash sequence");
+/// assert_eq!(digest.len(), 32);
+/// ```
+#[derive(Debug)]
+pub struct HashSequence<'a> {
+    writer: SequenceWriter<'a>,
+    hierarchy: Hierarchy,
+}
+
+impl<'a> HashSequence<'a> {
+    /// Starts a hash sequence.
+    ///
+    /// # Arguments
+    /// * `context` - The [Context] that is going to be used.
+    /// * `hashing_algorithm` - The [HashingAlgorithm] used to hash the data.
+    /// * `hierarchy` - The [Hierarchy] of the [HashcheckTicket] that is produced
+    ///                 when the sequence is finished.
+    pub fn start(
+        context: &'a mut Context,
+        hashing_algorithm: HashingAlgorithm,
+        hierarchy: Hierarchy,
+    ) -> Result<Self> {
+        let sequence_handle = context.hash_sequence_start(hashing_algorithm, None)?;
+        Ok(HashSequence {
+            writer: SequenceWriter::new(context, sequence_handle)?,
+            hierarchy,
+        })
+    }
+
+    /// Starts an HMAC sequence.
+    ///
+    /// # Arguments
+    /// * `context` - The [Context] that is going to be used. The session
+    ///               that is set in session slot 1 is used to authorize
+    ///               the use of the key.
+    /// * `key_handle` - A [KeyHandle] to the HMAC key.
+    /// * `hashing_algorithm` - The [HashingAlgorithm] used in the HMAC.
+    pub fn start_hmac(
+        context: &'a mut Context,
+        key_handle: KeyHandle,
+        hashing_algorithm: HashingAlgorithm,
+    ) -> Result<Self> {
+        let sequence_handle = context.hmac_start(key_handle.into(), hashing_algorithm, None)?;
+        Ok(HashSequence {
+            writer: SequenceWriter::new(context, sequence_handle)?,
+            hierarchy: Hierarchy::Null,
+        })
+    }
+
+    /// Finishes the sequence.
+    ///
+    /// # Returns
+    /// The resulting [Digest] and a [HashcheckTicket]. The ticket can be used with
+    /// [Context::sign] when signing the digest with a restricted signing key.
+    /// For HMAC sequences the ticket is a NULL ticket.
+    pub fn finish(self) -> Result<(Digest, HashcheckTicket)> {
+        let hierarchy = self.hierarchy;
+        self.writer.complete(|ctx, sequence_handle, data| {
+            ctx.execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.sequence_complete(sequence_handle, data, hierarchy)
+            })
+        })
+    }
+}
+
+impl std::io::Write for HashSequence<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.writer.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
@@ -3,11 +3,20 @@
 
 pub mod ak;
 pub mod cipher;
+pub mod clock;
+pub mod command_audit;
+pub mod dictionary_attack;
 pub mod ek;
+pub mod field_upgrade;
+pub mod hash;
 pub mod nv;
 pub mod pcr;
+pub mod policy_digest;
+pub mod policy_ticket;
 pub mod public;
+mod software_hash;
 pub mod transient;
+pub mod x509;
 
 use std::convert::TryFrom;
 
@@ -7,10 +7,10 @@ use std::{
 };
 
 use crate::{
-    constants::{tss::*, CapabilityType, PropertyTag},
-    handles::{AuthHandle, NvIndexHandle, NvIndexTpmHandle, TpmHandle},
-    interface_types::resource_handles::NvAuth,
-    structures::{CapabilityData, MaxNvBuffer, Name, NvPublic},
+    constants::{tss::*, CapabilityType, CommandCode, NvIndexType, PropertyTag, SessionType},
+    handles::{AuthHandle, NvIndexHandle, NvIndexTpmHandle, SessionHandle, TpmHandle},
+    interface_types::{resource_handles::NvAuth, session_handles::PolicySession},
+    structures::{CapabilityData, Digest, MaxNvBuffer, Name, NvPublic, SymmetricDefinition},
     Context, Error, Result, WrapperErrorKind,
 };
 
@@ -109,49 +109,170 @@ impl NvOpenOptions {
     /// The non-volatile storage index may be used for reading or writing or both.
     pub fn open<'a>(&self, context: &'a mut Context) -> Result<NvReaderWriter<'a>> {
         let buffer_size = max_nv_buffer_size(context)?;
+        let (nv_public, nv_idx, auth_handle) = self.open_index(context, None)?;
 
-        let (data_size, nv_idx, auth_handle) = match self {
+        Ok(NvReaderWriter {
+            context,
+            auth_handle,
+            buffer_size,
+            nv_idx,
+            data_size: nv_public.data_size(),
+            offset: 0,
+        })
+    }
+
+    /// Opens a non-volatile storage counter index using the options specified by `self`
+    ///
+    /// The index must be of the type [NvIndexType::Counter].
+    pub fn open_counter<'a>(&self, context: &'a mut Context) -> Result<NvCounter<'a>> {
+        let (_, nv_idx, auth_handle) = self.open_index(context, Some(NvIndexType::Counter))?;
+        Ok(NvCounter {
+            context,
+            auth_handle,
+            nv_idx,
+        })
+    }
+
+    /// Opens a non-volatile storage bit field index using the options specified by `self`
+    ///
+    /// The index must be of the type [NvIndexType::Bits].
+    pub fn open_bit_field<'a>(&self, context: &'a mut Context) -> Result<NvBitField<'a>> {
+        let (_, nv_idx, auth_handle) = self.open_index(context, Some(NvIndexType::Bits))?;
+        Ok(NvBitField {
+            context,
+            auth_handle,
+            nv_idx,
+        })
+    }
+
+    /// Opens a non-volatile storage extend index using the options specified by `self`
+    ///
+    /// The index must be of the type [NvIndexType::Extend].
+    pub fn open_extend<'a>(&self, context: &'a mut Context) -> Result<NvExtendIndex<'a>> {
+        let buffer_size = max_nv_buffer_size(context)?;
+        let (nv_public, nv_idx, auth_handle) =
+            self.open_index(context, Some(NvIndexType::Extend))?;
+        Ok(NvExtendIndex {
+            context,
+            auth_handle,
+            buffer_size,
+            nv_idx,
+            data_size: nv_public.data_size(),
+        })
+    }
+
+    /// Defines or opens the NV index and returns its public area, its ESYS handle
+    /// and the auth handle that is to be used with it.
+    ///
+    /// If `nv_index_type` is provided then the type of the index is checked
+    /// against it before the index is defined or once it has been opened.
+    fn open_index(
+        &self,
+        context: &mut Context,
+        nv_index_type: Option<NvIndexType>,
+    ) -> Result<(NvPublic, NvIndexHandle, NvAuth)> {
+        match self {
             NvOpenOptions::ExistingIndex {
                 nv_index_handle,
                 auth_handle,
             } => {
                 let nv_idx = TpmHandle::NvIndex(*nv_index_handle);
-                let nv_idx = context
+                let nv_idx: NvIndexHandle = context
                     .execute_without_session(|ctx| ctx.tr_from_tpm_public(nv_idx))?
                     .into();
-                (
-                    context
-                        .execute_without_session(|ctx| ctx.nv_read_public(nv_idx))
-                        .map(|(nvpub, _)| nvpub.data_size())?,
-                    nv_idx,
-                    auth_handle,
-                )
+                context
+                    .execute_without_session(|ctx| ctx.nv_read_public(nv_idx))
+                    .and_then(|(nv_public, _)| {
+                        check_nv_index_type(&nv_public, nv_index_type)?;
+                        Ok(nv_public)
+                    })
+                    .map(|nv_public| (nv_public, nv_idx, *auth_handle))
+                    .map_err(|e| {
+                        close_nv_index(context, nv_idx);
+                        e
+                    })
             }
             NvOpenOptions::NewIndex {
                 nv_public,
                 auth_handle,
-            } => (
-                nv_public.data_size(),
-                context.nv_define_space(
+            } => {
+                check_nv_index_type(nv_public, nv_index_type)?;
+                let nv_idx = context.nv_define_space(
                     AuthHandle::from(*auth_handle).try_into()?,
                     None,
                     nv_public.clone(),
-                )?,
-                auth_handle,
-            ),
-        };
+                )?;
+                Ok((nv_public.clone(), nv_idx, *auth_handle))
+            }
+        }
+    }
+}
 
-        Ok(NvReaderWriter {
-            context,
-            auth_handle: *auth_handle,
-            buffer_size,
-            nv_idx,
-            data_size,
-            offset: 0,
-        })
+/// Checks that the NV index described by `nv_public` is of the expected type.
+fn check_nv_index_type(nv_public: &NvPublic, nv_index_type: Option<NvIndexType>) -> Result<()> {
+    match nv_index_type {
+        Some(expected) => {
+            let actual = nv_public.attributes().index_type()?;
+            if actual != expected {
+                log::error!(
+                    "NV index has type {:?} but an index of type {:?} was expected",
+                    actual,
+                    expected
+                );
+                return Err(Error::WrapperError(WrapperErrorKind::InvalidParam));
+            }
+            Ok(())
+        }
+        None => Ok(()),
     }
 }
 
+/// Closes the ESYS handle of an opened NV index.
+fn close_nv_index(context: &mut Context, nv_idx: NvIndexHandle) {
+    let mut obj_handle = nv_idx.into();
+    let _ = context.execute_without_session(|ctx| ctx.tr_close(&mut obj_handle));
+}
+
+/// Starts a policy session that can be used to authorize
+/// [Context::nv_undefine_space_special] for an NV index.
+///
+/// The session uses the name algorithm of the NV index and
+/// TPM2_PolicyCommandCode with [CommandCode::NvUndefineSpaceSpecial]
+/// has been applied to it. Any other assertions required by the
+/// authorization policy of the index have to be applied to the returned
+/// session by the caller. The caller is also responsible for flushing
+/// the session if it is not consumed by the TPM.
+pub fn undefine_space_special_session(
+    context: &mut Context,
+    nv_index_handle: NvIndexHandle,
+) -> Result<PolicySession> {
+    let (nv_public, _) =
+        context.execute_without_session(|ctx| ctx.nv_read_public(nv_index_handle))?;
+    let policy_session = context
+        .execute_without_session(|ctx| {
+            ctx.start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Policy,
+                SymmetricDefinition::AES_128_CFB,
+                nv_public.name_algorithm(),
+            )
+        })?
+        .ok_or_else(|| Error::local_error(WrapperErrorKind::WrongValueFromTpm))
+        .and_then(PolicySession::try_from)?;
+
+    context
+        .execute_without_session(|ctx| {
+            ctx.policy_command_code(policy_session, CommandCode::NvUndefineSpaceSpecial)
+        })
+        .map_err(|e| {
+            let _ = context.flush_context(SessionHandle::from(policy_session).into());
+            e
+        })?;
+    Ok(policy_session)
+}
+
 /// Get the maximum buffer size for an NV space.
 pub fn max_nv_buffer_size(ctx: &mut Context) -> Result<usize> {
     Ok(ctx
@@ -266,9 +387,157 @@ impl std::io::Seek for NvReaderWriter<'_> {
 
 impl Drop for NvReaderWriter<'_> {
     fn drop(&mut self) {
-        let mut obj_handle = self.nv_idx.into();
-        let _ = self
+        close_nv_index(self.context, self.nv_idx);
+    }
+}
+
+/// Parses the 8 byte big-endian value stored in counter and bit field indices.
+fn read_u64(context: &mut Context, auth_handle: NvAuth, nv_idx: NvIndexHandle) -> Result<u64> {
+    let data = context.nv_read(auth_handle, nv_idx, 8, 0)?;
+    <[u8; 8]>::try_from(data.as_bytes())
+        .map(u64::from_be_bytes)
+        .map_err(|_| {
+            log::error!("NV index did not contain an 8 byte value");
+            Error::WrapperError(WrapperErrorKind::WrongValueFromTpm)
+        })
+}
+
+/// Non-volatile storage counter index
+///
+/// Provides methods to interact with a non-volatile storage index of
+/// the type [NvIndexType::Counter] that has been opened.
+///
+/// Use [`NvOpenOptions::open_counter`] to obtain an [`NvCounter`] object.
+///
+/// NOTE: When the `NvCounter` is dropped, any existing ESYS handles to NV Indexes _may_ be closed.
+#[derive(Debug)]
+pub struct NvCounter<'a> {
+    context: &'a mut Context,
+    auth_handle: NvAuth,
+    nv_idx: NvIndexHandle,
+}
+
+impl NvCounter<'_> {
+    /// Increments the counter.
+    ///
+    /// The counter is not readable until it has been incremented at least once.
+    pub fn increment(&mut self) -> Result<()> {
+        self.context.nv_increment(self.auth_handle, self.nv_idx)
+    }
+
+    /// Reads the current value of the counter.
+    pub fn read(&mut self) -> Result<u64> {
+        read_u64(self.context, self.auth_handle, self.nv_idx)
+    }
+}
+
+impl Drop for NvCounter<'_> {
+    fn drop(&mut self) {
+        close_nv_index(self.context, self.nv_idx);
+    }
+}
+
+/// Non-volatile storage bit field index
+///
+/// Provides methods to interact with a non-volatile storage index of
+/// the type [NvIndexType::Bits] that has been opened.
+///
+/// Use [`NvOpenOptions::open_bit_field`] to obtain an [`NvBitField`] object.
+///
+/// NOTE: When the `NvBitField` is dropped, any existing ESYS handles to NV Indexes _may_ be closed.
+#[derive(Debug)]
+pub struct NvBitField<'a> {
+    context: &'a mut Context,
+    auth_handle: NvAuth,
+    nv_idx: NvIndexHandle,
+}
+
+impl NvBitField<'_> {
+    /// Sets the bits that are set in `bits`.
+    ///
+    /// Bits that have been set cannot be cleared.
+    pub fn set_bits(&mut self, bits: u64) -> Result<()> {
+        self.context
+            .nv_set_bits(self.auth_handle, self.nv_idx, bits)
+    }
+
+    /// Reads the current value of the bit field.
+    ///
+    /// The bit field is not readable until bits have been set at least once.
+    pub fn read(&mut self) -> Result<u64> {
+        read_u64(self.context, self.auth_handle, self.nv_idx)
+    }
+}
+
+impl Drop for NvBitField<'_> {
+    fn drop(&mut self) {
+        close_nv_index(self.context, self.nv_idx);
+    }
+}
+
+/// Non-volatile storage extend index
+///
+/// Provides methods to interact with a non-volatile storage index of
+/// the type [NvIndexType::Extend] that has been opened.
+///
+/// Use [`NvOpenOptions::open_extend`] to obtain an [`NvExtendIndex`] object.
+///
+/// NOTE: When the `NvExtendIndex` is dropped, any existing ESYS handles to NV Indexes _may_ be closed.
+#[derive(Debug)]
+pub struct NvExtendIndex<'a> {
+    context: &'a mut Context,
+    auth_handle: NvAuth,
+    buffer_size: usize,
+    nv_idx: NvIndexHandle,
+    data_size: usize,
+}
+
+impl NvExtendIndex<'_> {
+    /// The size of the digest in the non-volatile storage index
+    pub fn size(&self) -> usize {
+        self.data_size
+    }
+
+    /// Extends `data` to the index in a single extend operation.
+    ///
+    /// # Errors
+    /// * if `data` does not fit in a single NV buffer, an `InvalidParam`
+    ///   wrapper error is returned. Splitting the data into several extend
+    ///   operations would result in a different digest than extending it
+    ///   at once.
+    pub fn extend(&mut self, data: &[u8]) -> Result<()> {
+        if data.len() > self.buffer_size {
+            log::error!(
+                "Data of size {} does not fit in an NV buffer of size {}",
+                data.len(),
+                self.buffer_size
+            );
+            return Err(Error::WrapperError(WrapperErrorKind::InvalidParam));
+        }
+        self.context.nv_extend(
+            self.auth_handle,
+            self.nv_idx,
+            MaxNvBuffer::from_bytes(data)?,
+        )
+    }
+
+    /// Reads the current digest of the index.
+    ///
+    /// The index is not readable until it has been extended at least once.
+    pub fn read(&mut self) -> Result<Digest> {
+        let size = u16::try_from(self.data_size).map_err(|_| {
+            log::error!("NV index data size is too large");
+            Error::WrapperError(WrapperErrorKind::InternalError)
+        })?;
+        let data = self
             .context
-            .execute_without_session(|ctx| ctx.tr_close(&mut obj_handle));
+            .nv_read(self.auth_handle, self.nv_idx, size, 0)?;
+        Digest::from_bytes(data.as_bytes())
+    }
+}
+
+impl Drop for NvExtendIndex<'_> {
+    fn drop(&mut self) {
+        close_nv_index(self.context, self.nv_idx);
     }
 }
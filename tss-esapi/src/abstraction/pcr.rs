@@ -3,7 +3,17 @@
 mod bank;
 mod data;
 
-use crate::{structures::PcrSelectionList, Context, Result};
+use crate::{
+    abstraction::hash::SequenceWriter,
+    constants::{CapabilityType, PropertyTag},
+    handles::PcrHandle,
+    interface_types::{algorithm::HashingAlgorithm, session_handles::AuthSession},
+    structures::{CapabilityData, DigestValues, PcrSelectionList, PcrSlot},
+    tss2_esys::TPM2_PCR_SELECT_MAX,
+    Context, Error, Result, WrapperErrorKind,
+};
+use log::error;
+use std::convert::TryFrom;
 
 pub use bank::PcrBank;
 pub use data::PcrData;
@@ -70,3 +80,175 @@ pub fn read_all(
     }
     Ok(pcr_data)
 }
+
+/// Function that computes the PCR allocation that is needed in order
+/// to have exactly the banks of the specified hashing algorithms active.
+///
+/// # Details
+/// The current allocation is read from the TPM using the
+/// [CapabilityType::AssignedPcr] capability. In the returned allocation all
+/// the PCRs of the requested banks are selected and the other banks that are
+/// implemented by the TPM are included without any selected PCRs, which
+/// deactivates them. The result can be used with [Context::pcr_allocate].
+///
+/// # Errors
+/// * if one of the hashing algorithms is not implemented as a PCR bank
+///   by the TPM then an `UnsupportedParam` wrapper error is returned.
+///
+/// # Example
+///
+/// ```rust
+/// # use tss_esapi::{Context, TctiNameConf};
+/// # // Create context
+/// # let mut context =
+/// #     Context::new(
+/// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+/// #     ).expect("Failed to create Context");
+/// use tss_esapi::interface_types::algorithm::HashingAlgorithm;
+///
+/// let pcr_allocation =
+///     tss_esapi::abstraction::pcr::allocation_for_banks(&mut context, &[HashingAlgorithm::Sha256])
+///         .expect("pcr::allocation_for_banks failed");
+/// ```
+pub fn allocation_for_banks(
+    context: &mut Context,
+    hashing_algorithms: &[HashingAlgorithm],
+) -> Result<PcrSelectionList> {
+    let (capability_data, _) = context.execute_without_session(|ctx| {
+        ctx.get_capability(
+            CapabilityType::AssignedPcr,
+            0,
+            PcrSelectionList::MAX_SIZE as u32,
+        )
+    })?;
+    let current_allocation = match capability_data {
+        CapabilityData::AssignedPcr(pcr_selection_list) => pcr_selection_list,
+        _ => {
+            error!("Unexpected capability data returned from TPM");
+            return Err(Error::local_error(WrapperErrorKind::WrongValueFromTpm));
+        }
+    };
+
+    if let Some(hashing_algorithm) = hashing_algorithms.iter().find(|hashing_algorithm| {
+        !current_allocation
+            .get_selections()
+            .iter()
+            .any(|pcr_selection| pcr_selection.hashing_algorithm() == **hashing_algorithm)
+    }) {
+        error!("No PCR bank is implemented for {:?}", hashing_algorithm);
+        return Err(Error::local_error(WrapperErrorKind::UnsupportedParam));
+    }
+
+    let pcr_count = context
+        .get_tpm_property(PropertyTag::PcrCount)?
+        .ok_or_else(|| {
+            error!("The TPM did not report the number of PCRs");
+            Error::local_error(WrapperErrorKind::WrongValueFromTpm)
+        })?;
+    if pcr_count > TPM2_PCR_SELECT_MAX * 8 {
+        error!("The number of PCRs reported by the TPM is not supported");
+        return Err(Error::local_error(WrapperErrorKind::UnsupportedParam));
+    }
+    let all_pcr_slots = (0..pcr_count)
+        .map(|index| PcrSlot::try_from(1u32 << index))
+        .collect::<Result<Vec<PcrSlot>>>()?;
+
+    let mut builder = PcrSelectionList::builder();
+    if let Some(pcr_selection) = current_allocation.get_selections().first() {
+        builder = builder.with_size_of_select(pcr_selection.size_of_select());
+    }
+    current_allocation
+        .get_selections()
+        .iter()
+        .fold(builder, |builder, pcr_selection| {
+            let hashing_algorithm = pcr_selection.hashing_algorithm();
+            if hashing_algorithms.contains(&hashing_algorithm) {
+                builder.with_selection(hashing_algorithm, &all_pcr_slots)
+            } else {
+                builder.with_selection(hashing_algorithm, &[])
+            }
+        })
+        .build()
+}
+
+/// Event sequence that measures arbitrary amounts of data into a PCR.
+///
+/// # Details
+/// The data that is written to the sequence, using the [std::io::Write]
+/// implementation, is sent to the TPM in chunks. When the sequence is
+/// finished the TPM extends the PCR in all the active banks with the digest
+/// of the data, hashed using the algorithm of each bank.
+///
+/// # Example
+///
+/// ```rust
+/// # use tss_esapi::{Context, TctiNameConf};
+/// # // Create context
+/// # let mut context =
+/// #     Context::new(
+/// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+/// #     ).expect("Failed to create Context");
+/// use std::io::Write;
+/// use tss_esapi::{
+///     abstraction::pcr::PcrEventSequence,
+///     handles::PcrHandle,
+///     interface_types::algorithm::HashingAlgorithm,
+/// };
+///
+/// let data = vec![0xa5; 4096];
+/// let mut event_sequence = PcrEventSequence::start(&mut context)
+///     .expect("Failed to start event sequence");
+/// event_sequence.write_all(&data).expect("Failed to write data to event sequence");
+/// let digests = event_sequence
+///     .finish(PcrHandle::Pcr16)
+///     .expect("Failed to finish event sequence");
+/// // The digests can be used to create an event log entry.
+/// assert!(digests.get(HashingAlgorithm::Sha256).is_some());
+/// ```
+#[derive(Debug)]
+pub struct PcrEventSequence<'a> {
+    writer: SequenceWriter<'a>,
+}
+
+impl<'a> PcrEventSequence<'a> {
+    /// Starts an event sequence.
+    pub fn start(context: &'a mut Context) -> Result<Self> {
+        let sequence_handle = context.hash_sequence_start(HashingAlgorithm::Null, None)?;
+        Ok(PcrEventSequence {
+            writer: SequenceWriter::new(context, sequence_handle)?,
+        })
+    }
+
+    /// Finishes the event sequence and extends the PCR.
+    ///
+    /// # Arguments
+    /// * `pcr_handle` - A [PcrHandle] to the PCR slot that is to be extended.
+    ///
+    /// # Details
+    /// The PCR is authorized using the session that is set in session slot 1
+    /// of the context. If no session has been set then a password session
+    /// is used.
+    ///
+    /// # Returns
+    /// The [DigestValues] containing the digest of the data for each
+    /// of the active PCR banks.
+    pub fn finish(self, pcr_handle: PcrHandle) -> Result<DigestValues> {
+        self.writer.complete(|ctx, sequence_handle, data| {
+            let pcr_session = ctx.sessions().0.unwrap_or(AuthSession::Password);
+            ctx.execute_with_sessions(
+                (Some(pcr_session), Some(AuthSession::Password), None),
+                |ctx| ctx.event_sequence_complete(pcr_handle, sequence_handle, data),
+            )
+        })
+    }
+}
+
+impl std::io::Write for PcrEventSequence<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.writer.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
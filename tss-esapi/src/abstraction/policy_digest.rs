@@ -0,0 +1,350 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::convert::TryFrom;
+
+use log::error;
+
+use super::software_hash::SoftwareHashingAlgorithm;
+use crate::{
+    attributes::LocalityAttributes,
+    constants::{ArithmeticOperation, CommandCode},
+    interface_types::{algorithm::HashingAlgorithm, YesNo},
+    structures::{Digest, DigestList, Name, Nonce, Operand, PcrSelectionList},
+    tss2_esys::{TPM2_CC, TPM2_EO, TPMA_LOCALITY, TPMI_YES_NO, TPML_PCR_SELECTION},
+    Error, Result, WrapperErrorKind,
+};
+
+/// Computes policy digests in software.
+///
+/// # Details
+/// The builder computes the same policy digest as a trial session
+/// on a TPM, i.e. the value returned by [crate::Context::policy_get_digest],
+/// without requiring access to a TPM.
+///
+/// The methods mirror the `policy_*` methods of [crate::Context]. Only the
+/// parameters that affect the policy digest are taken and the entities that
+/// are referenced by handles in a policy session are referenced by their
+/// names instead.
+///
+/// There is no counterpart to [crate::Context::policy_ticket] because a ticket
+/// extends the policy digest in the same way as the [PolicyDigestBuilder::policy_signed]
+/// or [PolicyDigestBuilder::policy_secret] call that produced it.
+///
+/// The [HashingAlgorithm::Sm3_256] and [HashingAlgorithm::Null] algorithms
+/// are not supported.
+///
+/// # Example
+///
+/// ```rust
+/// use tss_esapi::{
+///     abstraction::policy_digest::PolicyDigestBuilder,
+///     interface_types::algorithm::HashingAlgorithm, structures::Name,
+/// };
+/// use std::convert::TryFrom;
+///
+/// // The policy used by the default EK templates:
+/// // TPM2_PolicySecret(TPM_RH_ENDORSEMENT)
+/// let endorsement_name = Name::try_from(0x4000000Bu32.to_be_bytes().to_vec())
+///     .expect("Failed to create name");
+/// let policy_digest = PolicyDigestBuilder::new(HashingAlgorithm::Sha256)
+///     .expect("Failed to create builder")
+///     .policy_secret(endorsement_name, Default::default())
+///     .build()
+///     .expect("Failed to compute the policy digest");
+/// assert_eq!(policy_digest.as_bytes()[..4], [0x83, 0x71, 0x97, 0x67]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PolicyDigestBuilder {
+    hashing_algorithm: SoftwareHashingAlgorithm,
+    policy_digest: Vec<u8>,
+}
+
+impl PolicyDigestBuilder {
+    /// Creates a builder with a policy digest that has been reset.
+    ///
+    /// # Errors
+    /// * if the hashing algorithm can not be computed in software,
+    ///   an `UnsupportedParam` wrapper error is returned.
+    pub fn new(hashing_algorithm: HashingAlgorithm) -> Result<Self> {
+        let hashing_algorithm = SoftwareHashingAlgorithm::try_from(hashing_algorithm)?;
+        Ok(PolicyDigestBuilder {
+            hashing_algorithm,
+            policy_digest: Self::reset_digest(hashing_algorithm),
+        })
+    }
+
+    /// Mirrors [crate::Context::policy_signed].
+    ///
+    /// # Arguments
+    /// * `auth_object_name` - The name of the key that signs the authorization.
+    /// * `policy_ref` - The policy qualifier.
+    pub fn policy_signed(self, auth_object_name: Name, policy_ref: Nonce) -> Self {
+        self.policy_update(
+            CommandCode::PolicySigned,
+            auth_object_name.value(),
+            policy_ref.as_bytes(),
+        )
+    }
+
+    /// Mirrors [crate::Context::policy_secret].
+    ///
+    /// # Arguments
+    /// * `auth_name` - The name of the entity whose authorization is required.
+    /// * `policy_ref` - The policy qualifier.
+    pub fn policy_secret(self, auth_name: Name, policy_ref: Nonce) -> Self {
+        self.policy_update(
+            CommandCode::PolicySecret,
+            auth_name.value(),
+            policy_ref.as_bytes(),
+        )
+    }
+
+    /// Mirrors [crate::Context::policy_or].
+    ///
+    /// # Errors
+    /// * if the list contains fewer than two digests, an `InvalidParam`
+    ///   wrapper error is returned.
+    pub fn policy_or(mut self, digest_list: DigestList) -> Result<Self> {
+        if digest_list.len() < 2 {
+            error!("The digest list of a policy OR must contain at least two digests");
+            return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+        }
+        self.policy_digest = Self::reset_digest(self.hashing_algorithm);
+        let digests = digest_list
+            .value()
+            .iter()
+            .flat_map(|digest| digest.as_bytes().iter().copied())
+            .collect::<Vec<u8>>();
+        Ok(self.extend(CommandCode::PolicyOr, &[&digests]))
+    }
+
+    /// Mirrors [crate::Context::policy_pcr].
+    ///
+    /// # Arguments
+    /// * `pcr_policy_digest` - The digest of the selected PCR values.
+    /// * `pcr_selection_list` - The selected PCRs.
+    pub fn policy_pcr(
+        self,
+        pcr_policy_digest: Digest,
+        pcr_selection_list: PcrSelectionList,
+    ) -> Self {
+        let tpml_pcr_selection = TPML_PCR_SELECTION::from(pcr_selection_list);
+        let mut pcrs = tpml_pcr_selection.count.to_be_bytes().to_vec();
+        for pcr_selection in &tpml_pcr_selection.pcrSelections[..tpml_pcr_selection.count as usize]
+        {
+            pcrs.extend_from_slice(&pcr_selection.hash.to_be_bytes());
+            pcrs.push(pcr_selection.sizeofSelect);
+            pcrs.extend_from_slice(&pcr_selection.pcrSelect[..pcr_selection.sizeofSelect as usize]);
+        }
+        self.extend(
+            CommandCode::PolicyPcr,
+            &[&pcrs, pcr_policy_digest.as_bytes()],
+        )
+    }
+
+    /// Mirrors [crate::Context::policy_locality].
+    pub fn policy_locality(self, locality: LocalityAttributes) -> Self {
+        self.extend(
+            CommandCode::PolicyLocality,
+            &[&[TPMA_LOCALITY::from(locality)]],
+        )
+    }
+
+    /// Mirrors [crate::Context::policy_nv].
+    ///
+    /// # Arguments
+    /// * `nv_index_name` - The name of the NV index.
+    /// * `operand_b` - The second operand.
+    /// * `offset` - The offset in the NV index of the first operand.
+    /// * `operation` - The comparison to make.
+    pub fn policy_nv(
+        self,
+        nv_index_name: Name,
+        operand_b: Operand,
+        offset: u16,
+        operation: ArithmeticOperation,
+    ) -> Self {
+        let args = self.hashing_algorithm.hash(&[
+            operand_b.as_bytes(),
+            &offset.to_be_bytes(),
+            &TPM2_EO::from(operation).to_be_bytes(),
+        ]);
+        self.extend(CommandCode::PolicyNv, &[&args, nv_index_name.value()])
+    }
+
+    /// Mirrors [crate::Context::policy_counter_timer].
+    ///
+    /// # Arguments
+    /// * `operand_b` - The second operand.
+    /// * `offset` - The offset in TPMS_TIME_INFO of the first operand.
+    /// * `operation` - The comparison to make.
+    pub fn policy_counter_timer(
+        self,
+        operand_b: Operand,
+        offset: u16,
+        operation: ArithmeticOperation,
+    ) -> Self {
+        let args = self.hashing_algorithm.hash(&[
+            operand_b.as_bytes(),
+            &offset.to_be_bytes(),
+            &TPM2_EO::from(operation).to_be_bytes(),
+        ]);
+        self.extend(CommandCode::PolicyCounterTimer, &[&args])
+    }
+
+    /// Mirrors [crate::Context::policy_command_code].
+    pub fn policy_command_code(self, code: CommandCode) -> Self {
+        self.extend(
+            CommandCode::PolicyCommandCode,
+            &[&TPM2_CC::from(code).to_be_bytes()],
+        )
+    }
+
+    /// Mirrors [crate::Context::policy_physical_presence].
+    pub fn policy_physical_presence(self) -> Self {
+        self.extend(CommandCode::PolicyPhysicalPresence, &[])
+    }
+
+    /// Mirrors [crate::Context::policy_cp_hash].
+    pub fn policy_cp_hash(self, cp_hash_a: Digest) -> Self {
+        self.extend(CommandCode::PolicyCpHash, &[cp_hash_a.as_bytes()])
+    }
+
+    /// Mirrors [crate::Context::policy_name_hash].
+    pub fn policy_name_hash(self, name_hash: Digest) -> Self {
+        self.extend(CommandCode::PolicyNameHash, &[name_hash.as_bytes()])
+    }
+
+    /// Mirrors [crate::Context::policy_duplication_select].
+    pub fn policy_duplication_select(
+        self,
+        object_name: Name,
+        new_parent_name: Name,
+        include_object: bool,
+    ) -> Self {
+        let include_object_flag = [TPMI_YES_NO::from(YesNo::from(include_object))];
+        let object_name: &[u8] = if include_object {
+            object_name.value()
+        } else {
+            &[]
+        };
+        self.extend(
+            CommandCode::PolicyDuplicationSelect,
+            &[object_name, new_parent_name.value(), &include_object_flag],
+        )
+    }
+
+    /// Mirrors [crate::Context::policy_authorize].
+    ///
+    /// # Arguments
+    /// * `policy_ref` - The policy qualifier.
+    /// * `key_sign` - The name of the key that signs the approved policies.
+    pub fn policy_authorize(mut self, policy_ref: Nonce, key_sign: Name) -> Self {
+        self.policy_digest = Self::reset_digest(self.hashing_algorithm);
+        self.policy_update(
+            CommandCode::PolicyAuthorize,
+            key_sign.value(),
+            policy_ref.as_bytes(),
+        )
+    }
+
+    /// Mirrors [crate::Context::policy_auth_value].
+    pub fn policy_auth_value(self) -> Self {
+        self.extend(CommandCode::PolicyAuthValue, &[])
+    }
+
+    /// Mirrors [crate::Context::policy_password].
+    ///
+    /// # Details
+    /// The policy digest is extended in the same way as
+    /// for [PolicyDigestBuilder::policy_auth_value].
+    pub fn policy_password(self) -> Self {
+        self.extend(CommandCode::PolicyAuthValue, &[])
+    }
+
+    /// Mirrors [crate::Context::policy_nv_written].
+    pub fn policy_nv_written(self, written_set: bool) -> Self {
+        self.extend(
+            CommandCode::PolicyNvWritten,
+            &[&[TPMI_YES_NO::from(YesNo::from(written_set))]],
+        )
+    }
+
+    /// Mirrors [crate::Context::policy_template].
+    pub fn policy_template(self, template_hash: Digest) -> Self {
+        self.extend(CommandCode::PolicyTemplate, &[template_hash.as_bytes()])
+    }
+
+    /// Mirrors [crate::Context::policy_authorize_nv].
+    ///
+    /// # Arguments
+    /// * `nv_index_name` - The name of the NV index containing the approved policy.
+    pub fn policy_authorize_nv(mut self, nv_index_name: Name) -> Self {
+        self.policy_digest = Self::reset_digest(self.hashing_algorithm);
+        self.extend(CommandCode::PolicyAuthorizeNv, &[nv_index_name.value()])
+    }
+
+    /// Mirrors TPM2_Policy_AC_SendSelect.
+    pub fn policy_ac_send_select(
+        self,
+        object_name: Name,
+        auth_handle_name: Name,
+        ac_name: Name,
+        include_object: bool,
+    ) -> Self {
+        let include_object_flag = [TPMI_YES_NO::from(YesNo::from(include_object))];
+        let object_name: &[u8] = if include_object {
+            object_name.value()
+        } else {
+            &[]
+        };
+        self.extend(
+            CommandCode::PolicyAcSendSelect,
+            &[
+                object_name,
+                auth_handle_name.value(),
+                ac_name.value(),
+                &include_object_flag,
+            ],
+        )
+    }
+
+    /// Resets the policy digest, like TPM2_PolicyRestart.
+    pub fn policy_restart(mut self) -> Self {
+        self.policy_digest = Self::reset_digest(self.hashing_algorithm);
+        self
+    }
+
+    /// Returns the policy digest.
+    pub fn build(self) -> Result<Digest> {
+        Digest::try_from(self.policy_digest)
+    }
+
+    /// Extends the policy digest.
+    ///
+    /// policyDigest := H(policyDigest || commandCode || data)
+    fn extend(mut self, command_code: CommandCode, data: &[&[u8]]) -> Self {
+        let command_code = TPM2_CC::from(command_code).to_be_bytes();
+        let mut input: Vec<&[u8]> = vec![&self.policy_digest, &command_code];
+        input.extend_from_slice(data);
+        self.policy_digest = self.hashing_algorithm.hash(&input);
+        self
+    }
+
+    /// Extends the policy digest in the way defined by PolicyUpdate()
+    /// in the specification.
+    ///
+    /// policyDigest := H(H(policyDigest || commandCode || arg2) || arg3)
+    fn policy_update(self, command_code: CommandCode, arg2: &[u8], arg3: &[u8]) -> Self {
+        let mut builder = self.extend(command_code, &[arg2]);
+        builder.policy_digest = builder
+            .hashing_algorithm
+            .hash(&[&builder.policy_digest, arg3]);
+        builder
+    }
+
+    fn reset_digest(hashing_algorithm: SoftwareHashingAlgorithm) -> Vec<u8> {
+        vec![0; hashing_algorithm.hash(&[]).len()]
+    }
+}
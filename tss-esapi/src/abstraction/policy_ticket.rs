@@ -0,0 +1,188 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use log::error;
+use std::time::{Duration, Instant};
+
+use crate::{
+    handles::{AuthHandle, ObjectHandle},
+    interface_types::session_handles::PolicySession,
+    structures::{AuthTicket, Digest, Name, Nonce, Signature, Timeout},
+    Context, Error, Result, WrapperErrorKind,
+};
+
+/// A PolicySigned or PolicySecret authorization that has been
+/// converted into a ticket so that it can be reused.
+///
+/// # Details
+/// The ticket is stored together with the parameters that are
+/// needed in order to redeem it with PolicyTicket and the point in
+/// time when it expires. This makes it possible to reuse an
+/// authorization, e.g. one that was provided by a remote signer,
+/// in several policy sessions without having to create a new
+/// authorization for each session.
+///
+/// In order for the ticket to be usable in other sessions than
+/// the one that was used to create it the `nonce_tpm` needs to
+/// be empty when the ticket is created.
+///
+/// The TPM also invalidates tickets on TPM Reset so a ticket
+/// can be rejected by the TPM even if it has not expired.
+#[derive(Debug, Clone)]
+pub struct CachedPolicyTicket {
+    timeout: Timeout,
+    cp_hash_a: Digest,
+    policy_ref: Nonce,
+    auth_name: Name,
+    ticket: AuthTicket,
+    expires_at: Instant,
+}
+
+impl CachedPolicyTicket {
+    /// Creates a cached ticket from the parameters of a ticket.
+    ///
+    /// # Arguments
+    /// * `timeout` - The [Timeout] that was returned together with the ticket.
+    /// * `cp_hash_a` - The cpHash that was used when the ticket was created.
+    /// * `policy_ref` - The policy reference that was used when the ticket was created.
+    /// * `auth_name` - The [Name] of the object that provided the authorization.
+    /// * `ticket` - The [AuthTicket].
+    /// * `expiration` - The [Duration], counted from now, for which the ticket is valid.
+    pub fn new(
+        timeout: Timeout,
+        cp_hash_a: Digest,
+        policy_ref: Nonce,
+        auth_name: Name,
+        ticket: AuthTicket,
+        expiration: Duration,
+    ) -> Self {
+        CachedPolicyTicket {
+            timeout,
+            cp_hash_a,
+            policy_ref,
+            auth_name,
+            ticket,
+            expires_at: Instant::now() + expiration,
+        }
+    }
+
+    /// Executes PolicySigned and caches the resulting ticket.
+    ///
+    /// # Details
+    /// See [Context::policy_signed_with_ticket] for a description of the
+    /// arguments. The policy session that is used must not be a trial session.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_policy_signed(
+        context: &mut Context,
+        policy_session: PolicySession,
+        auth_object: ObjectHandle,
+        nonce_tpm: Nonce,
+        cp_hash_a: Digest,
+        policy_ref: Nonce,
+        expiration: Duration,
+        signature: Signature,
+    ) -> Result<Self> {
+        let expires_at = Instant::now() + expiration;
+        let auth_name = context.tr_get_name(auth_object)?;
+        let (timeout, ticket) = context.policy_signed_with_ticket(
+            policy_session,
+            auth_object,
+            nonce_tpm,
+            cp_hash_a.clone(),
+            policy_ref.clone(),
+            expiration,
+            signature,
+        )?;
+        Ok(CachedPolicyTicket {
+            timeout,
+            cp_hash_a,
+            policy_ref,
+            auth_name,
+            ticket,
+            expires_at,
+        })
+    }
+
+    /// Executes PolicySecret and caches the resulting ticket.
+    ///
+    /// # Details
+    /// See [Context::policy_secret_with_ticket] for a description of the
+    /// arguments. The policy session that is used must not be a trial session.
+    pub fn from_policy_secret(
+        context: &mut Context,
+        policy_session: PolicySession,
+        auth_handle: AuthHandle,
+        nonce_tpm: Nonce,
+        cp_hash_a: Digest,
+        policy_ref: Nonce,
+        expiration: Duration,
+    ) -> Result<Self> {
+        let expires_at = Instant::now() + expiration;
+        let auth_name = context.tr_get_name(auth_handle.into())?;
+        let (timeout, ticket) = context.policy_secret_with_ticket(
+            policy_session,
+            auth_handle,
+            nonce_tpm,
+            cp_hash_a.clone(),
+            policy_ref.clone(),
+            expiration,
+        )?;
+        Ok(CachedPolicyTicket {
+            timeout,
+            cp_hash_a,
+            policy_ref,
+            auth_name,
+            ticket,
+            expires_at,
+        })
+    }
+
+    /// Returns the point in time when the ticket expires.
+    pub fn expires_at(&self) -> Instant {
+        self.expires_at
+    }
+
+    /// Indicates if the ticket has expired.
+    pub fn is_expired(&self) -> bool {
+        Instant::now() >= self.expires_at
+    }
+
+    /// Returns the [Timeout] of the ticket.
+    pub fn timeout(&self) -> &Timeout {
+        &self.timeout
+    }
+
+    /// Returns the [AuthTicket].
+    pub fn ticket(&self) -> &AuthTicket {
+        &self.ticket
+    }
+
+    /// Returns the [Name] of the object that provided the authorization.
+    pub fn auth_name(&self) -> &Name {
+        &self.auth_name
+    }
+
+    /// Redeems the ticket in a policy session using PolicyTicket.
+    ///
+    /// # Arguments
+    /// * `context` - The [Context] that is going to be used.
+    /// * `policy_session` - The [PolicySession] in which the ticket is redeemed.
+    ///
+    /// # Errors
+    /// * if the ticket has expired then an `InvalidParam` wrapper error
+    ///   is returned without contacting the TPM.
+    pub fn redeem(&self, context: &mut Context, policy_session: PolicySession) -> Result<()> {
+        if self.is_expired() {
+            error!("The cached policy ticket has expired");
+            return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+        }
+        context.policy_ticket(
+            policy_session,
+            self.timeout.clone(),
+            self.cp_hash_a.clone(),
+            self.policy_ref.clone(),
+            self.auth_name.clone(),
+            self.ticket.clone(),
+        )
+    }
+}
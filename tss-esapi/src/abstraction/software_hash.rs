@@ -0,0 +1,68 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::convert::TryFrom;
+
+use log::error;
+use sha1::Sha1;
+use sha2::{Sha256, Sha384, Sha512};
+use sha3::{Sha3_256, Sha3_384, Sha3_512};
+
+use crate::{interface_types::algorithm::HashingAlgorithm, Error, Result, WrapperErrorKind};
+
+/// The hash algorithms that can be computed in software.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum SoftwareHashingAlgorithm {
+    Sha1,
+    Sha256,
+    Sha384,
+    Sha512,
+    Sha3_256,
+    Sha3_384,
+    Sha3_512,
+}
+
+impl SoftwareHashingAlgorithm {
+    pub(crate) fn hash(&self, data: &[&[u8]]) -> Vec<u8> {
+        fn hash<D: sha2::Digest>(data: &[&[u8]]) -> Vec<u8> {
+            let mut hasher = D::new();
+            for d in data {
+                hasher.update(d);
+            }
+            hasher.finalize().to_vec()
+        }
+
+        match self {
+            SoftwareHashingAlgorithm::Sha1 => hash::<Sha1>(data),
+            SoftwareHashingAlgorithm::Sha256 => hash::<Sha256>(data),
+            SoftwareHashingAlgorithm::Sha384 => hash::<Sha384>(data),
+            SoftwareHashingAlgorithm::Sha512 => hash::<Sha512>(data),
+            SoftwareHashingAlgorithm::Sha3_256 => hash::<Sha3_256>(data),
+            SoftwareHashingAlgorithm::Sha3_384 => hash::<Sha3_384>(data),
+            SoftwareHashingAlgorithm::Sha3_512 => hash::<Sha3_512>(data),
+        }
+    }
+}
+
+impl TryFrom<HashingAlgorithm> for SoftwareHashingAlgorithm {
+    type Error = Error;
+
+    fn try_from(hashing_algorithm: HashingAlgorithm) -> Result<Self> {
+        match hashing_algorithm {
+            HashingAlgorithm::Sha1 => Ok(SoftwareHashingAlgorithm::Sha1),
+            HashingAlgorithm::Sha256 => Ok(SoftwareHashingAlgorithm::Sha256),
+            HashingAlgorithm::Sha384 => Ok(SoftwareHashingAlgorithm::Sha384),
+            HashingAlgorithm::Sha512 => Ok(SoftwareHashingAlgorithm::Sha512),
+            HashingAlgorithm::Sha3_256 => Ok(SoftwareHashingAlgorithm::Sha3_256),
+            HashingAlgorithm::Sha3_384 => Ok(SoftwareHashingAlgorithm::Sha3_384),
+            HashingAlgorithm::Sha3_512 => Ok(SoftwareHashingAlgorithm::Sha3_512),
+            HashingAlgorithm::Sm3_256 | HashingAlgorithm::Null => {
+                error!(
+                    "The hashing algorithm {:?} can not be computed in software",
+                    hashing_algorithm
+                );
+                Err(Error::local_error(WrapperErrorKind::UnsupportedParam))
+            }
+        }
+    }
+}
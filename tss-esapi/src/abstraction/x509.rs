@@ -0,0 +1,189 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Helpers for creating X.509 certificates with TPM2_CertifyX509.
+//!
+//! The TPM signs a certificate by combining a partial certificate, provided by
+//! the caller, with the fields that it adds itself (the version, the serial number,
+//! the signature algorithm and the subject public key info). The helpers in this
+//! module build the partial certificate and assemble the complete [Certificate]
+//! from the output of the TPM.
+use log::error;
+use picky_asn1::{
+    bit_string::BitString,
+    wrapper::{BitStringAsn1, ExplicitContextTag0, ExplicitContextTag3, IntegerAsn1},
+};
+use picky_asn1_x509::{
+    AlgorithmIdentifier, Certificate, Extensions, Name, SubjectPublicKeyInfo, TbsCertificate,
+    Validity, Version,
+};
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+
+#[cfg(has_esys_certify_x509)]
+use crate::{
+    handles::{KeyHandle, ObjectHandle},
+    structures::{Digest, SignatureScheme},
+    Context,
+};
+use crate::{
+    structures::{MaxBuffer, Signature},
+    Error, Result, WrapperErrorKind,
+};
+
+/// The fields of a certificate that are provided to the TPM.
+///
+/// # Details
+/// The partial certificate does not contain a signature algorithm identifier
+/// so the TPM adds the one that corresponds to the signing scheme that is used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialCertificate {
+    pub issuer: Name,
+    pub validity: Validity,
+    pub subject: Name,
+    pub extensions: Extensions,
+}
+
+/// The DER layout of the partial certificate.
+#[derive(Serialize)]
+struct PartialTbsCertificate {
+    issuer: Name,
+    validity: Validity,
+    subject: Name,
+    extensions: ExplicitContextTag3<Extensions>,
+}
+
+/// The DER layout of the fields that are added by the TPM.
+#[derive(Deserialize)]
+struct AddedToCertificate {
+    version: ExplicitContextTag0<Version>,
+    serial_number: IntegerAsn1,
+    signature: AlgorithmIdentifier,
+    subject_public_key_info: SubjectPublicKeyInfo,
+}
+
+/// The DER layout of an ECDSA signature value.
+#[derive(Serialize)]
+struct EcdsaSignatureValue {
+    r: IntegerAsn1,
+    s: IntegerAsn1,
+}
+
+impl PartialCertificate {
+    /// Encodes the partial certificate so that it can be used with
+    /// `Context::certify_x509`.
+    ///
+    /// # Errors
+    /// * if the partial certificate cannot be DER encoded or if it
+    ///   does not fit in a [MaxBuffer] an `InvalidParam` wrapper error
+    ///   is returned.
+    pub fn to_der(&self) -> Result<MaxBuffer> {
+        let partial_tbs_certificate = PartialTbsCertificate {
+            issuer: self.issuer.clone(),
+            validity: self.validity.clone(),
+            subject: self.subject.clone(),
+            extensions: self.extensions.clone().into(),
+        };
+        picky_asn1_der::to_vec(&partial_tbs_certificate)
+            .map_err(|e| {
+                error!("Failed to encode the partial certificate: {}", e);
+                Error::local_error(WrapperErrorKind::InvalidParam)
+            })
+            .and_then(|data| {
+                MaxBuffer::try_from(data).map_err(|_| {
+                    error!("The encoded partial certificate is too large");
+                    Error::local_error(WrapperErrorKind::InvalidParam)
+                })
+            })
+    }
+
+    /// Assembles the complete certificate.
+    ///
+    /// # Arguments
+    /// * `added_to_certificate` - The DER encoded fields that were added by the TPM.
+    /// * `signature` - The [Signature] that was produced by the TPM.
+    ///
+    /// # Errors
+    /// * if `added_to_certificate` cannot be decoded a `WrongValueFromTpm` wrapper
+    ///   error is returned.
+    /// * if the signature is not an RSA or ECDSA signature an `UnsupportedParam`
+    ///   wrapper error is returned.
+    pub fn into_certificate(
+        self,
+        added_to_certificate: &[u8],
+        signature: Signature,
+    ) -> Result<Certificate> {
+        let added_to_certificate: AddedToCertificate =
+            picky_asn1_der::from_bytes(added_to_certificate).map_err(|e| {
+                error!(
+                    "Failed to decode the fields added to the certificate: {}",
+                    e
+                );
+                Error::local_error(WrapperErrorKind::WrongValueFromTpm)
+            })?;
+
+        Ok(Certificate {
+            tbs_certificate: TbsCertificate {
+                version: added_to_certificate.version,
+                serial_number: added_to_certificate.serial_number,
+                signature: added_to_certificate.signature.clone(),
+                issuer: self.issuer,
+                validity: self.validity,
+                subject: self.subject,
+                subject_public_key_info: added_to_certificate.subject_public_key_info,
+                extensions: self.extensions.into(),
+            },
+            signature_algorithm: added_to_certificate.signature,
+            signature_value: signature_value(signature)?,
+        })
+    }
+}
+
+/// Converts a TPM signature into the signature value of a certificate.
+fn signature_value(signature: Signature) -> Result<BitStringAsn1> {
+    let value = match signature {
+        Signature::RsaSsa(rsa_signature) | Signature::RsaPss(rsa_signature) => {
+            rsa_signature.signature().as_bytes().to_vec()
+        }
+        Signature::EcDsa(ecc_signature) => picky_asn1_der::to_vec(&EcdsaSignatureValue {
+            r: IntegerAsn1::from_bytes_be_unsigned(ecc_signature.signature_r().as_bytes().to_vec()),
+            s: IntegerAsn1::from_bytes_be_unsigned(ecc_signature.signature_s().as_bytes().to_vec()),
+        })
+        .map_err(|e| {
+            error!("Failed to encode the ECDSA signature: {}", e);
+            Error::local_error(WrapperErrorKind::InternalError)
+        })?,
+        _ => {
+            error!("Unsupported signature algorithm in certificate");
+            return Err(Error::local_error(WrapperErrorKind::UnsupportedParam));
+        }
+    };
+    Ok(BitString::with_bytes(value).into())
+}
+
+/// Creates an X.509 certificate for an object loaded in the TPM.
+///
+/// # Details
+/// See [Context::certify_x509] for a description of the arguments and of the
+/// sessions that are needed.
+///
+/// # Returns
+/// The complete [Certificate] and the digest of the TBS certificate that was signed
+/// by the TPM.
+#[cfg(has_esys_certify_x509)]
+pub fn certify_x509(
+    context: &mut Context,
+    object_handle: ObjectHandle,
+    signing_key_handle: KeyHandle,
+    signing_scheme: SignatureScheme,
+    partial_certificate: PartialCertificate,
+) -> Result<(Certificate, Digest)> {
+    let (added_to_certificate, tbs_digest, signature) = context.certify_x509(
+        object_handle,
+        signing_key_handle,
+        signing_scheme,
+        partial_certificate.to_der()?,
+    )?;
+    let certificate = partial_certificate.into_certificate(&added_to_certificate, signature)?;
+    Ok((certificate, tbs_digest))
+}
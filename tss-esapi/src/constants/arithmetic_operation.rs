@@ -0,0 +1,65 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{
+    constants::tss::{
+        TPM2_EO_BITCLEAR, TPM2_EO_BITSET, TPM2_EO_EQ, TPM2_EO_NEQ, TPM2_EO_SIGNED_GE,
+        TPM2_EO_SIGNED_GT, TPM2_EO_SIGNED_LE, TPM2_EO_SIGNED_LT, TPM2_EO_UNSIGNED_GE,
+        TPM2_EO_UNSIGNED_GT, TPM2_EO_UNSIGNED_LE, TPM2_EO_UNSIGNED_LT,
+    },
+    tss2_esys::TPM2_EO,
+    Error, Result, WrapperErrorKind,
+};
+use log::error;
+use num_derive::{FromPrimitive, ToPrimitive};
+use num_traits::{FromPrimitive, ToPrimitive};
+use std::convert::TryFrom;
+
+/// Enum representing the different TPM EA arithmetic operands (TPM_EO)
+/// that are used in order to compare operand A (the data in the TPM)
+/// with operand B (the data provided by the caller) in PolicyNV and
+/// PolicyCounterTimer.
+#[derive(FromPrimitive, ToPrimitive, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum ArithmeticOperation {
+    /// A = B
+    Eq = TPM2_EO_EQ,
+    /// A ≠ B
+    Neq = TPM2_EO_NEQ,
+    /// A > B signed
+    SignedGt = TPM2_EO_SIGNED_GT,
+    /// A > B unsigned
+    UnsignedGt = TPM2_EO_UNSIGNED_GT,
+    /// A < B signed
+    SignedLt = TPM2_EO_SIGNED_LT,
+    /// A < B unsigned
+    UnsignedLt = TPM2_EO_UNSIGNED_LT,
+    /// A ≥ B signed
+    SignedGe = TPM2_EO_SIGNED_GE,
+    /// A ≥ B unsigned
+    UnsignedGe = TPM2_EO_UNSIGNED_GE,
+    /// A ≤ B signed
+    SignedLe = TPM2_EO_SIGNED_LE,
+    /// A ≤ B unsigned
+    UnsignedLe = TPM2_EO_UNSIGNED_LE,
+    /// All bits SET in B are SET in A
+    BitSet = TPM2_EO_BITSET,
+    /// All bits SET in B are CLEAR in A
+    BitClear = TPM2_EO_BITCLEAR,
+}
+
+impl From<ArithmeticOperation> for TPM2_EO {
+    fn from(arithmetic_operation: ArithmeticOperation) -> TPM2_EO {
+        // The values are well defined so this cannot fail.
+        arithmetic_operation.to_u16().unwrap()
+    }
+}
+
+impl TryFrom<TPM2_EO> for ArithmeticOperation {
+    type Error = Error;
+    fn try_from(tpm_eo: TPM2_EO) -> Result<ArithmeticOperation> {
+        ArithmeticOperation::from_u16(tpm_eo).ok_or_else(|| {
+            error!("value = {} did not match any ArithmeticOperation.", tpm_eo);
+            Error::local_error(WrapperErrorKind::InvalidParam)
+        })
+    }
+}
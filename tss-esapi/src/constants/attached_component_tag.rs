@@ -0,0 +1,84 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{
+    constants::tss::{TPM2_AT_ANY, TPM2_AT_ERROR, TPM2_AT_PV1, TPM2_AT_VEND},
+    tss2_esys::UINT32,
+    Error, Result, WrapperErrorKind,
+};
+use log::error;
+use std::convert::TryFrom;
+
+/// Enum representing the tags of the data that
+/// is exchanged with an attached component.
+///
+/// # Details
+/// This corresponds to TPM_AT.
+///
+/// The vendor specific tags start at TPM2_AT_VEND and are
+/// represented by the [AttachedComponentTag::Vendor] variant.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AttachedComponentTag {
+    Any,
+    Error,
+    Pv1,
+    Vendor(VendorAttachedComponentTag),
+}
+
+/// A vendor specific attached component tag.
+///
+/// # Details
+/// The value of the tag is at least TPM2_AT_VEND.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct VendorAttachedComponentTag(u32);
+
+impl VendorAttachedComponentTag {
+    /// Creates a vendor specific tag.
+    ///
+    /// # Errors
+    /// * if `value` is smaller than TPM2_AT_VEND, an `InvalidParam`
+    ///   wrapper error is returned.
+    pub fn new(value: u32) -> Result<Self> {
+        if value < TPM2_AT_VEND {
+            error!(
+                "value = {} is not a vendor specific attached component tag.",
+                value
+            );
+            return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+        }
+        Ok(VendorAttachedComponentTag(value))
+    }
+
+    /// Returns the value of the tag.
+    pub const fn value(&self) -> u32 {
+        self.0
+    }
+}
+
+impl From<AttachedComponentTag> for UINT32 {
+    fn from(attached_component_tag: AttachedComponentTag) -> UINT32 {
+        match attached_component_tag {
+            AttachedComponentTag::Any => TPM2_AT_ANY,
+            AttachedComponentTag::Error => TPM2_AT_ERROR,
+            AttachedComponentTag::Pv1 => TPM2_AT_PV1,
+            AttachedComponentTag::Vendor(vendor_tag) => vendor_tag.value(),
+        }
+    }
+}
+
+impl TryFrom<UINT32> for AttachedComponentTag {
+    type Error = Error;
+    fn try_from(tpm_at: UINT32) -> Result<AttachedComponentTag> {
+        match tpm_at {
+            TPM2_AT_ANY => Ok(AttachedComponentTag::Any),
+            TPM2_AT_ERROR => Ok(AttachedComponentTag::Error),
+            TPM2_AT_PV1 => Ok(AttachedComponentTag::Pv1),
+            _ if tpm_at >= TPM2_AT_VEND => Ok(AttachedComponentTag::Vendor(
+                VendorAttachedComponentTag(tpm_at),
+            )),
+            _ => {
+                error!("value = {} did not match any AttachedComponentTag.", tpm_at);
+                Err(Error::local_error(WrapperErrorKind::InvalidParam))
+            }
+        }
+    }
+}
@@ -59,7 +59,22 @@ pub mod command_code;
 /// Constants -> TPM_PT_PCR section of the specification.
 pub mod pcr_property_tag;
 
+/// Representation of the constants defined in
+/// Constants -> TPM_EO section of the specification.
+pub mod arithmetic_operation;
+
+/// Representation of the constants defined in
+/// Constants -> TPM_CLOCK_ADJUST section of the specification.
+pub mod clock_adjust;
+
+/// Representation of the constants defined in
+/// Constants -> TPM_AT section of the specification.
+pub mod attached_component_tag;
+
+pub use arithmetic_operation::ArithmeticOperation;
+pub use attached_component_tag::{AttachedComponentTag, VendorAttachedComponentTag};
 pub use capabilities::CapabilityType;
+pub use clock_adjust::ClockAdjust;
 pub use command_code::CommandCode;
 pub use ecc::EccCurveIdentifier;
 pub use nv_index_type::NvIndexType;
@@ -3,10 +3,10 @@
 mod handle_manager;
 use crate::{
     attributes::SessionAttributesBuilder,
-    constants::{CapabilityType, PropertyTag, SessionType},
+    constants::{tss::TPM2_CC_FIRST, CapabilityType, PropertyTag, SessionType},
     handles::{ObjectHandle, SessionHandle},
     interface_types::{algorithm::HashingAlgorithm, session_handles::AuthSession},
-    structures::{CapabilityData, SymmetricDefinition},
+    structures::{CapabilityData, CommandCodeList, SymmetricDefinition},
     tcti_ldr::{TabrmdConfig, TctiContext, TctiNameConf},
     tss2_esys::*,
     Error, Result, ReturnCode, WrapperErrorKind as ErrorKind,
@@ -15,7 +15,8 @@ use handle_manager::HandleManager;
 use log::{error, info};
 use mbox::MBox;
 use std::collections::HashMap;
-use std::ptr::null_mut;
+use std::convert::TryFrom;
+use std::ptr::{null, null_mut};
 
 /// Safe abstraction over an ESYS_CONTEXT.
 ///
@@ -70,6 +71,9 @@ mod tpm_commands;
 mod session_administration;
 // Implementation of the general ESAPI ESYS_TR functions
 mod general_esys_tr;
+// Implementation of the automatic retry of commands
+// that require a self test.
+mod self_test_retry;
 
 impl Context {
     /// Create a new ESYS context based on the desired TCTI
@@ -382,6 +386,63 @@ impl Context {
         Ok(None)
     }
 
+    /// Returns the commands that require physical presence
+    /// for platform authorization.
+    ///
+    /// # Details
+    /// The list is read using [CapabilityType::PpCommands] and can be
+    /// changed with [Context::pp_commands].
+    ///
+    /// # Errors
+    /// * if the TPM returns capability data for another capability than
+    ///   the one requested, a `WrongValueFromTpm` wrapper error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use tss_esapi::{Context, tcti_ldr::TctiNameConf};
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// let pp_commands = context
+    ///     .get_pp_commands()
+    ///     .expect("Failed to get PP commands");
+    /// ```
+    pub fn get_pp_commands(&mut self) -> Result<CommandCodeList> {
+        let mut pp_commands = CommandCodeList::new();
+        let mut property = TPM2_CC_FIRST;
+        loop {
+            let (capabs, more_data) = self.execute_without_session(|ctx| {
+                ctx.get_capability(
+                    CapabilityType::PpCommands,
+                    property,
+                    CommandCodeList::MAX_SIZE as u32,
+                )
+            })?;
+
+            let command_codes = match capabs {
+                CapabilityData::PpCommands(command_codes) => command_codes.into_inner(),
+                _ => return Err(Error::WrapperError(ErrorKind::WrongValueFromTpm)),
+            };
+
+            let last_command_code = match command_codes.last() {
+                Some(&command_code) => command_code,
+                None => break,
+            };
+            for command_code in command_codes {
+                pp_commands.add(command_code)?;
+            }
+
+            if !more_data {
+                break;
+            }
+            property = TPM2_CC::from(last_command_code) + 1;
+        }
+        Ok(pp_commands)
+    }
+
     // ////////////////////////////////////////////////////////////////////////
     //  Private Methods Section
     // ////////////////////////////////////////////////////////////////////////
@@ -443,6 +504,74 @@ impl Context {
     fn ffi_data_to_owned<T>(data_ptr: *mut T) -> T {
         MBox::into_inner(unsafe { MBox::from_raw(data_ptr) })
     }
+
+    /// Returns a copy of the response parameter area of the last
+    /// command that was executed through the context.
+    ///
+    /// # Details
+    /// The data is read from the SYS context that is used by the
+    /// ESYS context, so it is only valid until the next command is sent.
+    pub(crate) fn last_response_parameters(&mut self) -> Result<Vec<u8>> {
+        let sys_context = self.sys_context()?;
+        let mut rp_buffer_size = 0;
+        let mut rp_buffer = null();
+        ReturnCode::ensure_success(
+            unsafe { Tss2_Sys_GetRpBuffer(sys_context, &mut rp_buffer_size, &mut rp_buffer) },
+            |ret| {
+                error!("Error when getting response parameters: {:#010X}", ret);
+            },
+        )?;
+        if rp_buffer.is_null() || rp_buffer_size == 0 {
+            return Ok(Vec::new());
+        }
+        let rp_buffer_size = usize::try_from(rp_buffer_size).map_err(|_| {
+            error!("Invalid response parameters size");
+            Error::local_error(ErrorKind::InternalError)
+        })?;
+        Ok(unsafe { std::slice::from_raw_parts(rp_buffer, rp_buffer_size) }.to_vec())
+    }
+
+    /// Returns the command code of the last command that was
+    /// executed through the context.
+    ///
+    /// # Details
+    /// The command code is read from the SYS context that is used by the
+    /// ESYS context, so it changes when the next command is sent.
+    pub(crate) fn last_command_code(&mut self) -> Result<TPM2_CC> {
+        let sys_context = self.sys_context()?;
+        let mut command_code = [0u8; 4];
+        ReturnCode::ensure_success(
+            unsafe { Tss2_Sys_GetCommandCode(sys_context, command_code.as_mut_ptr()) },
+            |ret| {
+                error!("Error when getting the command code: {:#010X}", ret);
+            },
+        )?;
+        Ok(TPM2_CC::from_be_bytes(command_code))
+    }
+
+    /// Returns the SYS context that is used by the ESYS context.
+    fn sys_context(&mut self) -> Result<*mut TSS2_SYS_CONTEXT> {
+        let mut sys_context = null_mut();
+        ReturnCode::ensure_success(
+            unsafe { Esys_GetSysContext(self.mut_context(), &mut sys_context) },
+            |ret| {
+                error!("Error when getting SYS context: {:#010X}", ret);
+            },
+        )?;
+        Ok(sys_context)
+    }
+
+    /// Returns the TCTI context used by the ESYS context.
+    fn tcti_context(&mut self) -> Result<*mut TSS2_TCTI_CONTEXT> {
+        let mut tcti_context = null_mut();
+        ReturnCode::ensure_success(
+            unsafe { Esys_GetTcti(self.mut_context(), &mut tcti_context) },
+            |ret| {
+                error!("Error when getting TCTI context: {:#010X}", ret);
+            },
+        )?;
+        Ok(tcti_context)
+    }
 }
 
 impl Drop for Context {
@@ -470,6 +599,11 @@ impl Drop for Context {
             error!("Not all handles have had their resources successfully released");
         }
 
+        // Restore the TCTI functions replaced by the self test retry mode.
+        if let Err(e) = self.remove_self_test_retry_hook() {
+            error!("Error when dropping the context: {}", e);
+        }
+
         // Close the context.
         unsafe {
             Esys_Finalize(
@@ -6,11 +6,14 @@ use crate::{
     handles::{handle_conversion::TryIntoNotNone, TpmHandle},
     structures::Auth,
     structures::Name,
-    tss2_esys::{Esys_TR_Close, Esys_TR_FromTPMPublic, Esys_TR_GetName, Esys_TR_SetAuth},
-    Context, Result, ReturnCode,
+    tss2_esys::{
+        Esys_Free, Esys_TR_Close, Esys_TR_Deserialize, Esys_TR_FromTPMPublic, Esys_TR_GetName,
+        Esys_TR_Serialize, Esys_TR_SetAuth,
+    },
+    Context, Error, Result, ReturnCode, WrapperErrorKind,
 };
 use log::error;
-use std::convert::TryFrom;
+use std::convert::{TryFrom, TryInto};
 use std::ptr::null_mut;
 use zeroize::Zeroize;
 
@@ -388,6 +391,131 @@ impl Context {
         TpmHandle::try_from(tpm_handle)
     }
 
-    // Missing function: Esys_TR_Serialize
-    // Missing function: Esys_TR_Deserialize
+    /// Serializes the metadata of an ESYS handle.
+    ///
+    /// # Details
+    /// The metadata contains the TPM handle, the name and, for keys and NV
+    /// indices, the public area of the object. It can be stored and used to
+    /// restore the ESYS handle with [Context::tr_deserialize] in another
+    /// context, which avoids having to trust the name reported by the TPM
+    /// when the handle is recreated using [Context::tr_from_tpm_public].
+    ///
+    /// The authorization value of the object is not part of the metadata.
+    ///
+    /// # Arguments
+    /// * `object_handle` - The [ObjectHandle] whose metadata is to be serialized.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{
+    /// #     Context, TctiNameConf, attributes::NvIndexAttributes, handles::NvIndexTpmHandle,
+    /// #     interface_types::{
+    /// #         algorithm::HashingAlgorithm, resource_handles::Provision,
+    /// #         session_handles::AuthSession,
+    /// #     },
+    /// #     structures::NvPublic,
+    /// # };
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// # context.set_sessions((Some(AuthSession::Password), None, None));
+    /// # let nv_index = NvIndexTpmHandle::new(0x01500403)
+    /// #     .expect("Failed to create NV index tpm handle");
+    /// # let nv_public = NvPublic::builder()
+    /// #     .with_nv_index(nv_index)
+    /// #     .with_index_name_algorithm(HashingAlgorithm::Sha256)
+    /// #     .with_index_attributes(
+    /// #         NvIndexAttributes::builder()
+    /// #             .with_owner_write(true)
+    /// #             .with_owner_read(true)
+    /// #             .build()
+    /// #             .expect("Failed to create owner nv index attributes"),
+    /// #     )
+    /// #     .with_data_area_size(32)
+    /// #     .build()
+    /// #     .expect("Failed to build NvPublic");
+    /// let nv_index_handle = context
+    ///     .nv_define_space(Provision::Owner, None, nv_public)
+    ///     .expect("Call to nv_define_space failed");
+    ///
+    /// let serialized = context
+    ///     .tr_serialize(nv_index_handle.into())
+    ///     .expect("Call to tr_serialize failed");
+    /// # context
+    /// #    .nv_undefine_space(Provision::Owner, nv_index_handle)
+    /// #    .expect("Call to nv_undefine_space failed");
+    /// ```
+    pub fn tr_serialize(&mut self, object_handle: ObjectHandle) -> Result<Vec<u8>> {
+        let mut buffer_ptr = null_mut();
+        let mut buffer_size = 0;
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_TR_Serialize(
+                    self.mut_context(),
+                    object_handle.into(),
+                    &mut buffer_ptr,
+                    &mut buffer_size,
+                )
+            },
+            |ret| {
+                error!("Error when serializing ESYS handle: {:#010X}", ret);
+            },
+        )?;
+        let buffer = usize::try_from(buffer_size)
+            .map(|len| unsafe { std::slice::from_raw_parts(buffer_ptr, len) }.to_vec())
+            .map_err(|e| {
+                error!("Failed to convert the buffer size to usize: {}", e);
+                Error::local_error(WrapperErrorKind::InternalError)
+            });
+        unsafe { Esys_Free(buffer_ptr.cast()) };
+        buffer
+    }
+
+    /// Restores an ESYS handle from serialized metadata.
+    ///
+    /// # Details
+    /// The returned handle is registered with the context in the same way
+    /// as handles returned by [Context::tr_from_tpm_public], so handles to
+    /// transient objects have to be flushed and other handles closed.
+    ///
+    /// # Arguments
+    /// * `buffer` - The metadata returned by [Context::tr_serialize].
+    ///
+    /// # Errors
+    /// * if the buffer does not contain valid metadata, an ESAPI error is returned.
+    pub fn tr_deserialize(&mut self, buffer: &[u8]) -> Result<ObjectHandle> {
+        let mut object = ObjectHandle::None.into();
+        let buffer_size = buffer.len().try_into().map_err(|e| {
+            error!("Failed to convert the buffer length to size_t: {}", e);
+            Error::local_error(WrapperErrorKind::InvalidParam)
+        })?;
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_TR_Deserialize(
+                    self.mut_context(),
+                    buffer.as_ptr(),
+                    buffer_size,
+                    &mut object,
+                )
+            },
+            |ret| {
+                error!("Error when deserializing ESYS handle: {:#010X}", ret);
+            },
+        )?;
+        // The serialized metadata starts with the TPM handle of the object.
+        let handle_drop_action = match buffer
+            .get(..4)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(u32::from_be_bytes)
+            .map(TpmHandle::try_from)
+        {
+            Some(Ok(tpm_handle)) if tpm_handle.may_be_flushed() => HandleDropAction::Flush,
+            _ => HandleDropAction::Close,
+        };
+        self.handle_manager
+            .add_handle(object.into(), handle_drop_action)?;
+        Ok(object.into())
+    }
 }
@@ -0,0 +1,438 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{
+    constants::tss::{
+        TPM2_CC_SelfTest, TPM2_RC_NEEDS_TEST, TPM2_RC_SUCCESS, TPM2_RC_TESTING,
+        TPM2_ST_NO_SESSIONS, TSS2_BASE_RC_BAD_VALUE, TSS2_BASE_RC_NOT_IMPLEMENTED,
+        TSS2_TCTI_RC_LAYER,
+    },
+    tss2_esys::{
+        size_t, TSS2_RC, TSS2_TCTI_CONTEXT, TSS2_TCTI_CONTEXT_COMMON_V1, TSS2_TCTI_RECEIVE_FCN,
+        TSS2_TCTI_TIMEOUT_BLOCK, TSS2_TCTI_TRANSMIT_FCN,
+    },
+    Context, Result,
+};
+use log::info;
+use std::convert::TryFrom;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::thread;
+use std::time::Duration;
+
+/// The number of times a command is retried when the TPM requires a self test.
+const SELF_TEST_RETRY_LIMIT: u32 = 3;
+
+/// The delay before the first retry. The delay is doubled for every following retry.
+const SELF_TEST_RETRY_DELAY: Duration = Duration::from_millis(50);
+
+impl Context {
+    /// Enables or disables the automatic retry of commands that fail
+    /// because the TPM has to perform a self test.
+    ///
+    /// # Details
+    /// After a cold boot the TPM may not have tested all of its algorithms yet.
+    /// Commands that use an untested algorithm then fail with `TPM_RC_NEEDS_TEST`,
+    /// or with `TPM_RC_TESTING` while the tests are being performed.
+    ///
+    /// When enabled, every command sent through the context that fails with one
+    /// of these response codes is sent again. Before a command that failed with
+    /// `TPM_RC_NEEDS_TEST` is sent again, the TPM is asked to test the algorithms
+    /// that have not been tested yet using TPM2_SelfTest, since the algorithms used by
+    /// the command are not known to the context. The first retry is delayed
+    /// by 50 ms and the delay is doubled for every following retry. A command is
+    /// retried at most three times before the error is returned to the caller.
+    ///
+    /// The retry mode is disabled by default.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{Context, TctiNameConf};
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// context
+    ///     .set_self_test_retry(true)
+    ///     .expect("Failed to enable the self test retry mode");
+    /// let random_digest = context
+    ///     .get_random(16)
+    ///     .expect("Failed to get random bytes");
+    /// ```
+    pub fn set_self_test_retry(&mut self, enabled: bool) -> Result<()> {
+        let tcti_context = self.tcti_context()?;
+        let mut hooks = lock_hooks();
+        if let Some(hook) = hooks
+            .iter_mut()
+            .find(|hook| hook.tcti_context == tcti_context as usize)
+        {
+            hook.enabled = enabled;
+        } else if enabled {
+            let common = tcti_context as *mut TSS2_TCTI_CONTEXT_COMMON_V1;
+            unsafe {
+                hooks.push(SelfTestRetryHook {
+                    tcti_context: tcti_context as usize,
+                    enabled,
+                    transmit: (*common).transmit,
+                    receive: (*common).receive,
+                    command: Vec::new(),
+                });
+                (*common).transmit = Some(retrying_transmit);
+                (*common).receive = Some(retrying_receive);
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes the self test retry hook from the TCTI of the context
+    /// if it has been installed.
+    pub(crate) fn remove_self_test_retry_hook(&mut self) -> Result<()> {
+        let tcti_context = self.tcti_context()?;
+        let mut hooks = lock_hooks();
+        if let Some(index) = hooks
+            .iter()
+            .position(|hook| hook.tcti_context == tcti_context as usize)
+        {
+            let hook = hooks.remove(index);
+            let common = tcti_context as *mut TSS2_TCTI_CONTEXT_COMMON_V1;
+            unsafe {
+                (*common).transmit = hook.transmit;
+                (*common).receive = hook.receive;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The functions of a TCTI context that have been replaced in order to retry
+/// commands and the last command that was sent through it.
+#[derive(Debug)]
+struct SelfTestRetryHook {
+    tcti_context: usize,
+    enabled: bool,
+    transmit: TSS2_TCTI_TRANSMIT_FCN,
+    receive: TSS2_TCTI_RECEIVE_FCN,
+    command: Vec<u8>,
+}
+
+/// The installed hooks. The TCTI functions are not given any user data,
+/// so the hooks are looked up using the TCTI context.
+static SELF_TEST_RETRY_HOOKS: Mutex<Vec<SelfTestRetryHook>> = Mutex::new(Vec::new());
+
+fn lock_hooks() -> MutexGuard<'static, Vec<SelfTestRetryHook>> {
+    SELF_TEST_RETRY_HOOKS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Calls `f` with the hook of the TCTI context if one has been installed.
+fn with_hook<F, T>(tcti_context: *mut TSS2_TCTI_CONTEXT, f: F) -> Option<T>
+where
+    F: FnOnce(&mut SelfTestRetryHook) -> T,
+{
+    lock_hooks()
+        .iter_mut()
+        .find(|hook| hook.tcti_context == tcti_context as usize)
+        .map(f)
+}
+
+/// Transmit function that records the command so that it can be sent again.
+unsafe extern "C" fn retrying_transmit(
+    tcti_context: *mut TSS2_TCTI_CONTEXT,
+    size: size_t,
+    command: *const u8,
+) -> TSS2_RC {
+    let transmit = with_hook(tcti_context, |hook| {
+        hook.command = match usize::try_from(size) {
+            Ok(size) if hook.enabled && !command.is_null() => {
+                std::slice::from_raw_parts(command, size).to_vec()
+            }
+            _ => Vec::new(),
+        };
+        hook.transmit
+    });
+    match transmit.flatten() {
+        Some(transmit) => transmit(tcti_context, size, command),
+        None => TSS2_TCTI_RC_LAYER | TSS2_BASE_RC_NOT_IMPLEMENTED,
+    }
+}
+
+/// Receive function that sends the last command again if the TPM
+/// responded with `TPM_RC_NEEDS_TEST` or `TPM_RC_TESTING`.
+unsafe extern "C" fn retrying_receive(
+    tcti_context: *mut TSS2_TCTI_CONTEXT,
+    size: *mut size_t,
+    response: *mut u8,
+    timeout: i32,
+) -> TSS2_RC {
+    let (transmit, receive, command) = match with_hook(tcti_context, |hook| {
+        (hook.transmit, hook.receive, hook.command.clone())
+    }) {
+        Some((transmit, Some(receive), command)) => (transmit, receive, command),
+        _ => return TSS2_TCTI_RC_LAYER | TSS2_BASE_RC_NOT_IMPLEMENTED,
+    };
+    // The size is only queried when no response buffer is provided.
+    if size.is_null() || response.is_null() {
+        return receive(tcti_context, size, response, timeout);
+    }
+    match transmit {
+        Some(transmit) if !command.is_empty() => receive_with_retry(&mut TctiTransport {
+            tcti_context,
+            transmit,
+            receive,
+            command,
+            capacity: *size,
+            size,
+            response,
+            timeout,
+        }),
+        _ => receive(tcti_context, size, response, timeout),
+    }
+}
+
+/// The operations used to receive a response and to send a command again.
+///
+/// The retry logic in [receive_with_retry] only uses this trait so that
+/// it can be tested without a TPM.
+trait RetryTransport {
+    /// Receives the response to the last command that was sent.
+    fn receive(&mut self) -> TSS2_RC;
+
+    /// Returns the response code of the last response that was received.
+    fn response_code(&self) -> Option<u32>;
+
+    /// Sends the last command again.
+    fn resend(&mut self) -> TSS2_RC;
+
+    /// Asks the TPM to test the algorithms that have not been tested
+    /// yet and returns the response code.
+    fn self_test(&mut self) -> TSS2_RC;
+
+    /// Waits before the command is sent again.
+    fn sleep(&mut self, delay: Duration);
+}
+
+/// Receives the response to a command and sends the command again while the
+/// TPM responds with `TPM_RC_NEEDS_TEST` or `TPM_RC_TESTING`, at most
+/// [SELF_TEST_RETRY_LIMIT] times.
+///
+/// Returns the TCTI return code of the last receive. The last response is left
+/// in place, so the error is returned to the caller once the limit is reached.
+fn receive_with_retry<T: RetryTransport>(transport: &mut T) -> TSS2_RC {
+    let mut rc = transport.receive();
+    let mut retries = 0;
+    while rc == TPM2_RC_SUCCESS && retries < SELF_TEST_RETRY_LIMIT {
+        match transport.response_code() {
+            Some(TPM2_RC_NEEDS_TEST) => {
+                // TPM2_IncrementalSelfTest would require the algorithms used by the
+                // command, which are not known here, so TPM2_SelfTest is used instead.
+                // It fails with TPM_RC_TESTING while the tests are being performed,
+                // which is handled by retrying the command after a delay.
+                let self_test_rc = transport.self_test();
+                if self_test_rc != TPM2_RC_SUCCESS {
+                    info!("Self test returned {:#010X}", self_test_rc);
+                }
+            }
+            Some(TPM2_RC_TESTING) => {}
+            _ => break,
+        }
+        transport.sleep(SELF_TEST_RETRY_DELAY * 2u32.pow(retries));
+        retries += 1;
+        info!(
+            "Command requires a self test, sending it again (attempt {})",
+            retries
+        );
+        rc = transport.resend();
+        if rc != TPM2_RC_SUCCESS {
+            return rc;
+        }
+        rc = transport.receive();
+    }
+    rc
+}
+
+/// [RetryTransport] using the functions of a TCTI context.
+struct TctiTransport {
+    tcti_context: *mut TSS2_TCTI_CONTEXT,
+    transmit: unsafe extern "C" fn(*mut TSS2_TCTI_CONTEXT, size_t, *const u8) -> TSS2_RC,
+    receive: unsafe extern "C" fn(*mut TSS2_TCTI_CONTEXT, *mut size_t, *mut u8, i32) -> TSS2_RC,
+    command: Vec<u8>,
+    capacity: size_t,
+    size: *mut size_t,
+    response: *mut u8,
+    timeout: i32,
+}
+
+impl RetryTransport for TctiTransport {
+    fn receive(&mut self) -> TSS2_RC {
+        unsafe {
+            *self.size = self.capacity;
+            (self.receive)(self.tcti_context, self.size, self.response, self.timeout)
+        }
+    }
+
+    fn response_code(&self) -> Option<u32> {
+        unsafe { response_code(self.response, *self.size) }
+    }
+
+    fn resend(&mut self) -> TSS2_RC {
+        match size_t::try_from(self.command.len()) {
+            Ok(command_size) => unsafe {
+                (self.transmit)(self.tcti_context, command_size, self.command.as_ptr())
+            },
+            Err(_) => TSS2_TCTI_RC_LAYER | TSS2_BASE_RC_BAD_VALUE,
+        }
+    }
+
+    fn self_test(&mut self) -> TSS2_RC {
+        unsafe { self_test(self.tcti_context, self.transmit, self.receive) }
+    }
+
+    fn sleep(&mut self, delay: Duration) {
+        thread::sleep(delay);
+    }
+}
+
+/// Reads the response code from the header of a response.
+unsafe fn response_code(response: *const u8, size: size_t) -> Option<u32> {
+    if size < 10 {
+        return None;
+    }
+    let response_code = std::slice::from_raw_parts(response.add(6), 4);
+    Some(u32::from_be_bytes([
+        response_code[0],
+        response_code[1],
+        response_code[2],
+        response_code[3],
+    ]))
+}
+
+/// Sends TPM2_SelfTest with fullTest set to NO, which makes the TPM
+/// test the algorithms that have not been tested yet.
+unsafe fn self_test(
+    tcti_context: *mut TSS2_TCTI_CONTEXT,
+    transmit: unsafe extern "C" fn(*mut TSS2_TCTI_CONTEXT, size_t, *const u8) -> TSS2_RC,
+    receive: unsafe extern "C" fn(*mut TSS2_TCTI_CONTEXT, *mut size_t, *mut u8, i32) -> TSS2_RC,
+) -> TSS2_RC {
+    let mut command = TPM2_ST_NO_SESSIONS.to_be_bytes().to_vec();
+    command.extend_from_slice(&11u32.to_be_bytes());
+    command.extend_from_slice(&TPM2_CC_SelfTest.to_be_bytes());
+    command.push(0);
+    let rc = transmit(tcti_context, command.len() as size_t, command.as_ptr());
+    if rc != TPM2_RC_SUCCESS {
+        return rc;
+    }
+    let mut response = [0u8; 64];
+    let mut size = response.len() as size_t;
+    let rc = receive(
+        tcti_context,
+        &mut size,
+        response.as_mut_ptr(),
+        TSS2_TCTI_TIMEOUT_BLOCK,
+    );
+    if rc != TPM2_RC_SUCCESS {
+        return rc;
+    }
+    response_code(response.as_ptr(), size).unwrap_or(TPM2_RC_SUCCESS)
+}
+
+// /////////////////////////////////////////////////////////////////////////
+// UNIT TESTS FOR THE SELF TEST RETRY
+//
+// These unit tests needs to be here because the retry logic is internal to
+// the crate and the TPM cannot be made to require a self test in the
+// integration tests.
+// /////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+#[derive(Debug, Default)]
+struct CannedTransport {
+    responses: std::collections::VecDeque<u32>,
+    response_code: Option<u32>,
+    resends: u32,
+    self_tests: u32,
+    delays: Vec<Duration>,
+}
+
+#[cfg(test)]
+impl CannedTransport {
+    fn new(responses: &[u32]) -> Self {
+        CannedTransport {
+            responses: responses.iter().copied().collect(),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+impl RetryTransport for CannedTransport {
+    fn receive(&mut self) -> TSS2_RC {
+        self.response_code = self.responses.pop_front();
+        TPM2_RC_SUCCESS
+    }
+
+    fn response_code(&self) -> Option<u32> {
+        self.response_code
+    }
+
+    fn resend(&mut self) -> TSS2_RC {
+        self.resends += 1;
+        TPM2_RC_SUCCESS
+    }
+
+    fn self_test(&mut self) -> TSS2_RC {
+        self.self_tests += 1;
+        TPM2_RC_TESTING
+    }
+
+    fn sleep(&mut self, delay: Duration) {
+        self.delays.push(delay);
+    }
+}
+
+#[test]
+fn test_retry_on_needs_test() {
+    let mut transport = CannedTransport::new(&[TPM2_RC_NEEDS_TEST, TPM2_RC_SUCCESS]);
+    assert_eq!(receive_with_retry(&mut transport), TPM2_RC_SUCCESS);
+    assert_eq!(transport.response_code, Some(TPM2_RC_SUCCESS));
+    assert_eq!(transport.resends, 1);
+    assert_eq!(transport.self_tests, 1);
+    assert_eq!(transport.delays, [SELF_TEST_RETRY_DELAY]);
+}
+
+#[test]
+fn test_retry_on_testing_with_backoff() {
+    let mut transport = CannedTransport::new(&[TPM2_RC_TESTING, TPM2_RC_TESTING, TPM2_RC_SUCCESS]);
+    assert_eq!(receive_with_retry(&mut transport), TPM2_RC_SUCCESS);
+    assert_eq!(transport.response_code, Some(TPM2_RC_SUCCESS));
+    assert_eq!(transport.resends, 2);
+    assert_eq!(transport.self_tests, 0);
+    assert_eq!(
+        transport.delays,
+        [SELF_TEST_RETRY_DELAY, SELF_TEST_RETRY_DELAY * 2]
+    );
+}
+
+#[test]
+fn test_retry_limit() {
+    let mut transport = CannedTransport::new(&[TPM2_RC_NEEDS_TEST; 5]);
+    assert_eq!(receive_with_retry(&mut transport), TPM2_RC_SUCCESS);
+    // The response to the last attempt is returned to the caller.
+    assert_eq!(transport.response_code, Some(TPM2_RC_NEEDS_TEST));
+    assert_eq!(transport.resends, SELF_TEST_RETRY_LIMIT);
+    assert_eq!(transport.self_tests, SELF_TEST_RETRY_LIMIT);
+    assert_eq!(
+        transport.delays,
+        [
+            SELF_TEST_RETRY_DELAY,
+            SELF_TEST_RETRY_DELAY * 2,
+            SELF_TEST_RETRY_DELAY * 4
+        ]
+    );
+}
+
+#[test]
+fn test_no_retry_on_other_response_codes() {
+    let mut transport = CannedTransport::new(&[TPM2_RC_SUCCESS, TPM2_RC_SUCCESS]);
+    assert_eq!(receive_with_retry(&mut transport), TPM2_RC_SUCCESS);
+    assert_eq!(transport.resends, 0);
+    assert!(transport.delays.is_empty());
+}
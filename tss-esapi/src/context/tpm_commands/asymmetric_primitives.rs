@@ -2,9 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
     handles::KeyHandle,
+    interface_types::{algorithm::EccSchemeAlgorithm, ecc::EccCurve},
     structures::Data,
-    structures::{EccPoint, PublicKeyRsa, RsaDecryptionScheme},
-    tss2_esys::{Esys_ECDH_KeyGen, Esys_ECDH_ZGen, Esys_RSA_Decrypt, Esys_RSA_Encrypt},
+    structures::{AlgorithmDetailEcc, EccPoint, PublicKeyRsa, RsaDecryptionScheme},
+    tss2_esys::{
+        Esys_ECC_Parameters, Esys_ECDH_KeyGen, Esys_ECDH_ZGen, Esys_RSA_Decrypt, Esys_RSA_Encrypt,
+        Esys_ZGen_2Phase,
+    },
     Context, Result, ReturnCode,
 };
 use log::error;
@@ -337,6 +341,103 @@ impl Context {
         EccPoint::try_from(out_point.point)
     }
 
-    // Missing function: ECC_Parameters
-    // Missing function: ZGen_2Phase
+    /// Get the parameters of an ECC curve.
+    ///
+    /// # Arguments
+    /// * `curve` - The curve for which the parameters are requested.
+    ///
+    /// # Details
+    /// The curves supported by the TPM can be listed by using
+    /// [Context::get_capability] with the EccCurves capability.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{Context, TctiNameConf, interface_types::ecc::EccCurve};
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// let parameters = context
+    ///     .ecc_parameters(EccCurve::NistP256)
+    ///     .expect("Failed to get ECC parameters");
+    /// assert_eq!(parameters.key_size(), 256);
+    /// ```
+    pub fn ecc_parameters(&mut self, curve: EccCurve) -> Result<AlgorithmDetailEcc> {
+        let mut parameters_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_ECC_Parameters(
+                    self.mut_context(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    curve.into(),
+                    &mut parameters_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when getting ECC parameters: {:#010X}", ret);
+            },
+        )?;
+        AlgorithmDetailEcc::try_from(Context::ffi_data_to_owned(parameters_ptr))
+    }
+
+    /// Performs the second phase of a two-phase key exchange.
+    ///
+    /// # Details
+    /// The TPM combines the static key `key_a` and the ephemeral key
+    /// created by [Context::ec_ephemeral] with the public keys of the
+    /// other party in order to produce the two shared secret points.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `key_a` - A handle to an unrestricted ECC decryption key.
+    /// * `in_qs_b` - The static public key of the other party.
+    /// * `in_qe_b` - The ephemeral public key of the other party.
+    /// * `in_scheme` - The key exchange scheme, i.e. [EccSchemeAlgorithm::EcDh],
+    ///   [EccSchemeAlgorithm::EcMqv] or [EccSchemeAlgorithm::Sm2].
+    /// * `counter` - The counter value returned by [Context::ec_ephemeral].
+    ///
+    /// # Returns
+    /// A tuple containing the points Z1 and Z2.
+    pub fn zgen_2phase(
+        &mut self,
+        key_a: KeyHandle,
+        in_qs_b: EccPoint,
+        in_qe_b: EccPoint,
+        in_scheme: EccSchemeAlgorithm,
+        counter: u16,
+    ) -> Result<(EccPoint, EccPoint)> {
+        let mut out_z1_ptr = null_mut();
+        let mut out_z2_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_ZGen_2Phase(
+                    self.mut_context(),
+                    key_a.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &in_qs_b.into(),
+                    &in_qe_b.into(),
+                    in_scheme.into(),
+                    counter,
+                    &mut out_z1_ptr,
+                    &mut out_z2_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when performing ZGen 2Phase: {:#010X}", ret);
+            },
+        )?;
+        let out_z1 = Context::ffi_data_to_owned(out_z1_ptr);
+        let out_z2 = Context::ffi_data_to_owned(out_z2_ptr);
+        Ok((
+            EccPoint::try_from(out_z1.point)?,
+            EccPoint::try_from(out_z2.point)?,
+        ))
+    }
 }
@@ -1,9 +1,155 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use crate::Context;
+#[cfg(has_esys_attached_components)]
+use crate::{
+    constants::AttachedComponentTag,
+    handles::{AcHandle, AuthHandle, ObjectHandle, SessionHandle},
+    interface_types::{resource_handles::NvAuth, session_handles::PolicySession, YesNo},
+    structures::{AcCapabilityList, AcOutput, MaxBuffer, Name},
+    tss2_esys::{Esys_AC_GetCapability, Esys_AC_Send, Esys_Policy_AC_SendSelect},
+    Result, ReturnCode,
+};
+#[cfg(has_esys_attached_components)]
+use log::error;
+#[cfg(has_esys_attached_components)]
+use std::{convert::TryFrom, ptr::null_mut};
 
 impl Context {
-    // Missing function: AC_GetCapability
-    // Missing function: AC_Send
-    // Missing function: Policy_AC_SendSelect
+    /// Reads the capabilities of an attached component.
+    ///
+    /// # Arguments
+    /// * `ac` - The [AcHandle] of the attached component.
+    /// * `capability` - The [AttachedComponentTag] of the first data that is to be returned.
+    /// * `count` - The maximum number of values to return.
+    ///
+    /// # Returns
+    /// A tuple containing the data returned by the attached component and
+    /// a flag that indicates if there is more data available.
+    #[cfg(has_esys_attached_components)]
+    pub fn ac_get_capability(
+        &mut self,
+        ac: AcHandle,
+        capability: AttachedComponentTag,
+        count: u32,
+    ) -> Result<(AcCapabilityList, bool)> {
+        let mut more_data = YesNo::No.into();
+        let mut capability_data_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_AC_GetCapability(
+                    self.mut_context(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    ac.into(),
+                    capability.into(),
+                    count,
+                    &mut more_data,
+                    &mut capability_data_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when getting AC capability: {:#010X}", ret);
+            },
+        )?;
+        Ok((
+            AcCapabilityList::try_from(Context::ffi_data_to_owned(capability_data_ptr))?,
+            YesNo::try_from(more_data)?.into(),
+        ))
+    }
+
+    /// Sends an object to an attached component.
+    ///
+    /// # Details
+    /// The TPM sends the object referred to by `send_object` to the attached
+    /// component. The use of the attached component may be restricted by a
+    /// policy that includes TPM2_Policy_AC_SendSelect, see
+    /// [Context::policy_ac_send_select].
+    ///
+    /// Please beware that this method requires two authorization
+    /// session handles to be present.
+    ///
+    /// # Arguments
+    /// * `send_object` - The object that is to be sent.
+    /// * `nv_auth_handle` - The handle used for authorization of the NV index
+    ///   associated with the attached component.
+    /// * `ac` - The [AcHandle] of the attached component.
+    /// * `ac_data_in` - Optional non sensitive data that is sent with the object.
+    ///
+    /// # Returns
+    /// The output from the attached component.
+    #[cfg(has_esys_attached_components)]
+    pub fn ac_send(
+        &mut self,
+        send_object: ObjectHandle,
+        nv_auth_handle: NvAuth,
+        ac: AcHandle,
+        ac_data_in: MaxBuffer,
+    ) -> Result<AcOutput> {
+        let mut ac_data_out_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_AC_Send(
+                    self.mut_context(),
+                    send_object.into(),
+                    AuthHandle::from(nv_auth_handle).into(),
+                    ac.into(),
+                    self.required_session_1()?,
+                    self.required_session_2()?,
+                    self.optional_session_3(),
+                    &ac_data_in.into(),
+                    &mut ac_data_out_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when sending to AC: {:#010X}", ret);
+            },
+        )?;
+        AcOutput::try_from(Context::ffi_data_to_owned(ac_data_out_ptr))
+    }
+
+    /// Binds a policy to a specific object, authorizing
+    /// entity and attached component.
+    ///
+    /// # Details
+    /// This restricts the policy to only be used with [Context::ac_send]
+    /// for the object, authorization handle and attached component with
+    /// the provided names.
+    ///
+    /// # Arguments
+    /// * `policy_session` - The [policy session][PolicySession] being extended.
+    /// * `object_name` - The name of the object that is to be sent.
+    /// * `auth_handle_name` - The name of the handle used for authorization.
+    /// * `ac_name` - The name of the attached component.
+    /// * `include_object` - Indicates if `object_name` is to be included in the
+    ///   value of the policy digest.
+    #[cfg(has_esys_attached_components)]
+    pub fn policy_ac_send_select(
+        &mut self,
+        policy_session: PolicySession,
+        object_name: Name,
+        auth_handle_name: Name,
+        ac_name: Name,
+        include_object: bool,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_Policy_AC_SendSelect(
+                    self.mut_context(),
+                    SessionHandle::from(policy_session).into(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &object_name.into(),
+                    &auth_handle_name.into(),
+                    &ac_name.into(),
+                    YesNo::from(include_object).into(),
+                )
+            },
+            |ret| {
+                error!("Error when sending policy AC send select: {:#010X}", ret);
+            },
+        )
+    }
 }
@@ -1,13 +1,22 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
-    handles::{KeyHandle, ObjectHandle},
-    structures::{Attest, AttestBuffer, Data, PcrSelectionList, Signature, SignatureScheme},
-    tss2_esys::{Esys_Certify, Esys_Quote},
+    handles::{AuthHandle, KeyHandle, ObjectHandle, SessionHandle},
+    interface_types::session_handles::{AuthSession, HmacSession},
+    structures::{
+        Attest, AttestBuffer, CreationTicket, Data, Digest, PcrSelectionList, Signature,
+        SignatureScheme,
+    },
+    tss2_esys::{
+        Esys_Certify, Esys_CertifyCreation, Esys_GetCommandAuditDigest, Esys_GetSessionAuditDigest,
+        Esys_GetTime, Esys_Quote,
+    },
     Context, Result, ReturnCode,
 };
+#[cfg(has_esys_certify_x509)]
+use crate::{structures::MaxBuffer, tss2_esys::Esys_CertifyX509};
 use log::error;
-use std::convert::TryFrom;
+use std::convert::{TryFrom, TryInto};
 use std::ptr::null_mut;
 
 impl Context {
@@ -152,7 +161,69 @@ impl Context {
         ))
     }
 
-    // Missing function: CertifyCreation
+    /// Prove the association between an object and its creation data
+    ///
+    /// # Arguments
+    /// * `signing_key_handle` - Handle of the key used to sign the attestation buffer
+    /// * `object_handle` - Handle of the object associated with the creation data
+    /// * `qualifying_data` - Qualifying data
+    /// * `creation_hash` - The hash of the creation data that was produced when the object was created
+    /// * `signing_scheme` - Signing scheme to use if the scheme for `signing_key_handle` is `Null`.
+    /// * `creation_ticket` - The ticket that was produced when the object was created
+    ///
+    /// The `creation_hash` and `creation_ticket` are the values that were returned together with the object
+    /// by [Self::create_primary()] or [Self::create()].
+    ///
+    /// The `signing_key_handle` must be usable for signing and requires an authorization session in session
+    /// slot 1. No authorization is needed for the `object_handle`.
+    ///
+    /// # Returns
+    /// The command returns a tuple consisting of:
+    /// * `attest_data` - TPM-generated attestation data.
+    /// * `signature` - Signature for the attestation data.
+    ///
+    /// # Errors
+    /// * if the qualifying data provided is too long, a `WrongParamSize` wrapper error will be returned
+    pub fn certify_creation(
+        &mut self,
+        signing_key_handle: KeyHandle,
+        object_handle: ObjectHandle,
+        qualifying_data: Data,
+        creation_hash: Digest,
+        signing_scheme: SignatureScheme,
+        creation_ticket: CreationTicket,
+    ) -> Result<(Attest, Signature)> {
+        let mut certify_info_ptr = null_mut();
+        let mut signature_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_CertifyCreation(
+                    self.mut_context(),
+                    signing_key_handle.into(),
+                    object_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &qualifying_data.into(),
+                    &creation_hash.into(),
+                    &signing_scheme.into(),
+                    &creation_ticket.try_into()?,
+                    &mut certify_info_ptr,
+                    &mut signature_ptr,
+                )
+            },
+            |ret| {
+                error!("Error in certifying creation: {:#010X}", ret);
+            },
+        )?;
+
+        let certify_info = Context::ffi_data_to_owned(certify_info_ptr);
+        let signature = Context::ffi_data_to_owned(signature_ptr);
+        Ok((
+            Attest::try_from(AttestBuffer::try_from(certify_info)?)?,
+            Signature::try_from(signature)?,
+        ))
+    }
 
     /// Generate a quote on the selected PCRs
     ///
@@ -195,8 +266,231 @@ impl Context {
         ))
     }
 
-    // Missing function: GetSessionAuditDigest
-    // Missing function: GestCommandAuditDigest
-    // Missing function: GetTime
-    // Missing function: CertifyX509
+    /// Get a signed digest of the commands audited by a session
+    ///
+    /// # Arguments
+    /// * `signing_key_handle` - Handle of the key used to sign the attestation buffer
+    /// * `audit_session` - The session whose audit digest is requested
+    /// * `qualifying_data` - Qualifying data
+    /// * `signing_scheme` - Signing scheme to use if the scheme for `signing_key_handle` is `Null`.
+    ///
+    /// The command requires authorization for the endorsement hierarchy in session slot 1
+    /// and for the `signing_key_handle` in session slot 2.
+    ///
+    /// # Returns
+    /// The command returns a tuple consisting of:
+    /// * `audit_info` - TPM-generated attestation data.
+    /// * `signature` - Signature for the attestation data.
+    ///
+    /// # Errors
+    /// * if the qualifying data provided is too long, a `WrongParamSize` wrapper error will be returned
+    pub fn get_session_audit_digest(
+        &mut self,
+        signing_key_handle: KeyHandle,
+        audit_session: HmacSession,
+        qualifying_data: Data,
+        signing_scheme: SignatureScheme,
+    ) -> Result<(Attest, Signature)> {
+        let mut audit_info_ptr = null_mut();
+        let mut signature_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_GetSessionAuditDigest(
+                    self.mut_context(),
+                    AuthHandle::Endorsement.into(),
+                    signing_key_handle.into(),
+                    SessionHandle::from(AuthSession::from(audit_session)).into(),
+                    self.required_session_1()?,
+                    self.required_session_2()?,
+                    self.optional_session_3(),
+                    &qualifying_data.into(),
+                    &signing_scheme.into(),
+                    &mut audit_info_ptr,
+                    &mut signature_ptr,
+                )
+            },
+            |ret| {
+                error!("Error in getting session audit digest: {:#010X}", ret);
+            },
+        )?;
+
+        let audit_info = Context::ffi_data_to_owned(audit_info_ptr);
+        let signature = Context::ffi_data_to_owned(signature_ptr);
+        Ok((
+            Attest::try_from(AttestBuffer::try_from(audit_info)?)?,
+            Signature::try_from(signature)?,
+        ))
+    }
+
+    /// Get a signed digest of the commands that are audited by the TPM
+    ///
+    /// # Arguments
+    /// * `signing_key_handle` - Handle of the key used to sign the attestation buffer
+    /// * `qualifying_data` - Qualifying data
+    /// * `signing_scheme` - Signing scheme to use if the scheme for `signing_key_handle` is `Null`.
+    ///
+    /// The command requires authorization for the endorsement hierarchy in session slot 1
+    /// and for the `signing_key_handle` in session slot 2.
+    ///
+    /// If the signing key is not the `Null` handle then the audit digest of the TPM is reset
+    /// by the command.
+    ///
+    /// # Returns
+    /// The command returns a tuple consisting of:
+    /// * `audit_info` - TPM-generated attestation data.
+    /// * `signature` - Signature for the attestation data.
+    ///
+    /// # Errors
+    /// * if the qualifying data provided is too long, a `WrongParamSize` wrapper error will be returned
+    pub fn get_command_audit_digest(
+        &mut self,
+        signing_key_handle: KeyHandle,
+        qualifying_data: Data,
+        signing_scheme: SignatureScheme,
+    ) -> Result<(Attest, Signature)> {
+        let mut audit_info_ptr = null_mut();
+        let mut signature_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_GetCommandAuditDigest(
+                    self.mut_context(),
+                    AuthHandle::Endorsement.into(),
+                    signing_key_handle.into(),
+                    self.required_session_1()?,
+                    self.required_session_2()?,
+                    self.optional_session_3(),
+                    &qualifying_data.into(),
+                    &signing_scheme.into(),
+                    &mut audit_info_ptr,
+                    &mut signature_ptr,
+                )
+            },
+            |ret| {
+                error!("Error in getting command audit digest: {:#010X}", ret);
+            },
+        )?;
+
+        let audit_info = Context::ffi_data_to_owned(audit_info_ptr);
+        let signature = Context::ffi_data_to_owned(signature_ptr);
+        Ok((
+            Attest::try_from(AttestBuffer::try_from(audit_info)?)?,
+            Signature::try_from(signature)?,
+        ))
+    }
+
+    /// Get a signed attestation of the current time and clock of the TPM
+    ///
+    /// # Arguments
+    /// * `signing_key_handle` - Handle of the key used to sign the attestation buffer
+    /// * `qualifying_data` - Qualifying data
+    /// * `signing_scheme` - Signing scheme to use if the scheme for `signing_key_handle` is `Null`.
+    ///
+    /// The command requires authorization for the endorsement hierarchy in session slot 1
+    /// and for the `signing_key_handle` in session slot 2.
+    ///
+    /// # Returns
+    /// The command returns a tuple consisting of:
+    /// * `time_info` - TPM-generated attestation data.
+    /// * `signature` - Signature for the attestation data.
+    ///
+    /// # Errors
+    /// * if the qualifying data provided is too long, a `WrongParamSize` wrapper error will be returned
+    pub fn get_time(
+        &mut self,
+        signing_key_handle: KeyHandle,
+        qualifying_data: Data,
+        signing_scheme: SignatureScheme,
+    ) -> Result<(Attest, Signature)> {
+        let mut time_info_ptr = null_mut();
+        let mut signature_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_GetTime(
+                    self.mut_context(),
+                    AuthHandle::Endorsement.into(),
+                    signing_key_handle.into(),
+                    self.required_session_1()?,
+                    self.required_session_2()?,
+                    self.optional_session_3(),
+                    &qualifying_data.into(),
+                    &signing_scheme.into(),
+                    &mut time_info_ptr,
+                    &mut signature_ptr,
+                )
+            },
+            |ret| {
+                error!("Error in getting time: {:#010X}", ret);
+            },
+        )?;
+
+        let time_info = Context::ffi_data_to_owned(time_info_ptr);
+        let signature = Context::ffi_data_to_owned(signature_ptr);
+        Ok((
+            Attest::try_from(AttestBuffer::try_from(time_info)?)?,
+            Signature::try_from(signature)?,
+        ))
+    }
+
+    /// Get an X.509 certificate for an object loaded in the TPM
+    ///
+    /// # Arguments
+    /// * `object_handle` - Handle of the object to be certified
+    /// * `signing_key_handle` - Handle of the key used to sign the certificate
+    /// * `signing_scheme` - Signing scheme to use if the scheme for `signing_key_handle` is `Null`.
+    /// * `partial_certificate` - A DER encoded SEQUENCE containing the fields of the
+    ///                           certificate that are provided by the caller.
+    ///
+    /// The `partial_certificate` contains the issuer, validity, subject and extensions
+    /// of the certificate. The signature algorithm identifier can optionally be placed
+    /// before the other fields. See `abstraction::x509` for a helper that builds
+    /// the partial certificate and assembles the complete certificate.
+    ///
+    /// The command requires authorization for the `object_handle` in session slot 1
+    /// and for the `signing_key_handle` in session slot 2.
+    ///
+    /// # Returns
+    /// The command returns a tuple consisting of:
+    /// * `added_to_certificate` - A DER encoded SEQUENCE containing the fields that the TPM added
+    ///                            to the partial certificate in order to create the TBS certificate.
+    /// * `tbs_digest` - The digest of the TBS certificate that was signed.
+    /// * `signature` - The signature of the TBS certificate.
+    #[cfg(has_esys_certify_x509)]
+    pub fn certify_x509(
+        &mut self,
+        object_handle: ObjectHandle,
+        signing_key_handle: KeyHandle,
+        signing_scheme: SignatureScheme,
+        partial_certificate: MaxBuffer,
+    ) -> Result<(MaxBuffer, Digest, Signature)> {
+        let mut added_to_certificate_ptr = null_mut();
+        let mut tbs_digest_ptr = null_mut();
+        let mut signature_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_CertifyX509(
+                    self.mut_context(),
+                    object_handle.into(),
+                    signing_key_handle.into(),
+                    self.required_session_1()?,
+                    self.required_session_2()?,
+                    self.optional_session_3(),
+                    &Data::default().into(),
+                    &signing_scheme.into(),
+                    &partial_certificate.into(),
+                    &mut added_to_certificate_ptr,
+                    &mut tbs_digest_ptr,
+                    &mut signature_ptr,
+                )
+            },
+            |ret| {
+                error!("Error in certifying X.509: {:#010X}", ret);
+            },
+        )?;
+
+        Ok((
+            MaxBuffer::try_from(Context::ffi_data_to_owned(added_to_certificate_ptr))?,
+            Digest::try_from(Context::ffi_data_to_owned(tbs_digest_ptr))?,
+            Signature::try_from(Context::ffi_data_to_owned(signature_ptr))?,
+        ))
+    }
 }
@@ -1,7 +1,47 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use crate::Context;
+#[cfg(has_esys_act_set_timeout)]
+use crate::{
+    handles::AuthHandle, interface_types::resource_handles::Act, tss2_esys::Esys_ACT_SetTimeout,
+    Result, ReturnCode,
+};
+#[cfg(has_esys_act_set_timeout)]
+use log::error;
 
 impl Context {
-    // Missing function: ACT_SetTimeout
+    /// Set the timeout of an authenticated countdown timer.
+    ///
+    /// # Details
+    /// Sets the number of seconds before the timer expires. A
+    /// timeout of zero stops the timer and clears the signaled
+    /// attribute of the timer.
+    ///
+    /// The state of the timers can be read with [Context::get_capability]
+    /// using [CapabilityType::Act](crate::constants::CapabilityType::Act).
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `act_handle` - The [Act] whose timeout is to be set.
+    /// * `start_timeout` - The number of seconds before the timer expires.
+    #[cfg(has_esys_act_set_timeout)]
+    pub fn act_set_timeout(&mut self, act_handle: Act, start_timeout: u32) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_ACT_SetTimeout(
+                    self.mut_context(),
+                    AuthHandle::from(act_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    start_timeout,
+                )
+            },
+            |ret| {
+                error!("Error when setting ACT timeout: {:#010X}", ret);
+            },
+        )
+    }
 }
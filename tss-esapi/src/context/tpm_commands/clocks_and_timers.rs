@@ -1,9 +1,109 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    constants::ClockAdjust,
+    handles::AuthHandle,
+    interface_types::resource_handles::Provision,
+    structures::TimeInfo,
+    tss2_esys::{Esys_ClockRateAdjust, Esys_ClockSet, Esys_ReadClock},
+    Context, Result, ReturnCode,
+};
+use log::error;
+use std::convert::TryFrom;
+use std::ptr::null_mut;
 
 impl Context {
-    // Missing function: ReadClock
-    // Missing function: ClockSet
-    // Missing function: ClockRateAdjust
+    /// Read the current time and clock values of the TPM.
+    ///
+    /// # Details
+    /// The returned [TimeInfo] contains the time since the last TPM Reset or
+    /// TPM Restart and the [ClockInfo](crate::structures::ClockInfo) with the
+    /// clock value and the reset and restart counts.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{Context, TctiNameConf};
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// let time_info = context.read_clock().expect("Failed to read clock");
+    /// let clock = time_info.clock_info().clock();
+    /// ```
+    pub fn read_clock(&mut self) -> Result<TimeInfo> {
+        let mut current_time_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_ReadClock(
+                    self.mut_context(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &mut current_time_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when reading clock: {:#010X}", ret);
+            },
+        )?;
+        TimeInfo::try_from(Context::ffi_data_to_owned(current_time_ptr))
+    }
+
+    /// Set the clock of the TPM.
+    ///
+    /// # Details
+    /// The clock can only be advanced, a value that is lower than the
+    /// current clock value will be rejected by the TPM.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth` - The [Provision] used for authorization.
+    /// * `new_time` - The new clock value in milliseconds.
+    pub fn clock_set(&mut self, auth: Provision, new_time: u64) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_ClockSet(
+                    self.mut_context(),
+                    AuthHandle::from(auth).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    new_time,
+                )
+            },
+            |ret| {
+                error!("Error when setting clock: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Adjust the rate of advance of the clock of the TPM.
+    ///
+    /// # Details
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth` - The [Provision] used for authorization.
+    /// * `rate_adjust` - The [ClockAdjust] that is to be applied.
+    pub fn clock_rate_adjust(&mut self, auth: Provision, rate_adjust: ClockAdjust) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_ClockRateAdjust(
+                    self.mut_context(),
+                    AuthHandle::from(auth).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    rate_adjust.into(),
+                )
+            },
+            |ret| {
+                error!("Error when adjusting clock rate: {:#010X}", ret);
+            },
+        )
+    }
 }
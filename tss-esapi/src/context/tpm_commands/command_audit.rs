@@ -1,7 +1,105 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    handles::AuthHandle,
+    interface_types::{algorithm::HashingAlgorithm, resource_handles::Provision},
+    structures::CommandCodeList,
+    tss2_esys::Esys_SetCommandCodeAuditStatus,
+    Context, Result, ReturnCode,
+};
+use log::error;
 
 impl Context {
-    // Missing function: SetCommandCodeAuditStatus
+    /// Change the audit status of commands or the hash algorithm
+    /// used for the command audit digest.
+    ///
+    /// # Details
+    /// If `audit_alg` is not [HashingAlgorithm::Null] and differs from
+    /// the algorithm currently used by the TPM then the algorithm is
+    /// changed, the audit digest is cleared and both lists have to be empty.
+    /// Otherwise the commands in `set_list` are added to, and the commands in
+    /// `clear_list` are removed from, the list of audited commands.
+    ///
+    /// TPM2_SetCommandCodeAuditStatus is always audited.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth` - The [Provision] used for authorization.
+    /// * `audit_alg` - The hash algorithm for the audit digest or [HashingAlgorithm::Null].
+    /// * `set_list` - The commands that are to be added to the audit list.
+    /// * `clear_list` - The commands that are to be removed from the audit list.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{
+    /// #     Context, TctiNameConf,
+    /// #     constants::CommandCode,
+    /// #     interface_types::{
+    /// #         algorithm::HashingAlgorithm, resource_handles::Provision,
+    /// #         session_handles::AuthSession,
+    /// #     },
+    /// #     structures::CommandCodeList,
+    /// # };
+    /// # use std::convert::TryFrom;
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// let set_list = CommandCodeList::try_from(vec![CommandCode::GetRandom])
+    ///     .expect("Failed to create command code list");
+    /// context
+    ///     .execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///         ctx.set_command_code_audit_status(
+    ///             Provision::Owner,
+    ///             HashingAlgorithm::Null,
+    ///             set_list,
+    ///             CommandCodeList::new(),
+    ///         )
+    ///     })
+    ///     .expect("Failed to set command code audit status");
+    /// # // Remove the command from the audit list again
+    /// # let clear_list = CommandCodeList::try_from(vec![CommandCode::GetRandom])
+    /// #     .expect("Failed to create command code list");
+    /// # context
+    /// #     .execute_with_session(Some(AuthSession::Password), |ctx| {
+    /// #         ctx.set_command_code_audit_status(
+    /// #             Provision::Owner,
+    /// #             HashingAlgorithm::Null,
+    /// #             CommandCodeList::new(),
+    /// #             clear_list,
+    /// #         )
+    /// #     })
+    /// #     .expect("Failed to clear command code audit status");
+    /// ```
+    pub fn set_command_code_audit_status(
+        &mut self,
+        auth: Provision,
+        audit_alg: HashingAlgorithm,
+        set_list: CommandCodeList,
+        clear_list: CommandCodeList,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_SetCommandCodeAuditStatus(
+                    self.mut_context(),
+                    AuthHandle::from(auth).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    audit_alg.into(),
+                    &set_list.into(),
+                    &clear_list.into(),
+                )
+            },
+            |ret| {
+                error!(
+                    "Error when setting command code audit status: {:#010X}",
+                    ret
+                );
+            },
+        )
+    }
 }
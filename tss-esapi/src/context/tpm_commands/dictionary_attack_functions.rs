@@ -1,8 +1,105 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    handles::ObjectHandle,
+    interface_types::resource_handles::Lockout,
+    tss2_esys::{Esys_DictionaryAttackLockReset, Esys_DictionaryAttackParameters},
+    Context, Result, ReturnCode,
+};
+use log::error;
 
 impl Context {
-    // Missing function: DictionaryAttackLockReset
-    // Missing function: DictionaryAttackParameters
+    /// Reset the dictionary attack lockout.
+    ///
+    /// # Details
+    /// This sets the failed authorization counter of the TPM to zero
+    /// and takes the TPM out of lockout.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `lock_handle` - The [Lockout] handle used for authorization.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{
+    /// #     Context, TctiNameConf,
+    /// #     interface_types::{resource_handles::Lockout, session_handles::AuthSession},
+    /// # };
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// context
+    ///     .execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///         ctx.dictionary_attack_lock_reset(Lockout::Lockout)
+    ///     })
+    ///     .expect("Failed to reset the dictionary attack lockout");
+    /// ```
+    pub fn dictionary_attack_lock_reset(&mut self, lock_handle: Lockout) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_DictionaryAttackLockReset(
+                    self.mut_context(),
+                    ObjectHandle::from(lock_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                )
+            },
+            |ret| {
+                error!(
+                    "Error when resetting dictionary attack lockout: {:#010X}",
+                    ret
+                );
+            },
+        )
+    }
+
+    /// Change the dictionary attack lockout parameters.
+    ///
+    /// # Details
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `lock_handle` - The [Lockout] handle used for authorization.
+    /// * `new_max_tries` - The number of authorization failures before the TPM
+    ///   enters lockout.
+    /// * `new_recovery_time` - The time in seconds after which the failed
+    ///   authorization counter is decremented. A value of zero disables the
+    ///   dictionary attack protection.
+    /// * `lockout_recovery` - The time in seconds after a failed authorization
+    ///   with the lockout authorization before it can be used again. A value of
+    ///   zero means that a TPM Reset is needed.
+    pub fn dictionary_attack_parameters(
+        &mut self,
+        lock_handle: Lockout,
+        new_max_tries: u32,
+        new_recovery_time: u32,
+        lockout_recovery: u32,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_DictionaryAttackParameters(
+                    self.mut_context(),
+                    ObjectHandle::from(lock_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    new_max_tries,
+                    new_recovery_time,
+                    lockout_recovery,
+                )
+            },
+            |ret| {
+                error!(
+                    "Error when setting dictionary attack parameters: {:#010X}",
+                    ret
+                );
+            },
+        )
+    }
 }
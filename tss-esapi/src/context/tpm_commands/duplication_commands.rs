@@ -3,8 +3,8 @@
 use crate::Context;
 use crate::{
     handles::ObjectHandle,
-    structures::{Data, EncryptedSecret, Private, Public, SymmetricDefinitionObject},
-    tss2_esys::{Esys_Duplicate, Esys_Import},
+    structures::{Data, EncryptedSecret, Name, Private, Public, SymmetricDefinitionObject},
+    tss2_esys::{Esys_Duplicate, Esys_Import, Esys_Rewrap},
     Result, ReturnCode,
 };
 use log::error;
@@ -333,7 +333,68 @@ impl Context {
         ))
     }
 
-    // Missing function: Rewrap
+    /// Rewrap a duplicated object so that it can be imported under a different parent.
+    ///
+    /// # Details
+    /// This command allows the TPM to serve in the role of a Duplication Authority.
+    /// The outer wrapper of the duplicated object, that protects it for `old_parent`,
+    /// is removed and a new outer wrapper for `new_parent` is applied. The inner
+    /// wrapper, if any, is left untouched so the sensitive area of the object is
+    /// never exposed in plain text. Only the public area of `new_parent` is
+    /// required to be loaded.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present for `old_parent`.
+    ///
+    /// # Arguments
+    /// * `old_parent` - An [ObjectHandle] of the parent that the object is currently
+    ///   protected for.
+    /// * `new_parent` - An [ObjectHandle] of the new parent.
+    /// * `in_duplicate` - The duplicated object, as returned by [Context::duplicate]
+    ///   or by a previous rewrap.
+    /// * `name` - The [Name] of the duplicated object.
+    /// * `in_sym_seed` - The seed that protects the outer wrapper for `old_parent`.
+    ///
+    /// # Returns
+    /// The command returns a tuple consisting of:
+    /// * `out_duplicate` - The object protected for `new_parent`.
+    /// * `out_sym_seed` - Seed protected by the asymmetric algorithms of `new_parent`.
+    pub fn rewrap(
+        &mut self,
+        old_parent: ObjectHandle,
+        new_parent: ObjectHandle,
+        in_duplicate: Private,
+        name: Name,
+        in_sym_seed: EncryptedSecret,
+    ) -> Result<(Private, EncryptedSecret)> {
+        let mut out_duplicate_ptr = null_mut();
+        let mut out_sym_seed_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_Rewrap(
+                    self.mut_context(),
+                    old_parent.into(),
+                    new_parent.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &in_duplicate.into(),
+                    name.as_ref(),
+                    &in_sym_seed.into(),
+                    &mut out_duplicate_ptr,
+                    &mut out_sym_seed_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when performing rewrap: {:#010X}", ret);
+            },
+        )?;
+
+        Ok((
+            Private::try_from(Context::ffi_data_to_owned(out_duplicate_ptr))?,
+            EncryptedSecret::try_from(Context::ffi_data_to_owned(out_sym_seed_ptr))?,
+        ))
+    }
 
     /// Import attaches imported object to a new parent.
     ///
@@ -2,19 +2,20 @@
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
     attributes::LocalityAttributes,
-    constants::CommandCode,
-    handles::{AuthHandle, ObjectHandle, SessionHandle},
-    interface_types::{session_handles::PolicySession, YesNo},
+    constants::{ArithmeticOperation, CommandCode},
+    handles::{AuthHandle, NvIndexHandle, ObjectHandle, SessionHandle},
+    interface_types::{resource_handles::NvAuth, session_handles::PolicySession, YesNo},
     structures::{
-        AuthTicket, Digest, DigestList, Name, Nonce, PcrSelectionList, Signature, Timeout,
+        AuthTicket, Digest, DigestList, Name, Nonce, Operand, PcrSelectionList, Signature, Timeout,
         VerifiedTicket,
     },
     tss2_esys::{
-        Esys_PolicyAuthValue, Esys_PolicyAuthorize, Esys_PolicyCommandCode, Esys_PolicyCpHash,
-        Esys_PolicyDuplicationSelect, Esys_PolicyGetDigest, Esys_PolicyLocality,
-        Esys_PolicyNameHash, Esys_PolicyNvWritten, Esys_PolicyOR, Esys_PolicyPCR,
-        Esys_PolicyPassword, Esys_PolicyPhysicalPresence, Esys_PolicySecret, Esys_PolicySigned,
-        Esys_PolicyTemplate,
+        Esys_PolicyAuthValue, Esys_PolicyAuthorize, Esys_PolicyAuthorizeNV, Esys_PolicyCommandCode,
+        Esys_PolicyCounterTimer, Esys_PolicyCpHash, Esys_PolicyDuplicationSelect,
+        Esys_PolicyGetDigest, Esys_PolicyLocality, Esys_PolicyNV, Esys_PolicyNameHash,
+        Esys_PolicyNvWritten, Esys_PolicyOR, Esys_PolicyPCR, Esys_PolicyPassword,
+        Esys_PolicyPhysicalPresence, Esys_PolicySecret, Esys_PolicySigned, Esys_PolicyTemplate,
+        Esys_PolicyTicket,
     },
     Context, Error, Result, ReturnCode, WrapperErrorKind as ErrorKind,
 };
@@ -36,37 +37,63 @@ impl Context {
         expiration: Option<Duration>,
         signature: Signature,
     ) -> Result<(Timeout, AuthTicket)> {
-        let mut out_timeout_ptr = null_mut();
-        let mut out_policy_ticket_ptr = null_mut();
-        ReturnCode::ensure_success(
-            unsafe {
-                Esys_PolicySigned(
-                    self.mut_context(),
-                    auth_object.into(),
-                    SessionHandle::from(policy_session).into(),
-                    self.required_session_1()?,
-                    self.optional_session_2(),
-                    self.optional_session_3(),
-                    &nonce_tpm.into(),
-                    &cp_hash_a.into(),
-                    &policy_ref.into(),
-                    i32::try_from(expiration.map_or(0, |v| v.as_secs())).map_err(|e| {
-                        error!("Unable to convert duration to i32: {}", e);
-                        Error::local_error(ErrorKind::InvalidParam)
-                    })?,
-                    &signature.try_into()?,
-                    &mut out_timeout_ptr,
-                    &mut out_policy_ticket_ptr,
-                )
-            },
-            |ret| {
-                error!("Error when sending policy signed: {:#010X}", ret);
-            },
-        )?;
-        Ok((
-            Timeout::try_from(Context::ffi_data_to_owned(out_timeout_ptr))?,
-            AuthTicket::try_from(Context::ffi_data_to_owned(out_policy_ticket_ptr))?,
-        ))
+        self.policy_signed_internal(
+            policy_session,
+            auth_object,
+            nonce_tpm,
+            cp_hash_a,
+            policy_ref,
+            Self::expiration_value(expiration, false)?,
+            signature,
+        )
+    }
+
+    /// Cause the policy to include a signed authorization and
+    /// request a ticket for the authorization.
+    ///
+    /// # Arguments
+    /// * `policy_session` - The [policy session][PolicySession] being extended.
+    /// * `auth_object` - The [ObjectHandle] of the key that was used to sign the authorization.
+    /// * `nonce_tpm` - The nonce of the policy session. If this is empty the ticket can be
+    ///                 used with any policy session.
+    /// * `cp_hash_a` - The cpHash of the command that is being authorized or empty.
+    /// * `policy_ref` - A value that qualifies the policy or empty.
+    /// * `expiration` - The [Duration] for which the ticket is valid.
+    /// * `signature` - The [Signature] of the authorization.
+    ///
+    /// # Details
+    /// This is the same as [Context::policy_signed] except that the
+    /// expiration is sent as a negative value which makes the TPM
+    /// produce an [AuthTicket]. The ticket can later be redeemed in
+    /// other policy sessions using [Context::policy_ticket] until it
+    /// expires. A ticket is not produced for trial sessions.
+    ///
+    /// # Returns
+    /// The [Timeout] of the ticket and the [AuthTicket].
+    ///
+    /// # Errors
+    /// * if the expiration is shorter than one second, an `InvalidParam`
+    ///   wrapper error is returned since the TPM would not produce a ticket.
+    #[allow(clippy::too_many_arguments)]
+    pub fn policy_signed_with_ticket(
+        &mut self,
+        policy_session: PolicySession,
+        auth_object: ObjectHandle,
+        nonce_tpm: Nonce,
+        cp_hash_a: Digest,
+        policy_ref: Nonce,
+        expiration: Duration,
+        signature: Signature,
+    ) -> Result<(Timeout, AuthTicket)> {
+        self.policy_signed_internal(
+            policy_session,
+            auth_object,
+            nonce_tpm,
+            cp_hash_a,
+            policy_ref,
+            Self::expiration_value(Some(expiration), true)?,
+            signature,
+        )
     }
 
     /// Cause the policy to require a secret in authValue
@@ -79,40 +106,106 @@ impl Context {
         policy_ref: Nonce,
         expiration: Option<Duration>,
     ) -> Result<(Timeout, AuthTicket)> {
-        let mut out_timeout_ptr = null_mut();
-        let mut out_policy_ticket_ptr = null_mut();
+        self.policy_secret_internal(
+            policy_session,
+            auth_handle,
+            nonce_tpm,
+            cp_hash_a,
+            policy_ref,
+            Self::expiration_value(expiration, false)?,
+        )
+    }
+
+    /// Cause the policy to require a secret in authValue and
+    /// request a ticket for the authorization.
+    ///
+    /// # Arguments
+    /// * `policy_session` - The [policy session][PolicySession] being extended.
+    /// * `auth_handle` - The [AuthHandle] of the entity whose authorization is required.
+    /// * `nonce_tpm` - The nonce of the policy session. If this is empty the ticket can be
+    ///                 used with any policy session.
+    /// * `cp_hash_a` - The cpHash of the command that is being authorized or empty.
+    /// * `policy_ref` - A value that qualifies the policy or empty.
+    /// * `expiration` - The [Duration] for which the ticket is valid.
+    ///
+    /// # Details
+    /// This is the same as [Context::policy_secret] except that the
+    /// expiration is sent as a negative value which makes the TPM
+    /// produce an [AuthTicket]. The ticket can later be redeemed in
+    /// other policy sessions using [Context::policy_ticket] until it
+    /// expires. A ticket is not produced for trial sessions.
+    ///
+    /// # Returns
+    /// The [Timeout] of the ticket and the [AuthTicket].
+    ///
+    /// # Errors
+    /// * if the expiration is shorter than one second, an `InvalidParam`
+    ///   wrapper error is returned since the TPM would not produce a ticket.
+    pub fn policy_secret_with_ticket(
+        &mut self,
+        policy_session: PolicySession,
+        auth_handle: AuthHandle,
+        nonce_tpm: Nonce,
+        cp_hash_a: Digest,
+        policy_ref: Nonce,
+        expiration: Duration,
+    ) -> Result<(Timeout, AuthTicket)> {
+        self.policy_secret_internal(
+            policy_session,
+            auth_handle,
+            nonce_tpm,
+            cp_hash_a,
+            policy_ref,
+            Self::expiration_value(Some(expiration), true)?,
+        )
+    }
+
+    /// Cause the policy to include an authorization that was
+    /// previously granted with a ticket.
+    ///
+    /// # Arguments
+    /// * `policy_session` - The [policy session][PolicySession] being extended.
+    /// * `timeout` - The [Timeout] that was returned together with the ticket.
+    /// * `cp_hash_a` - The cpHash that was used when the ticket was created.
+    /// * `policy_ref` - The policy reference that was used when the ticket was created.
+    /// * `auth_name` - The [Name] of the object that provided the authorization.
+    /// * `ticket` - The [AuthTicket] that was produced by [Context::policy_signed_with_ticket]
+    ///              or [Context::policy_secret_with_ticket].
+    ///
+    /// # Details
+    /// The TPM will validate the ticket and, if it is valid and has not expired,
+    /// extend the policy digest of the session in the same way as the
+    /// PolicySigned or PolicySecret command that produced the ticket.
+    pub fn policy_ticket(
+        &mut self,
+        policy_session: PolicySession,
+        timeout: Timeout,
+        cp_hash_a: Digest,
+        policy_ref: Nonce,
+        auth_name: Name,
+        ticket: AuthTicket,
+    ) -> Result<()> {
         ReturnCode::ensure_success(
             unsafe {
-                Esys_PolicySecret(
+                Esys_PolicyTicket(
                     self.mut_context(),
-                    auth_handle.into(),
                     SessionHandle::from(policy_session).into(),
-                    self.required_session_1()?,
+                    self.optional_session_1(),
                     self.optional_session_2(),
                     self.optional_session_3(),
-                    &nonce_tpm.into(),
+                    &timeout.into(),
                     &cp_hash_a.into(),
                     &policy_ref.into(),
-                    i32::try_from(expiration.map_or(0, |v| v.as_secs())).map_err(|e| {
-                        error!("Unable to convert duration to i32: {}", e);
-                        Error::local_error(ErrorKind::InvalidParam)
-                    })?,
-                    &mut out_timeout_ptr,
-                    &mut out_policy_ticket_ptr,
+                    auth_name.as_ref(),
+                    &ticket.try_into()?,
                 )
             },
             |ret| {
-                error!("Error when sending policy secret: {:#010X}", ret);
+                error!("Error when sending policy ticket: {:#010X}", ret);
             },
-        )?;
-        Ok((
-            Timeout::try_from(Context::ffi_data_to_owned(out_timeout_ptr))?,
-            AuthTicket::try_from(Context::ffi_data_to_owned(out_policy_ticket_ptr))?,
-        ))
+        )
     }
 
-    // Missing function: PolicyTicket
-
     /// Cause conditional gating of a policy based on an OR'd condition.
     ///
     /// The TPM will ensure that the current policy digest equals at least
@@ -216,8 +309,102 @@ impl Context {
         )
     }
 
-    // Missing function: PolicyNV
-    // Missing function: PolicyCounterTimer
+    /// Cause conditional gating of a policy based on the contents of an NV index.
+    ///
+    /// # Arguments
+    /// * `policy_session` - The [policy session][PolicySession] being extended.
+    /// * `auth_handle` - The [NvAuth] handle that is used to authorize the read
+    ///                   of the NV index.
+    /// * `nv_index_handle` - The [NvIndexHandle] associated with the NV index
+    ///                       containing operand A.
+    /// * `operand_b` - The [Operand] that is compared with the contents of the NV index.
+    /// * `offset` - The octet offset in the NV index where operand A starts.
+    /// * `operation` - The [ArithmeticOperation] used in the comparison.
+    ///
+    /// # Details
+    /// The TPM will ensure that the comparison `A op B` holds where A is
+    /// the data read from the NV index, starting at `offset`, and B is
+    /// `operand_b`. Both operands are interpreted as big-endian values
+    /// with the size of `operand_b`.
+    ///
+    /// The read of the NV index requires authorization so a session needs
+    /// to be set in session slot 1.
+    pub fn policy_nv(
+        &mut self,
+        policy_session: PolicySession,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+        operand_b: Operand,
+        offset: u16,
+        operation: ArithmeticOperation,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PolicyNV(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    nv_index_handle.into(),
+                    SessionHandle::from(policy_session).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &operand_b.into(),
+                    offset,
+                    operation.into(),
+                )
+            },
+            |ret| {
+                error!("Error when computing policy NV: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Cause conditional gating of a policy based on the TPM clock and counters.
+    ///
+    /// # Arguments
+    /// * `policy_session` - The [policy session][PolicySession] being extended.
+    /// * `operand_b` - The [Operand] that is compared with the time info.
+    /// * `offset` - The octet offset in the marshalled TPMS_TIME_INFO structure
+    ///              where operand A starts.
+    /// * `operation` - The [ArithmeticOperation] used in the comparison.
+    ///
+    /// # Details
+    /// The TPM will ensure that the comparison `A op B` holds where A is
+    /// taken from the marshalled TPMS_TIME_INFO structure of the TPM, starting
+    /// at `offset`, and B is `operand_b`. Both operands are interpreted as
+    /// big-endian values with the size of `operand_b`.
+    ///
+    /// The offsets of the fields in the marshalled TPMS_TIME_INFO structure are:
+    /// * `time` - 0 (8 octets)
+    /// * `clock` - 8 (8 octets)
+    /// * `resetCount` - 16 (4 octets)
+    /// * `restartCount` - 20 (4 octets)
+    /// * `safe` - 24 (1 octet)
+    pub fn policy_counter_timer(
+        &mut self,
+        policy_session: PolicySession,
+        operand_b: Operand,
+        offset: u16,
+        operation: ArithmeticOperation,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PolicyCounterTimer(
+                    self.mut_context(),
+                    SessionHandle::from(policy_session).into(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &operand_b.into(),
+                    offset,
+                    operation.into(),
+                )
+            },
+            |ret| {
+                error!("Error when computing policy counter timer: {:#010X}", ret);
+            },
+        )
+    }
 
     /// Cause conditional gating of a policy based on command code of authorized command.
     ///
@@ -593,5 +780,143 @@ impl Context {
             },
         )
     }
-    // Missing function: PolicyAuthorizeNV
+
+    /// Cause conditional gating of a policy based on an authorized policy
+    /// stored in an NV index.
+    ///
+    /// # Arguments
+    /// * `policy_session` - The [policy session][PolicySession] being extended.
+    /// * `auth_handle` - The [NvAuth] handle that is used to authorize the read
+    ///                   of the NV index.
+    /// * `nv_index_handle` - The [NvIndexHandle] associated with the NV index
+    ///                       containing the authorized policy.
+    ///
+    /// # Details
+    /// The NV index needs to contain a TPMT_HA structure with a policy digest.
+    /// The TPM will ensure that the current policy digest of the session
+    /// matches the digest in the NV index and if that is the case the policy
+    /// digest is reset and then extended with the name of the NV index.
+    ///
+    /// The read of the NV index requires authorization so a session needs
+    /// to be set in session slot 1.
+    pub fn policy_authorize_nv(
+        &mut self,
+        policy_session: PolicySession,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PolicyAuthorizeNV(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    nv_index_handle.into(),
+                    SessionHandle::from(policy_session).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                )
+            },
+            |ret| {
+                error!("Error when computing policy authorize NV: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Converts the expiration into the value that is sent to the TPM.
+    ///
+    /// The TPM only produces a ticket if the expiration is negative.
+    fn expiration_value(expiration: Option<Duration>, request_ticket: bool) -> Result<i32> {
+        let expiration = i32::try_from(expiration.map_or(0, |v| v.as_secs())).map_err(|e| {
+            error!("Unable to convert duration to i32: {}", e);
+            Error::local_error(ErrorKind::InvalidParam)
+        })?;
+        if !request_ticket {
+            return Ok(expiration);
+        }
+        if expiration == 0 {
+            error!("A ticket can only be produced if the expiration is at least one second");
+            return Err(Error::local_error(ErrorKind::InvalidParam));
+        }
+        Ok(-expiration)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn policy_signed_internal(
+        &mut self,
+        policy_session: PolicySession,
+        auth_object: ObjectHandle,
+        nonce_tpm: Nonce,
+        cp_hash_a: Digest,
+        policy_ref: Nonce,
+        expiration: i32,
+        signature: Signature,
+    ) -> Result<(Timeout, AuthTicket)> {
+        let mut out_timeout_ptr = null_mut();
+        let mut out_policy_ticket_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PolicySigned(
+                    self.mut_context(),
+                    auth_object.into(),
+                    SessionHandle::from(policy_session).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &nonce_tpm.into(),
+                    &cp_hash_a.into(),
+                    &policy_ref.into(),
+                    expiration,
+                    &signature.try_into()?,
+                    &mut out_timeout_ptr,
+                    &mut out_policy_ticket_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when sending policy signed: {:#010X}", ret);
+            },
+        )?;
+        Ok((
+            Timeout::try_from(Context::ffi_data_to_owned(out_timeout_ptr))?,
+            AuthTicket::try_from(Context::ffi_data_to_owned(out_policy_ticket_ptr))?,
+        ))
+    }
+
+    fn policy_secret_internal(
+        &mut self,
+        policy_session: PolicySession,
+        auth_handle: AuthHandle,
+        nonce_tpm: Nonce,
+        cp_hash_a: Digest,
+        policy_ref: Nonce,
+        expiration: i32,
+    ) -> Result<(Timeout, AuthTicket)> {
+        let mut out_timeout_ptr = null_mut();
+        let mut out_policy_ticket_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PolicySecret(
+                    self.mut_context(),
+                    auth_handle.into(),
+                    SessionHandle::from(policy_session).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &nonce_tpm.into(),
+                    &cp_hash_a.into(),
+                    &policy_ref.into(),
+                    expiration,
+                    &mut out_timeout_ptr,
+                    &mut out_policy_ticket_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when sending policy secret: {:#010X}", ret);
+            },
+        )?;
+        Ok((
+            Timeout::try_from(Context::ffi_data_to_owned(out_timeout_ptr))?,
+            AuthTicket::try_from(Context::ffi_data_to_owned(out_policy_ticket_ptr))?,
+        ))
+    }
 }
@@ -1,8 +1,183 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    handles::KeyHandle,
+    interface_types::ecc::EccCurve,
+    structures::{EccParameter, EccPoint, SensitiveData},
+    tss2_esys::{Esys_Commit, Esys_EC_Ephemeral},
+    Context, Result, ReturnCode,
+};
+use log::error;
+use std::convert::TryFrom;
+use std::ptr::{null, null_mut};
 
 impl Context {
-    // Missing function: Commit
-    // Missing function: EC_Ephemeral
+    /// Performs the first part of an ECC anonymous signing operation.
+    ///
+    /// # Details
+    /// The TPM generates an ephemeral value that is associated with the
+    /// returned counter. The counter can then be used together with the
+    /// ECDAA scheme in [Context::sign] to complete the signing operation.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `sign_handle` - A handle to an ECC key using an anonymous signing scheme.
+    /// * `p1` - An optional point on the curve of the key.
+    /// * `s2` - An optional octet array used to derive the x-coordinate of a second point.
+    /// * `y2` - The y-coordinate of the second point. This has to be provided if `s2`
+    ///   is provided.
+    ///
+    /// # Returns
+    /// A tuple containing the points K, L and E and the commit counter.
+    /// K and L are empty unless `s2` and `y2` are provided.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{
+    /// #     attributes::ObjectAttributesBuilder,
+    /// #     interface_types::{
+    /// #         algorithm::{HashingAlgorithm, PublicAlgorithm},
+    /// #         ecc::EccCurve,
+    /// #         resource_handles::Hierarchy,
+    /// #         session_handles::AuthSession,
+    /// #     },
+    /// #     structures::{
+    /// #         EcDaaScheme, EccPoint, EccScheme, KeyDerivationFunctionScheme, PublicBuilder,
+    /// #         PublicEccParametersBuilder,
+    /// #     },
+    /// #     Context, TctiNameConf,
+    /// # };
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// # context.set_sessions((Some(AuthSession::Password), None, None));
+    /// let ecc_parameters = PublicEccParametersBuilder::new()
+    ///     .with_ecc_scheme(EccScheme::EcDaa(EcDaaScheme::new(HashingAlgorithm::Sha256, 0)))
+    ///     .with_curve(EccCurve::BnP256)
+    ///     .with_is_signing_key(true)
+    ///     .with_is_decryption_key(false)
+    ///     .with_restricted(false)
+    ///     .with_key_derivation_function_scheme(KeyDerivationFunctionScheme::Null)
+    ///     .build()
+    ///     .expect("Failed to build ECC parameters");
+    /// let object_attributes = ObjectAttributesBuilder::new()
+    ///     .with_fixed_tpm(true)
+    ///     .with_fixed_parent(true)
+    ///     .with_sensitive_data_origin(true)
+    ///     .with_user_with_auth(true)
+    ///     .with_sign_encrypt(true)
+    ///     .build()
+    ///     .expect("Failed to build object attributes");
+    /// let public = PublicBuilder::new()
+    ///     .with_public_algorithm(PublicAlgorithm::Ecc)
+    ///     .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+    ///     .with_object_attributes(object_attributes)
+    ///     .with_ecc_parameters(ecc_parameters)
+    ///     .with_ecc_unique_identifier(EccPoint::default())
+    ///     .build()
+    ///     .expect("Failed to build public");
+    /// let key_handle = context
+    ///     .create_primary(Hierarchy::Owner, public, None, None, None, None)
+    ///     .expect("Failed to create primary key")
+    ///     .key_handle;
+    ///
+    /// let (_, _, _, counter) = context
+    ///     .commit(key_handle, None, None, None)
+    ///     .expect("Failed to perform commit");
+    /// ```
+    pub fn commit(
+        &mut self,
+        sign_handle: KeyHandle,
+        p1: Option<EccPoint>,
+        s2: Option<SensitiveData>,
+        y2: Option<EccParameter>,
+    ) -> Result<(EccPoint, EccPoint, EccPoint, u16)> {
+        let mut k_ptr = null_mut();
+        let mut l_ptr = null_mut();
+        let mut e_ptr = null_mut();
+        let mut counter = 0;
+        let potential_p1 = p1.map(|v| v.into());
+        let potential_s2 = s2.map(|v| v.into());
+        let potential_y2 = y2.map(|v| v.into());
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_Commit(
+                    self.mut_context(),
+                    sign_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    potential_p1.as_ref().map_or_else(null, |v| v),
+                    potential_s2.as_ref().map_or_else(null, |v| v),
+                    potential_y2.as_ref().map_or_else(null, |v| v),
+                    &mut k_ptr,
+                    &mut l_ptr,
+                    &mut e_ptr,
+                    &mut counter,
+                )
+            },
+            |ret| {
+                error!("Error when performing commit: {:#010X}", ret);
+            },
+        )?;
+        let k = Context::ffi_data_to_owned(k_ptr);
+        let l = Context::ffi_data_to_owned(l_ptr);
+        let e = Context::ffi_data_to_owned(e_ptr);
+        Ok((
+            EccPoint::try_from(k.point)?,
+            EccPoint::try_from(l.point)?,
+            EccPoint::try_from(e.point)?,
+            counter,
+        ))
+    }
+
+    /// Creates an ephemeral key for use in a two-phase key exchange protocol.
+    ///
+    /// # Details
+    /// The TPM keeps the private part of the ephemeral key, which can be
+    /// used in [Context::zgen_2phase] by providing the returned counter.
+    ///
+    /// # Arguments
+    /// * `curve` - The curve of the ephemeral key.
+    ///
+    /// # Returns
+    /// A tuple containing the public part of the ephemeral key and the
+    /// counter associated with it.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{Context, TctiNameConf, interface_types::ecc::EccCurve};
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// let (q, counter) = context
+    ///     .ec_ephemeral(EccCurve::NistP256)
+    ///     .expect("Failed to create ephemeral key");
+    /// ```
+    pub fn ec_ephemeral(&mut self, curve: EccCurve) -> Result<(EccPoint, u16)> {
+        let mut q_ptr = null_mut();
+        let mut counter = 0;
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_EC_Ephemeral(
+                    self.mut_context(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    curve.into(),
+                    &mut q_ptr,
+                    &mut counter,
+                )
+            },
+            |ret| {
+                error!("Error when creating ephemeral key: {:#010X}", ret);
+            },
+        )?;
+        let q = Context::ffi_data_to_owned(q_ptr);
+        Ok((EccPoint::try_from(q.point)?, counter))
+    }
 }
@@ -1,9 +1,139 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    constants::tss::TPM2_ALG_NULL,
+    handles::{AuthHandle, KeyHandle},
+    interface_types::resource_handles::Platform,
+    structures::{Digest, HashAgile, MaxBuffer, Signature},
+    tss2_esys::{Esys_FieldUpgradeData, Esys_FieldUpgradeStart, Esys_FirmwareRead},
+    Context, Result, ReturnCode,
+};
+use log::error;
+use std::convert::{TryFrom, TryInto};
+use std::ptr::null_mut;
 
 impl Context {
-    // Missing function: FieldUpgradeStart
-    // Missing function: FieldUpgradeData
-    // Missing function: FirmwareRead
+    /// Starts a field upgrade of the TPM firmware.
+    ///
+    /// # Details
+    /// The TPM validates the signature of the manifest digest and, if it
+    /// is valid, the firmware image can be sent to the TPM in blocks using
+    /// [Context::field_upgrade_data]. See
+    /// [crate::abstraction::field_upgrade::upgrade_firmware] for a helper
+    /// that sends a complete image.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `authorization` - The [Platform] authorization handle.
+    /// * `key_handle` - The handle of the public key used to verify the signature.
+    /// * `fu_digest` - The digest of the first block of the field upgrade sequence.
+    /// * `manifest_signature` - The signature over `fu_digest`.
+    ///
+    /// # Errors
+    /// * if the TPM does not support field upgrades, the TPM error is returned.
+    pub fn field_upgrade_start(
+        &mut self,
+        authorization: Platform,
+        key_handle: KeyHandle,
+        fu_digest: Digest,
+        manifest_signature: Signature,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_FieldUpgradeStart(
+                    self.mut_context(),
+                    AuthHandle::from(authorization).into(),
+                    key_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &fu_digest.into(),
+                    &manifest_signature.try_into()?,
+                )
+            },
+            |ret| {
+                error!("Error when starting field upgrade: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Sends a block of the firmware image to the TPM.
+    ///
+    /// # Arguments
+    /// * `fu_data` - A block of the field upgrade image.
+    ///
+    /// # Returns
+    /// A tuple containing the digest of the next block, which is [None]
+    /// when the field upgrade is complete, and the digest of the first
+    /// block of the field upgrade sequence.
+    ///
+    /// # Errors
+    /// * if the TPM does not support field upgrades, the TPM error is returned.
+    pub fn field_upgrade_data(
+        &mut self,
+        fu_data: MaxBuffer,
+    ) -> Result<(Option<HashAgile>, HashAgile)> {
+        let mut next_digest_ptr = null_mut();
+        let mut first_digest_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_FieldUpgradeData(
+                    self.mut_context(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &fu_data.into(),
+                    &mut next_digest_ptr,
+                    &mut first_digest_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when sending field upgrade data: {:#010X}", ret);
+            },
+        )?;
+        let next_digest = Context::ffi_data_to_owned(next_digest_ptr);
+        let first_digest = Context::ffi_data_to_owned(first_digest_ptr);
+        Ok((
+            if next_digest.hashAlg == TPM2_ALG_NULL {
+                None
+            } else {
+                Some(HashAgile::try_from(next_digest)?)
+            },
+            HashAgile::try_from(first_digest)?,
+        ))
+    }
+
+    /// Reads a block of the firmware image from the TPM.
+    ///
+    /// # Details
+    /// See [crate::abstraction::field_upgrade::read_firmware] for a helper
+    /// that reads the complete image.
+    ///
+    /// # Arguments
+    /// * `sequence_number` - The number of the block that is to be read,
+    ///   starting at zero.
+    ///
+    /// # Errors
+    /// * if the TPM does not support reading the firmware, the TPM error is returned.
+    pub fn firmware_read(&mut self, sequence_number: u32) -> Result<MaxBuffer> {
+        let mut fu_data_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_FirmwareRead(
+                    self.mut_context(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    sequence_number,
+                    &mut fu_data_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when reading firmware: {:#010X}", ret);
+            },
+        )?;
+        MaxBuffer::try_from(Context::ffi_data_to_owned(fu_data_ptr))
+    }
 }
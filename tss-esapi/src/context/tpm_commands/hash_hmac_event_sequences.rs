@@ -1,12 +1,393 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    context::handle_manager::HandleDropAction,
+    handles::{ObjectHandle, PcrHandle, TpmHandle},
+    interface_types::{
+        algorithm::{HashingAlgorithm, MacAlgorithm},
+        resource_handles::Hierarchy,
+    },
+    structures::{Auth, Digest, DigestValues, HashcheckTicket, MaxBuffer},
+    tss2_esys::{
+        Esys_EventSequenceComplete, Esys_HMAC_Start, Esys_HashSequenceStart, Esys_SequenceComplete,
+        Esys_SequenceUpdate,
+    },
+    Context, Result, ReturnCode,
+};
+use log::error;
+use std::convert::TryFrom;
+use std::ptr::null_mut;
 
 impl Context {
-    // Missing function: HMAC_Start
-    // Missing function: MAC_Start
-    // Missing function: HashSequenceStart
-    // Missing function: SequenceUpdate
-    // Missing function: SequenceComplete
-    // Missing function: EventSequenceComplete
+    /// Starts an HMAC sequence.
+    ///
+    /// # Arguments
+    /// * `handle` - An [ObjectHandle] to the HMAC key that is going to be used.
+    /// * `hashing_algorithm` - The [HashingAlgorithm] to be used in the HMAC. If the
+    ///                         key has a defined scheme then this must be [HashingAlgorithm::Null]
+    ///                         or match the scheme of the key.
+    /// * `auth` - The authorization value that will be required in order to
+    ///            use the sequence object. If `None` then an empty auth value is used.
+    ///
+    /// # Returns
+    /// An [ObjectHandle] to the sequence object that have been created.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use tss_esapi::{
+    /// #     attributes::ObjectAttributesBuilder,
+    /// #     structures::{MaxBuffer, PublicKeyedHashParameters, KeyedHashScheme, PublicBuilder, Digest},
+    /// #     interface_types::{
+    /// #           resource_handles::Hierarchy,
+    /// #           algorithm::{HashingAlgorithm, PublicAlgorithm},
+    /// #           session_handles::AuthSession,
+    /// #     },
+    /// #     Context, tcti_ldr::TctiNameConf,
+    /// # };
+    /// # use std::convert::TryFrom;
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// # let object_attributes = ObjectAttributesBuilder::new()
+    /// #     .with_sign_encrypt(true)
+    /// #     .with_sensitive_data_origin(true)
+    /// #     .with_user_with_auth(true)
+    /// #     .build()
+    /// #     .expect("Failed to build object attributes");
+    /// # let key_pub = PublicBuilder::new()
+    /// #     .with_public_algorithm(PublicAlgorithm::KeyedHash)
+    /// #     .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+    /// #     .with_object_attributes(object_attributes)
+    /// #     .with_keyed_hash_parameters(PublicKeyedHashParameters::new(KeyedHashScheme::HMAC_SHA_256))
+    /// #     .with_keyed_hash_unique_identifier(Digest::default())
+    /// #     .build()
+    /// #     .expect("Failed to build public for HMAC key");
+    /// let hmac = context.execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///     let key_handle = ctx
+    ///         .create_primary(Hierarchy::Owner, key_pub, None, None, None, None)
+    ///         .expect("Failed to create HMAC key")
+    ///         .key_handle;
+    ///     let sequence_handle = ctx
+    ///         .hmac_start(key_handle.into(), HashingAlgorithm::Sha256, None)
+    ///         .expect("Call to hmac_start failed");
+    ///     ctx.sequence_update(
+    ///         sequence_handle,
+    ///         MaxBuffer::try_from("There is no spoon".as_bytes().to_vec())
+    ///             .expect("Failed to create MaxBuffer"),
+    ///     )
+    ///     .expect("Call to sequence_update failed");
+    ///     let (hmac, _) = ctx
+    ///         .sequence_complete(sequence_handle, MaxBuffer::default(), Hierarchy::Null)
+    ///         .expect("Call to sequence_complete failed");
+    ///     hmac
+    /// });
+    /// ```
+    pub fn hmac_start(
+        &mut self,
+        handle: ObjectHandle,
+        hashing_algorithm: HashingAlgorithm,
+        auth: Option<Auth>,
+    ) -> Result<ObjectHandle> {
+        self.mac_start(handle, hashing_algorithm.into(), auth)
+    }
+
+    /// Starts a MAC sequence.
+    ///
+    /// # Arguments
+    /// * `handle` - An [ObjectHandle] to the key that is going to be used.
+    /// * `mac_algorithm` - The [MacAlgorithm] to be used.
+    /// * `auth` - The authorization value that will be required in order to
+    ///            use the sequence object. If `None` then an empty auth value is used.
+    ///
+    /// # Details
+    /// TPM2_MAC_Start shares the command code and the wire format with
+    /// TPM2_HMAC_Start, the only difference being that the algorithm
+    /// parameter is allowed to be a symmetric block cipher MAC such as
+    /// [MacAlgorithm::Cmac] when the key is a symmetric cipher key.
+    ///
+    /// # Returns
+    /// An [ObjectHandle] to the sequence object that have been created.
+    pub fn mac_start(
+        &mut self,
+        handle: ObjectHandle,
+        mac_algorithm: MacAlgorithm,
+        auth: Option<Auth>,
+    ) -> Result<ObjectHandle> {
+        let mut sequence_handle = ObjectHandle::None.into();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_HMAC_Start(
+                    self.mut_context(),
+                    handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &auth.unwrap_or_default().into(),
+                    mac_algorithm.into(),
+                    &mut sequence_handle,
+                )
+            },
+            |ret| {
+                error!("Error failed to start MAC sequence: {:#010X}", ret);
+            },
+        )?;
+        let sequence_handle = ObjectHandle::from(sequence_handle);
+        self.handle_manager
+            .add_handle(sequence_handle, HandleDropAction::Flush)?;
+        Ok(sequence_handle)
+    }
+
+    /// Starts a hash sequence.
+    ///
+    /// # Arguments
+    /// * `hashing_algorithm` - The [HashingAlgorithm] to be used. If [HashingAlgorithm::Null]
+    ///                         is used then an event sequence is started.
+    /// * `auth` - The authorization value that will be required in order to
+    ///            use the sequence object. If `None` then an empty auth value is used.
+    ///
+    /// # Returns
+    /// An [ObjectHandle] to the sequence object that have been created.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use tss_esapi::{
+    /// #     structures::MaxBuffer,
+    /// #     interface_types::{
+    /// #           resource_handles::Hierarchy,
+    /// #           algorithm::HashingAlgorithm,
+    /// #           session_handles::AuthSession,
+    /// #     },
+    /// #     Context, tcti_ldr::TctiNameConf,
+    /// # };
+    /// # use std::convert::TryFrom;
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// let expected_hashed_data: [u8; 32] = [
+    ///     0x6b, 0x38, 0x4d, 0x2b, 0xfb, 0x0e, 0x0d, 0xfb, 0x64, 0x89, 0xdb, 0xf4, 0xf8, 0xe9,
+    ///     0xe5, 0x2f, 0x71, 0xee, 0xb1, 0x0d, 0x06, 0x4c, 0x56, 0x59, 0x70, 0xcd, 0xd9, 0x44,
+    ///     0x43, 0x18, 0x5d, 0xc1,
+    /// ];
+    /// let sequence_handle = context
+    ///     .hash_sequence_start(HashingAlgorithm::Sha256, None)
+    ///     .expect("Call to hash_sequence_start failed");
+    /// let (digest, _ticket) = context.execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///     ctx.sequence_update(
+    ///         sequence_handle,
+    ///         MaxBuffer::try_from("There is ".as_bytes().to_vec())
+    ///             .expect("Failed to create MaxBuffer"),
+    ///     )
+    ///     .expect("Call to sequence_update failed");
+    ///     ctx.sequence_complete(
+    ///         sequence_handle,
+    ///         MaxBuffer::try_from("no spoon".as_bytes().to_vec())
+    ///             .expect("Failed to create MaxBuffer"),
+    ///         Hierarchy::Owner,
+    ///     )
+    ///     .expect("Call to sequence_complete failed")
+    /// });
+    /// assert_eq!(&expected_hashed_data[..], digest.as_bytes());
+    /// ```
+    pub fn hash_sequence_start(
+        &mut self,
+        hashing_algorithm: HashingAlgorithm,
+        auth: Option<Auth>,
+    ) -> Result<ObjectHandle> {
+        let mut sequence_handle = ObjectHandle::None.into();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_HashSequenceStart(
+                    self.mut_context(),
+                    self.optional_session_1(),
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &auth.unwrap_or_default().into(),
+                    hashing_algorithm.into(),
+                    &mut sequence_handle,
+                )
+            },
+            |ret| {
+                error!("Error failed to start hash sequence: {:#010X}", ret);
+            },
+        )?;
+        let sequence_handle = ObjectHandle::from(sequence_handle);
+        self.handle_manager
+            .add_handle(sequence_handle, HandleDropAction::Flush)?;
+        Ok(sequence_handle)
+    }
+
+    /// Adds data to a hash, HMAC or event sequence.
+    ///
+    /// # Arguments
+    /// * `sequence_handle` - An [ObjectHandle] to the sequence object.
+    /// * `data` - The data that is to be added to the sequence.
+    ///
+    /// # Details
+    /// The sequence object requires authorization so a session
+    /// needs to be set in session slot 1.
+    pub fn sequence_update(
+        &mut self,
+        sequence_handle: ObjectHandle,
+        data: MaxBuffer,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_SequenceUpdate(
+                    self.mut_context(),
+                    sequence_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &data.into(),
+                )
+            },
+            |ret| {
+                error!("Error failed to update sequence: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Adds the last part of the data to a hash or HMAC sequence and
+    /// returns the result.
+    ///
+    /// # Arguments
+    /// * `sequence_handle` - An [ObjectHandle] to the sequence object.
+    /// * `data` - The last data that is to be added to the sequence.
+    /// * `hierarchy` - The [Hierarchy] of the ticket that is produced for a hash sequence.
+    ///
+    /// # Details
+    /// The sequence object requires authorization so a session
+    /// needs to be set in session slot 1. When the command succeeds the
+    /// sequence object is flushed by the TPM.
+    ///
+    /// # Returns
+    /// The resulting [Digest] and a [HashcheckTicket] that indicates if the digest
+    /// can be used when signing with a restricted signing key. For HMAC sequences
+    /// the ticket is a NULL ticket.
+    pub fn sequence_complete(
+        &mut self,
+        sequence_handle: ObjectHandle,
+        data: MaxBuffer,
+        hierarchy: Hierarchy,
+    ) -> Result<(Digest, HashcheckTicket)> {
+        let mut result_ptr = null_mut();
+        let mut validation_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_SequenceComplete(
+                    self.mut_context(),
+                    sequence_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &data.into(),
+                    if cfg!(hierarchy_is_esys_tr) {
+                        ObjectHandle::from(hierarchy).into()
+                    } else {
+                        TpmHandle::from(hierarchy).into()
+                    },
+                    &mut result_ptr,
+                    &mut validation_ptr,
+                )
+            },
+            |ret| {
+                error!("Error failed to complete sequence: {:#010X}", ret);
+            },
+        )?;
+        let result = Context::ffi_data_to_owned(result_ptr);
+        let validation = Context::ffi_data_to_owned(validation_ptr);
+        self.handle_manager.set_as_flushed(sequence_handle)?;
+        Ok((
+            Digest::try_from(result)?,
+            HashcheckTicket::try_from(validation)?,
+        ))
+    }
+
+    /// Adds the last part of the data to an event sequence and extends
+    /// a PCR with the result.
+    ///
+    /// # Arguments
+    /// * `pcr_handle` - A [PcrHandle] to the PCR slot that is to be extended.
+    /// * `sequence_handle` - An [ObjectHandle] to the event sequence object.
+    /// * `data` - The last data that is to be added to the sequence.
+    ///
+    /// # Details
+    /// The PCR and the sequence object both require authorization so
+    /// sessions needs to be set in session slot 1 (for the PCR) and
+    /// session slot 2 (for the sequence object). When the command
+    /// succeeds the sequence object is flushed by the TPM.
+    ///
+    /// # Returns
+    /// The [DigestValues] with the digests of the event data for each
+    /// implemented PCR bank.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use tss_esapi::{
+    /// #     structures::MaxBuffer,
+    /// #     interface_types::{
+    /// #           algorithm::HashingAlgorithm,
+    /// #           session_handles::AuthSession,
+    /// #     },
+    /// #     handles::PcrHandle,
+    /// #     Context, tcti_ldr::TctiNameConf,
+    /// # };
+    /// # use std::convert::TryFrom;
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// let sequence_handle = context
+    ///     .hash_sequence_start(HashingAlgorithm::Null, None)
+    ///     .expect("Call to hash_sequence_start failed");
+    /// let digests = context.execute_with_sessions(
+    ///     (Some(AuthSession::Password), Some(AuthSession::Password), None),
+    ///     |ctx| {
+    ///         ctx.event_sequence_complete(
+    ///             PcrHandle::Pcr16,
+    ///             sequence_handle,
+    ///             MaxBuffer::try_from("There is no spoon".as_bytes().to_vec())
+    ///                 .expect("Failed to create MaxBuffer"),
+    ///         )
+    ///         .expect("Call to event_sequence_complete failed")
+    ///     },
+    /// );
+    /// assert!(digests.get(HashingAlgorithm::Sha256).is_some());
+    /// ```
+    pub fn event_sequence_complete(
+        &mut self,
+        pcr_handle: PcrHandle,
+        sequence_handle: ObjectHandle,
+        data: MaxBuffer,
+    ) -> Result<DigestValues> {
+        let mut results_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_EventSequenceComplete(
+                    self.mut_context(),
+                    pcr_handle.into(),
+                    sequence_handle.into(),
+                    self.required_session_1()?,
+                    self.required_session_2()?,
+                    self.optional_session_3(),
+                    &data.into(),
+                    &mut results_ptr,
+                )
+            },
+            |ret| {
+                error!("Error failed to complete event sequence: {:#010X}", ret);
+            },
+        )?;
+        let results = Context::ffi_data_to_owned(results_ptr);
+        self.handle_manager.set_as_flushed(sequence_handle)?;
+        DigestValues::try_from(results)
+    }
 }
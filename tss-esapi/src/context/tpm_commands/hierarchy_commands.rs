@@ -2,13 +2,20 @@
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
     context::handle_manager::HandleDropAction,
-    handles::{AuthHandle, KeyHandle, ObjectHandle},
-    interface_types::{resource_handles::Hierarchy, YesNo},
+    handles::{AuthHandle, KeyHandle, ObjectHandle, TpmHandle},
+    interface_types::{
+        algorithm::HashingAlgorithm,
+        resource_handles::{Enables, Hierarchy, Platform},
+        YesNo,
+    },
     structures::{
         Auth, CreatePrimaryKeyResult, CreationData, CreationTicket, Data, Digest, PcrSelectionList,
         Public, SensitiveCreate, SensitiveData,
     },
-    tss2_esys::{Esys_Clear, Esys_ClearControl, Esys_CreatePrimary, Esys_HierarchyChangeAuth},
+    tss2_esys::{
+        Esys_ChangeEPS, Esys_ChangePPS, Esys_Clear, Esys_ClearControl, Esys_CreatePrimary,
+        Esys_HierarchyChangeAuth, Esys_HierarchyControl, Esys_SetPrimaryPolicy,
+    },
     Context, Result, ReturnCode,
 };
 use log::error;
@@ -87,10 +94,165 @@ impl Context {
         })
     }
 
-    // Missing function: HierarchyControl
-    // Missing function: SetPrimaryPolicy
-    // Missing function: ChangePPS
-    // Missing function: ChangeEPS
+    /// Enable or disable the use of a hierarchy and its associated NV storage.
+    ///
+    /// # Details
+    /// A hierarchy can be disabled using either the platform authorization
+    /// or the authorization of the hierarchy itself, but it can only be
+    /// enabled again using the platform authorization. The state of the
+    /// hierarchies is reflected in the TPMA_STARTUP_CLEAR attributes and is
+    /// reset on TPM Reset or TPM Restart.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - An [AuthHandle] of the platform or of the hierarchy that
+    ///   is going to be disabled.
+    /// * `enable` - The [Enables] target whose state is going to be changed.
+    /// * `state` - Whether the target is going to be enabled or disabled.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{
+    /// #     Context, TctiNameConf,
+    /// #     handles::AuthHandle,
+    /// #     interface_types::{resource_handles::Enables, session_handles::AuthSession},
+    /// # };
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// context
+    ///     .execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///         ctx.hierarchy_control(AuthHandle::Platform, Enables::Endorsement, false)?;
+    ///         ctx.hierarchy_control(AuthHandle::Platform, Enables::Endorsement, true)
+    ///     })
+    ///     .expect("Failed to call hierarchy_control");
+    /// ```
+    pub fn hierarchy_control(
+        &mut self,
+        auth_handle: AuthHandle,
+        enable: Enables,
+        state: bool,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_HierarchyControl(
+                    self.mut_context(),
+                    auth_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    TpmHandle::from(enable).into(),
+                    YesNo::from(state).into(),
+                )
+            },
+            |ret| {
+                error!("Error in controlling hierarchy: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Set the authorization policy of a hierarchy.
+    ///
+    /// # Details
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - An [AuthHandle] of the owner, endorsement, platform or
+    ///   lockout hierarchy.
+    /// * `auth_policy` - The policy digest. An empty digest removes the policy.
+    /// * `hash_alg` - The [HashingAlgorithm] that was used to compute the policy digest.
+    pub fn set_primary_policy(
+        &mut self,
+        auth_handle: AuthHandle,
+        auth_policy: Digest,
+        hash_alg: HashingAlgorithm,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_SetPrimaryPolicy(
+                    self.mut_context(),
+                    auth_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &auth_policy.into(),
+                    hash_alg.into(),
+                )
+            },
+            |ret| {
+                error!("Error when setting primary policy: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Replace the platform primary seed with a new value.
+    ///
+    /// # Details
+    /// This flushes all transient objects of the platform hierarchy and
+    /// sets the platform policy to its default value.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [Platform] handle used for authorization.
+    pub fn change_pps(&mut self, auth_handle: Platform) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_ChangePPS(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                )
+            },
+            |ret| {
+                error!(
+                    "Error when changing the platform primary seed: {:#010X}",
+                    ret
+                );
+            },
+        )
+    }
+
+    /// Replace the endorsement primary seed with a new value.
+    ///
+    /// # Details
+    /// This flushes all transient objects of the endorsement hierarchy,
+    /// evicts its persistent objects and resets the endorsement policy
+    /// and the endorsement authorization value. Any endorsement key that
+    /// is derived from the seed is lost.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [Platform] handle used for authorization.
+    pub fn change_eps(&mut self, auth_handle: Platform) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_ChangeEPS(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                )
+            },
+            |ret| {
+                error!(
+                    "Error when changing the endorsement primary seed: {:#010X}",
+                    ret
+                );
+            },
+        )
+    }
 
     /// Clear all TPM context associated with a specific Owner
     pub fn clear(&mut self, auth_handle: AuthHandle) -> Result<()> {
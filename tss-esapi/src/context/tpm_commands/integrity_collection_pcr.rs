@@ -1,9 +1,15 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
-    handles::PcrHandle,
-    structures::{DigestList, DigestValues, PcrSelectionList},
-    tss2_esys::{Esys_PCR_Extend, Esys_PCR_Read, Esys_PCR_Reset},
+    handles::{AuthHandle, PcrHandle, PcrTpmHandle},
+    interface_types::{algorithm::HashingAlgorithm, resource_handles::Platform, YesNo},
+    structures::{
+        Auth, Digest, DigestList, DigestValues, Event, PcrAllocationResult, PcrSelectionList,
+    },
+    tss2_esys::{
+        Esys_PCR_Allocate, Esys_PCR_Event, Esys_PCR_Extend, Esys_PCR_Read, Esys_PCR_Reset,
+        Esys_PCR_SetAuthPolicy, Esys_PCR_SetAuthValue,
+    },
     Context, Result, ReturnCode,
 };
 use log::error;
@@ -105,7 +111,67 @@ impl Context {
         )
     }
 
-    // Missing function: PCR_Event
+    /// Hashes event data and extends a PCR with the result.
+    ///
+    /// # Arguments
+    /// * `pcr_handle`- A [PcrHandle] to the PCR slot that is to be extended.
+    /// * `event_data` - The [Event] data that is to be hashed.
+    ///
+    /// # Details
+    /// The TPM hashes the event data using the hashing algorithm of
+    /// each of the implemented PCR banks and extends the PCR slot in
+    /// each of the banks with the corresponding digest.
+    ///
+    /// # Returns
+    /// The [DigestValues] with the digests of the event data
+    /// for each implemented bank.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use tss_esapi::{
+    /// #     Context, TctiNameConf,
+    /// #     interface_types::session_handles::AuthSession,
+    /// # };
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// use std::convert::TryFrom;
+    /// use tss_esapi::{
+    ///     handles::PcrHandle,
+    ///     interface_types::algorithm::HashingAlgorithm,
+    ///     structures::Event,
+    /// };
+    /// let event_data = Event::try_from("There is no spoon".as_bytes().to_vec())
+    ///     .expect("Failed to create Event");
+    /// let digests = context.execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///     ctx.pcr_event(PcrHandle::Pcr16, event_data)
+    ///         .expect("Call to pcr_event failed")
+    /// });
+    /// assert!(digests.get(HashingAlgorithm::Sha256).is_some());
+    /// ```
+    pub fn pcr_event(&mut self, pcr_handle: PcrHandle, event_data: Event) -> Result<DigestValues> {
+        let mut digests_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PCR_Event(
+                    self.mut_context(),
+                    pcr_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &event_data.into(),
+                    &mut digests_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when performing PCR event: {:#010X}", ret);
+            },
+        )?;
+        DigestValues::try_from(Context::ffi_data_to_owned(digests_ptr))
+    }
 
     /// Reads the values of a PCR.
     ///
@@ -180,9 +246,167 @@ impl Context {
         ))
     }
 
-    // Missing function: PCR_Allocate
-    // Missing function: PCR_SetAuthPolicy
-    // Missing function: PCR_SetAuthValue
+    /// Sets the desired PCR allocation of the TPM.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [Platform] handle used for authorization.
+    /// * `pcr_allocation` - A [PcrSelectionList] with the requested allocation.
+    ///
+    /// # Details
+    /// The PCR banks that are not present in the selection list are left
+    /// unchanged. In order to deactivate a bank it has to be included
+    /// in the selection list without any selected PCRs. The new allocation
+    /// takes effect on the next TPM Reset.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Returns
+    /// A [PcrAllocationResult] indicating if the allocation succeeded together
+    /// with the sizes that were used when evaluating the allocation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use tss_esapi::{
+    /// #     Context, TctiNameConf,
+    /// #     interface_types::{resource_handles::Platform, session_handles::AuthSession},
+    /// # };
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// use tss_esapi::{
+    ///     interface_types::algorithm::HashingAlgorithm,
+    ///     structures::{PcrSelectionListBuilder, PcrSlot},
+    /// };
+    /// // Keep all the PCRs of the SHA-256 bank allocated.
+    /// let pcr_allocation = PcrSelectionListBuilder::new()
+    ///     .with_selection(HashingAlgorithm::Sha256,
+    ///         &[
+    ///             PcrSlot::Slot0, PcrSlot::Slot1, PcrSlot::Slot2, PcrSlot::Slot3,
+    ///             PcrSlot::Slot4, PcrSlot::Slot5, PcrSlot::Slot6, PcrSlot::Slot7,
+    ///             PcrSlot::Slot8, PcrSlot::Slot9, PcrSlot::Slot10, PcrSlot::Slot11,
+    ///             PcrSlot::Slot12, PcrSlot::Slot13, PcrSlot::Slot14, PcrSlot::Slot15,
+    ///             PcrSlot::Slot16, PcrSlot::Slot17, PcrSlot::Slot18, PcrSlot::Slot19,
+    ///             PcrSlot::Slot20, PcrSlot::Slot21, PcrSlot::Slot22, PcrSlot::Slot23,
+    ///     ])
+    ///     .build()
+    ///     .expect("Failed to build PcrSelectionList");
+    /// let allocation_result = context
+    ///     .execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///         ctx.pcr_allocate(Platform::Platform, pcr_allocation)
+    ///     })
+    ///     .expect("Call to pcr_allocate failed");
+    /// assert!(allocation_result.allocation_success);
+    /// ```
+    pub fn pcr_allocate(
+        &mut self,
+        auth_handle: Platform,
+        pcr_allocation: PcrSelectionList,
+    ) -> Result<PcrAllocationResult> {
+        let mut allocation_success = YesNo::No.into();
+        let mut max_pcr: u32 = 0;
+        let mut size_needed: u32 = 0;
+        let mut size_available: u32 = 0;
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PCR_Allocate(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &pcr_allocation.into(),
+                    &mut allocation_success,
+                    &mut max_pcr,
+                    &mut size_needed,
+                    &mut size_available,
+                )
+            },
+            |ret| {
+                error!("Error when allocating PCR banks: {:#010X}", ret);
+            },
+        )?;
+
+        Ok(PcrAllocationResult {
+            allocation_success: YesNo::try_from(allocation_success)?.into(),
+            max_pcr,
+            size_needed,
+            size_available,
+        })
+    }
+
+    /// Associates a policy with a group of PCRs.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [Platform] handle used for authorization.
+    /// * `auth_policy` - The policy digest. An empty digest removes the policy.
+    /// * `hash_alg` - The [HashingAlgorithm] of the policy digest.
+    /// * `pcr_num` - A [PcrTpmHandle] of a PCR in the group whose policy is set.
+    ///
+    /// # Details
+    /// The policy is only used for PCRs that belong to a policy group
+    /// of the platform.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    pub fn pcr_set_auth_policy(
+        &mut self,
+        auth_handle: Platform,
+        auth_policy: Digest,
+        hash_alg: HashingAlgorithm,
+        pcr_num: PcrTpmHandle,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PCR_SetAuthPolicy(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &auth_policy.into(),
+                    hash_alg.into(),
+                    pcr_num.into(),
+                )
+            },
+            |ret| {
+                error!("Error when setting PCR auth policy: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Changes the authorization value of a group of PCRs.
+    ///
+    /// # Arguments
+    /// * `pcr_handle` - A [PcrHandle] of a PCR in the group whose authorization value is set.
+    /// * `auth` - The new [Auth] value.
+    ///
+    /// # Details
+    /// The authorization value is only used for PCRs that belong to an
+    /// authorization group of the platform.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    pub fn pcr_set_auth_value(&mut self, pcr_handle: PcrHandle, auth: Auth) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PCR_SetAuthValue(
+                    self.mut_context(),
+                    pcr_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &auth.into(),
+                )
+            },
+            |ret| {
+                error!("Error when setting PCR auth value: {:#010X}", ret);
+            },
+        )
+    }
 
     /// Resets the value in a PCR.
     ///
@@ -1,8 +1,89 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    handles::AuthHandle,
+    interface_types::resource_handles::Platform,
+    structures::CommandCodeList,
+    tss2_esys::{Esys_PP_Commands, Esys_SetAlgorithmSet},
+    Context, Result, ReturnCode,
+};
+use log::error;
 
 impl Context {
-    // Missing function: PP_Commands
-    // Missing function: SetAlgorithmSet
+    /// Changes the list of commands that require physical presence
+    /// for platform authorization.
+    ///
+    /// # Details
+    /// The commands in `set_list` are added to, and the commands in
+    /// `clear_list` are removed from, the list of commands that require
+    /// the assertion of physical presence. The current list can be read
+    /// with [Context::get_pp_commands].
+    ///
+    /// The command itself always requires physical presence.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth` - The [Platform] authorization handle.
+    /// * `set_list` - The commands that are to be added to the list.
+    /// * `clear_list` - The commands that are to be removed from the list.
+    ///
+    /// # Errors
+    /// * if a command in the lists does not support physical presence, or
+    ///   if a command in `clear_list` always requires physical presence, the
+    ///   TPM error is returned.
+    pub fn pp_commands(
+        &mut self,
+        auth: Platform,
+        set_list: CommandCodeList,
+        clear_list: CommandCodeList,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_PP_Commands(
+                    self.mut_context(),
+                    AuthHandle::from(auth).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &set_list.into(),
+                    &clear_list.into(),
+                )
+            },
+            |ret| {
+                error!("Error when setting PP commands: {:#010X}", ret);
+            },
+        )
+    }
+
+    /// Sets the algorithm set of the TPM.
+    ///
+    /// # Details
+    /// The meaning of the algorithm set is vendor dependent and
+    /// the change takes effect on the next TPM Reset.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth` - The [Platform] authorization handle.
+    /// * `algorithm_set` - A vendor dependent value selecting the algorithm set.
+    pub fn set_algorithm_set(&mut self, auth: Platform, algorithm_set: u32) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_SetAlgorithmSet(
+                    self.mut_context(),
+                    AuthHandle::from(auth).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    algorithm_set,
+                )
+            },
+            |ret| {
+                error!("Error when setting algorithm set: {:#010X}", ret);
+            },
+        )
+    }
 }
@@ -2,12 +2,16 @@
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
     context::handle_manager::HandleDropAction,
-    handles::{AuthHandle, NvIndexHandle, ObjectHandle},
-    interface_types::resource_handles::{NvAuth, Provision},
-    structures::{Auth, MaxNvBuffer, Name, NvPublic},
+    handles::{AuthHandle, KeyHandle, NvIndexHandle, ObjectHandle},
+    interface_types::resource_handles::{NvAuth, Platform, Provision},
+    structures::{
+        Attest, AttestBuffer, Auth, Data, MaxNvBuffer, Name, NvPublic, Signature, SignatureScheme,
+    },
     tss2_esys::{
-        Esys_NV_DefineSpace, Esys_NV_Increment, Esys_NV_Read, Esys_NV_ReadPublic,
-        Esys_NV_UndefineSpace, Esys_NV_Write,
+        Esys_NV_Certify, Esys_NV_ChangeAuth, Esys_NV_DefineSpace, Esys_NV_Extend,
+        Esys_NV_GlobalWriteLock, Esys_NV_Increment, Esys_NV_Read, Esys_NV_ReadLock,
+        Esys_NV_ReadPublic, Esys_NV_SetBits, Esys_NV_UndefineSpace, Esys_NV_UndefineSpaceSpecial,
+        Esys_NV_Write, Esys_NV_WriteLock,
     },
     Context, Result, ReturnCode,
 };
@@ -226,7 +230,48 @@ impl Context {
         self.handle_manager.set_as_closed(nv_index_handle.into())
     }
 
-    // Missing function: UndefineSpaceSpecial
+    /// Deletes an index in the non volatile storage that has
+    /// the policy delete attribute set.
+    ///
+    /// # Details
+    /// NV indices defined with the `TPMA_NV_POLICY_DELETE` attribute can
+    /// only be removed using this method. It requires both a policy session
+    /// satisfying the authorization policy of the index and the platform
+    /// authorization.
+    ///
+    /// Please beware that this method requires two authorization session
+    /// handles to be present. The first session has to be a policy session
+    /// for the NV index that includes TPM2_PolicyCommandCode with
+    /// [CommandCode::NvUndefineSpaceSpecial](crate::constants::CommandCode::NvUndefineSpaceSpecial),
+    /// see [crate::abstraction::nv::undefine_space_special_session], and the
+    /// second session is used for the platform authorization.
+    ///
+    /// # Arguments
+    /// * `nv_index_handle` - The [NvIndexHandle] of the NV index that is to be deleted.
+    /// * `platform` - The [Platform] authorization handle.
+    pub fn nv_undefine_space_special(
+        &mut self,
+        nv_index_handle: NvIndexHandle,
+        platform: Platform,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_UndefineSpaceSpecial(
+                    self.mut_context(),
+                    nv_index_handle.into(),
+                    AuthHandle::from(platform).into(),
+                    self.required_session_1()?,
+                    self.required_session_2()?,
+                    self.optional_session_3(),
+                )
+            },
+            |ret| {
+                error!("Error when undefining NV space special: {:#010X}", ret);
+            },
+        )?;
+
+        self.handle_manager.set_as_closed(nv_index_handle.into())
+    }
 
     /// Reads the public part of an nv index.
     ///
@@ -556,10 +601,215 @@ impl Context {
         )
     }
 
-    // Missing function: NV_Extend
-    // Missing function: NV_SetBits
-    // Missing function: NV_WriteLock
-    // Missing function: NV_GlobalWriteLock
+    /// Extends data to an nv index.
+    ///
+    /// # Details
+    /// This method is used to extend a value to an nv index
+    /// in a manner similar to extending a PCR. The nv index
+    /// needs to be of the type [NvIndexType::Extend](crate::constants::NvIndexType::Extend).
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - Handle indicating the source of authorization value.
+    /// * `nv_index_handle` - The [NvIndexHandle] associated with NV memory
+    ///                       to which the data is to be extended.
+    /// * `data` - The data to extend.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{
+    /// #     Context, TctiNameConf, attributes::{SessionAttributes, NvIndexAttributes},
+    /// #     handles::NvIndexTpmHandle, interface_types::algorithm::HashingAlgorithm,
+    /// #     structures::{SymmetricDefinition, NvPublic}, constants::SessionType,
+    /// #     constants::nv_index_type::NvIndexType,
+    /// # };
+    /// use tss_esapi::{
+    ///       interface_types::resource_handles::{Provision, NvAuth}, structures::MaxNvBuffer,
+    /// };
+    /// use std::convert::TryFrom;
+    ///
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// #
+    /// # let session = context
+    /// #     .start_auth_session(
+    /// #         None,
+    /// #         None,
+    /// #         None,
+    /// #         SessionType::Hmac,
+    /// #         SymmetricDefinition::AES_256_CFB,
+    /// #         tss_esapi::interface_types::algorithm::HashingAlgorithm::Sha256,
+    /// #     )
+    /// #     .expect("Failed to create session")
+    /// #     .expect("Received invalid handle");
+    /// # let (session_attributes, session_attributes_mask) = SessionAttributes::builder()
+    /// #     .with_decrypt(true)
+    /// #     .with_encrypt(true)
+    /// #     .build();
+    /// # context.tr_sess_set_attributes(session, session_attributes, session_attributes_mask)
+    /// #     .expect("Failed to set attributes on session");
+    /// # context.set_sessions((Some(session), None, None));
+    /// #
+    /// # let nv_index = NvIndexTpmHandle::new(0x01500028)
+    /// #     .expect("Failed to create NV index tpm handle");
+    /// #
+    /// # // Create NV index attributes
+    /// # let owner_nv_index_attributes = NvIndexAttributes::builder()
+    /// #     .with_owner_write(true)
+    /// #     .with_owner_read(true)
+    /// #     .with_nv_index_type(NvIndexType::Extend)
+    /// #     .build()
+    /// #     .expect("Failed to create owner nv index attributes");
+    /// #
+    /// # // Create owner nv public.
+    /// # let owner_nv_public = NvPublic::builder()
+    /// #     .with_nv_index(nv_index)
+    /// #     .with_index_name_algorithm(HashingAlgorithm::Sha256)
+    /// #     .with_index_attributes(owner_nv_index_attributes)
+    /// #     .with_data_area_size(32)
+    /// #     .build()
+    /// #     .expect("Failed to build NvPublic for owner");
+    /// #
+    /// let data = MaxNvBuffer::try_from(vec![1, 2, 3, 4, 5, 6, 7])
+    ///    .expect("Failed to create MaxNvBuffer from vec");
+    ///
+    /// let nv_index_handle = context
+    ///    .nv_define_space(Provision::Owner, None, owner_nv_public)
+    ///    .expect("Call to nv_define_space failed");
+    ///
+    /// // Extend data using owner authorization
+    /// let nv_extend_result = context.nv_extend(NvAuth::Owner, nv_index_handle, data);
+    ///
+    /// context
+    ///     .nv_undefine_space(Provision::Owner, nv_index_handle)
+    ///     .expect("Call to nv_undefine_space failed");
+    ///
+    /// // Process result
+    /// nv_extend_result.expect("Call to nv_extend failed");
+    /// ```
+    pub fn nv_extend(
+        &mut self,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+        data: MaxNvBuffer,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_Extend(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    nv_index_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &data.into(),
+                )
+            },
+            |ret| error!("Error when extending NV: {:#010X}", ret),
+        )
+    }
+
+    /// Sets bits in an nv index.
+    ///
+    /// # Details
+    /// This method is used to OR a value into an nv index
+    /// of the type [NvIndexType::Bits](crate::constants::NvIndexType::Bits).
+    /// Bits that have been set cannot be cleared.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - Handle indicating the source of authorization value.
+    /// * `nv_index_handle` - The [NvIndexHandle] associated with NV memory
+    ///                       in which the bits are to be set.
+    /// * `bits` - The bits to set.
+    pub fn nv_set_bits(
+        &mut self,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+        bits: u64,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_SetBits(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    nv_index_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    bits,
+                )
+            },
+            |ret| error!("Error when setting bits in NV: {:#010X}", ret),
+        )
+    }
+
+    /// Prevents further writes to an nv index.
+    ///
+    /// # Details
+    /// The nv index needs to have either the `write_define` or
+    /// the `write_stclear` attribute set. If `write_stclear` is set
+    /// then the lock is released on the next TPM Reset or TPM Restart.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - Handle indicating the source of authorization value.
+    /// * `nv_index_handle` - The [NvIndexHandle] associated with NV memory
+    ///                       that is to be write locked.
+    pub fn nv_write_lock(
+        &mut self,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_WriteLock(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    nv_index_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                )
+            },
+            |ret| error!("Error when write locking NV: {:#010X}", ret),
+        )
+    }
+
+    /// Prevents further writes to all nv indices that have the
+    /// `global_lock` attribute set.
+    ///
+    /// # Details
+    /// The lock is released on the next TPM Reset or TPM Restart.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [Provision] used for authorization.
+    pub fn nv_global_write_lock(&mut self, auth_handle: Provision) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_GlobalWriteLock(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                )
+            },
+            |ret| error!("Error when global write locking NV: {:#010X}", ret),
+        )
+    }
 
     /// Reads data from the nv index.
     ///
@@ -686,7 +936,133 @@ impl Context {
         MaxNvBuffer::try_from(Context::ffi_data_to_owned(data_ptr))
     }
 
-    // Missing function: NV_ReadLock
-    // Missing function: NV_ChangeAuth
-    // Missing function: NV_Certify
+    /// Prevents further reads of an nv index.
+    ///
+    /// # Details
+    /// The nv index needs to have the `read_stclear` attribute set.
+    /// The lock is released on the next TPM Reset or TPM Restart.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - Handle indicating the source of authorization value.
+    /// * `nv_index_handle` - The [NvIndexHandle] associated with NV memory
+    ///                       that is to be read locked.
+    pub fn nv_read_lock(
+        &mut self,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+    ) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_ReadLock(
+                    self.mut_context(),
+                    AuthHandle::from(auth_handle).into(),
+                    nv_index_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                )
+            },
+            |ret| error!("Error when read locking NV: {:#010X}", ret),
+        )
+    }
+
+    /// Changes the authorization value of an nv index.
+    ///
+    /// # Details
+    /// This command requires ADMIN role authorization, which means
+    /// that the session in session slot 1 needs to be a policy
+    /// session that satisfies the auth policy of the nv index,
+    /// e.g. one that includes a PolicyCommandCode with the command
+    /// code of NV_ChangeAuth.
+    ///
+    /// # Arguments
+    /// * `nv_index_handle` - The [NvIndexHandle] associated with NV memory
+    ///                       whose authorization value is to be changed.
+    /// * `new_auth` - The new authorization value.
+    pub fn nv_change_auth(&mut self, nv_index_handle: NvIndexHandle, new_auth: Auth) -> Result<()> {
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_ChangeAuth(
+                    self.mut_context(),
+                    nv_index_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &new_auth.into(),
+                )
+            },
+            |ret| error!("Error when changing the auth value of NV: {:#010X}", ret),
+        )
+    }
+
+    /// Certifies the contents of an nv index.
+    ///
+    /// # Details
+    /// This method is used to get a signed attestation of the
+    /// contents of an area in NV memory of the TPM.
+    ///
+    /// Please beware that this method requires authorization
+    /// sessions to be present in session slot 1, for the signing key,
+    /// and in session slot 2, for the nv index.
+    ///
+    /// # Arguments
+    /// * `signing_key_handle` - Handle of the key used to sign the attestation buffer.
+    /// * `auth_handle` - Handle indicating the source of authorization value.
+    /// * `nv_index_handle` - The [NvIndexHandle] associated with NV memory
+    ///                       that is to be certified.
+    /// * `qualifying_data` - Qualifying data.
+    /// * `signing_scheme` - Signing scheme to use if the scheme for `signing_key_handle` is `Null`.
+    /// * `size` - The number of octets to certify.
+    /// * `offset`- Octet offset into the NV area.
+    ///
+    /// # Returns
+    /// The command returns a tuple consisting of:
+    /// * `certify_info` - TPM-generated attestation data.
+    /// * `signature` - Signature for the attestation data.
+    #[allow(clippy::too_many_arguments)]
+    pub fn nv_certify(
+        &mut self,
+        signing_key_handle: KeyHandle,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+        qualifying_data: Data,
+        signing_scheme: SignatureScheme,
+        size: u16,
+        offset: u16,
+    ) -> Result<(Attest, Signature)> {
+        let mut certify_info_ptr = null_mut();
+        let mut signature_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_NV_Certify(
+                    self.mut_context(),
+                    signing_key_handle.into(),
+                    AuthHandle::from(auth_handle).into(),
+                    nv_index_handle.into(),
+                    self.required_session_1()?,
+                    self.required_session_2()?,
+                    self.optional_session_3(),
+                    &qualifying_data.into(),
+                    &signing_scheme.into(),
+                    size,
+                    offset,
+                    &mut certify_info_ptr,
+                    &mut signature_ptr,
+                )
+            },
+            |ret| {
+                error!("Error when certifying NV: {:#010X}", ret);
+            },
+        )?;
+
+        let certify_info = Context::ffi_data_to_owned(certify_info_ptr);
+        let signature = Context::ffi_data_to_owned(signature_ptr);
+        Ok((
+            Attest::try_from(AttestBuffer::try_from(certify_info)?)?,
+            Signature::try_from(signature)?,
+        ))
+    }
 }
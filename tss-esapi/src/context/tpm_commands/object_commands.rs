@@ -5,15 +5,18 @@ mod create_command_output;
 
 use crate::{
     context::handle_manager::HandleDropAction,
+    ffi::data_zeroize::FfiDataZeroize,
     handles::{KeyHandle, ObjectHandle, TpmHandle},
     interface_types::resource_handles::Hierarchy,
     structures::{
-        Auth, CreateKeyResult, Data, Digest, EncryptedSecret, IdObject, Name, PcrSelectionList,
-        Private, Public, Sensitive, SensitiveData,
+        Auth, CreateKeyResult, CreateLoadedKeyResult, Data, Digest, EncryptedSecret, IdObject,
+        Name, ObjectTemplate, PcrSelectionList, Private, Public, Sensitive, SensitiveCreate,
+        SensitiveData,
     },
     tss2_esys::{
-        Esys_ActivateCredential, Esys_Create, Esys_Load, Esys_LoadExternal, Esys_MakeCredential,
-        Esys_ObjectChangeAuth, Esys_ReadPublic, Esys_Unseal,
+        Esys_ActivateCredential, Esys_Create, Esys_CreateLoaded, Esys_Load, Esys_LoadExternal,
+        Esys_MakeCredential, Esys_ObjectChangeAuth, Esys_ReadPublic, Esys_Unseal,
+        TPM2B_SENSITIVE_CREATE,
     },
     Context, Result, ReturnCode,
 };
@@ -338,5 +341,125 @@ impl Context {
         Private::try_from(Context::ffi_data_to_owned(out_private_ptr))
     }
 
-    // Missing function: CreateLoaded
+    /// Create an object and load it into the TPM.
+    ///
+    /// # Details
+    /// The object is created and loaded in a single command. If the parent is a
+    /// hierarchy then a primary object is created. If the parent is a derivation
+    /// parent then the object is derived from the seed of the parent using the
+    /// label and context of a template created with
+    /// [Template::derived](crate::structures::Template::derived).
+    ///
+    /// # Arguments
+    /// * `parent_handle` - The [ObjectHandle] of the parent key or of the hierarchy.
+    /// * `template` - The [ObjectTemplate] of the object that is being created.
+    /// * `auth_value` - The value used to be used for authorize usage of the object.
+    /// * `sensitive_data` - The data that is to be sealed or a key.
+    ///
+    /// # Returns
+    /// A [CreateLoadedKeyResult] containing the handle of the loaded object
+    /// together with its private and public parts.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{
+    /// #     Context, TctiNameConf,
+    /// #     attributes::ObjectAttributesBuilder,
+    /// #     interface_types::{
+    /// #         algorithm::{HashingAlgorithm, PublicAlgorithm},
+    /// #         key_bits::RsaKeyBits,
+    /// #         resource_handles::Hierarchy,
+    /// #         session_handles::AuthSession,
+    /// #     },
+    /// #     structures::{
+    /// #         PublicBuilder, PublicKeyRsa, PublicRsaParametersBuilder, RsaExponent,
+    /// #         RsaScheme, SymmetricDefinitionObject,
+    /// #     },
+    /// # };
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// # let object_attributes = ObjectAttributesBuilder::new()
+    /// #     .with_fixed_tpm(true)
+    /// #     .with_fixed_parent(true)
+    /// #     .with_sensitive_data_origin(true)
+    /// #     .with_user_with_auth(true)
+    /// #     .with_decrypt(true)
+    /// #     .with_restricted(true)
+    /// #     .build()
+    /// #     .expect("Failed to build object attributes");
+    /// # let primary_public = PublicBuilder::new()
+    /// #     .with_public_algorithm(PublicAlgorithm::Rsa)
+    /// #     .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+    /// #     .with_object_attributes(object_attributes)
+    /// #     .with_rsa_parameters(
+    /// #         PublicRsaParametersBuilder::new_restricted_decryption_key(
+    /// #             SymmetricDefinitionObject::AES_128_CFB,
+    /// #             RsaKeyBits::Rsa2048,
+    /// #             RsaExponent::default(),
+    /// #         )
+    /// #         .build()
+    /// #         .expect("Failed to build rsa parameters"),
+    /// #     )
+    /// #     .with_rsa_unique_identifier(PublicKeyRsa::default())
+    /// #     .build()
+    /// #     .expect("Failed to build public");
+    /// let create_loaded_result = context
+    ///     .execute_with_session(Some(AuthSession::Password), |ctx| {
+    ///         ctx.create_loaded(Hierarchy::Owner.into(), primary_public.into(), None, None)
+    ///     })
+    ///     .expect("Failed to call create_loaded");
+    /// # context
+    /// #     .flush_context(create_loaded_result.key_handle.into())
+    /// #     .expect("Failed to flush key");
+    /// ```
+    pub fn create_loaded(
+        &mut self,
+        parent_handle: ObjectHandle,
+        template: ObjectTemplate,
+        auth_value: Option<Auth>,
+        sensitive_data: Option<SensitiveData>,
+    ) -> Result<CreateLoadedKeyResult> {
+        let mut in_sensitive: TPM2B_SENSITIVE_CREATE = SensitiveCreate::new(
+            auth_value.unwrap_or_default(),
+            sensitive_data.unwrap_or_default(),
+        )
+        .try_into()?;
+        let in_public = template.try_into()?;
+        let mut object_handle = ObjectHandle::None.into();
+        let mut out_private_ptr = null_mut();
+        let mut out_public_ptr = null_mut();
+        let result = ReturnCode::ensure_success(
+            unsafe {
+                Esys_CreateLoaded(
+                    self.mut_context(),
+                    parent_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &in_sensitive,
+                    &in_public,
+                    &mut object_handle,
+                    &mut out_private_ptr,
+                    &mut out_public_ptr,
+                )
+            },
+            |ret| {
+                error!("Error in creating and loading object: {:#010X}", ret);
+            },
+        );
+        in_sensitive.ffi_data_zeroize();
+        result?;
+
+        let key_handle = KeyHandle::from(object_handle);
+        self.handle_manager
+            .add_handle(key_handle.into(), HandleDropAction::Flush)?;
+        Ok(CreateLoadedKeyResult {
+            key_handle,
+            out_private: Private::try_from(Context::ffi_data_to_owned(out_private_ptr))?,
+            out_public: Public::try_from(Context::ffi_data_to_owned(out_public_ptr))?,
+        })
+    }
 }
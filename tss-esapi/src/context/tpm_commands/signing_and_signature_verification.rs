@@ -40,6 +40,12 @@ impl Context {
     }
 
     /// Sign a digest with a key present in the TPM and return the signature.
+    ///
+    /// # Details
+    /// In order to produce an ECDAA signature, [Context::commit] has to be
+    /// called first and the returned counter has to be provided as the count
+    /// of the [EcDaaScheme](crate::structures::EcDaaScheme) in `scheme`, e.g.
+    /// `SignatureScheme::try_from(EccScheme::EcDaa(EcDaaScheme::new(hashing_algorithm, counter)))`.
     pub fn sign(
         &mut self,
         key_handle: KeyHandle,
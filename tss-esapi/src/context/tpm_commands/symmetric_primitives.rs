@@ -3,11 +3,11 @@
 use crate::{
     handles::{KeyHandle, ObjectHandle, TpmHandle},
     interface_types::{
-        algorithm::{HashingAlgorithm, SymmetricMode},
+        algorithm::{HashingAlgorithm, MacAlgorithm, SymmetricMode},
         resource_handles::Hierarchy,
     },
     structures::{Digest, HashcheckTicket, InitialValue, MaxBuffer},
-    tss2_esys::{Esys_EncryptDecrypt2, Esys_HMAC, Esys_Hash},
+    tss2_esys::{Esys_EncryptDecrypt, Esys_EncryptDecrypt2, Esys_HMAC, Esys_Hash},
     Context, Result, ReturnCode,
 };
 use log::error;
@@ -15,7 +15,71 @@ use std::convert::TryFrom;
 use std::ptr::null_mut;
 
 impl Context {
-    // Missing function: EncryptDecrypt, deprecated use EncryptDecrypt2 instead.
+    /// Performs symmetric encryption or decryption of the data using
+    /// the key associated with the `key_handle`
+    ///
+    /// # Details
+    /// This is the original version of the command which is deprecated
+    /// in favour of [Context::encrypt_decrypt_2]. It is provided for TPMs
+    /// that do not implement TPM2_EncryptDecrypt2. The
+    /// [encrypt_decrypt](crate::abstraction::cipher::encrypt_decrypt) function
+    /// can be used to pick the command that is supported by the TPM.
+    ///
+    /// The arguments are in the same order as for [Context::encrypt_decrypt_2]
+    /// even though the TPM command takes the initial value before the data.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `key_handle` -  A [KeyHandle] to the key to be used.
+    /// * `decrypt` - A boolean indicating if the data should be decrypted or encrypted.
+    ///   If set to true the data will be decrypted else encrypted.
+    /// * `mode` - The [SymmetricMode] to be used.
+    /// * `in_data` - The data that is going to be decrypted or encrypted.
+    /// * `initial_value_in` - An initial value as required by the algorithm.
+    ///
+    /// # Returns
+    /// The decrypted or encrypted data and the chaining value
+    /// for a subsequent operation.
+    pub fn encrypt_decrypt(
+        &mut self,
+        key_handle: KeyHandle,
+        decrypt: bool,
+        mode: SymmetricMode,
+        in_data: MaxBuffer,
+        initial_value_in: InitialValue,
+    ) -> Result<(MaxBuffer, InitialValue)> {
+        let mut out_data_ptr = null_mut();
+        let mut iv_out_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_EncryptDecrypt(
+                    self.mut_context(),
+                    key_handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    decrypt.into(),
+                    mode.into(),
+                    &initial_value_in.into(),
+                    &in_data.into(),
+                    &mut out_data_ptr,
+                    &mut iv_out_ptr,
+                )
+            },
+            |ret| {
+                error!(
+                    "Error failed to perform encrypt or decrypt operations {:#010X}",
+                    ret
+                );
+            },
+        )?;
+        Ok((
+            MaxBuffer::try_from(Context::ffi_data_to_owned(out_data_ptr))?,
+            InitialValue::try_from(Context::ffi_data_to_owned(iv_out_ptr))?,
+        ))
+    }
 
     /// Performs symmetric encryption or decryption of the data using
     /// the key associated with the `key_handle`
@@ -374,5 +438,50 @@ impl Context {
         Digest::try_from(Context::ffi_data_to_owned(out_hmac_ptr))
     }
 
-    // Missing function: MAC
+    /// Asks the TPM to compute a MAC over buffer with the specified key
+    ///
+    /// # Details
+    /// TPM2_MAC shares its command code with TPM2_HMAC. The difference is
+    /// that the scheme can be a symmetric block cipher based MAC, like
+    /// [MacAlgorithm::Cmac], in which case the key has to be a symmetric
+    /// cipher key that has the sign attribute set.
+    ///
+    /// Please beware that this method requires an authorization
+    /// session handle to be present.
+    ///
+    /// # Arguments
+    /// * `handle` - A handle to the key that is used to compute the MAC.
+    /// * `buffer` - The data that is to be authenticated.
+    /// * `in_scheme` - The [MacAlgorithm] to use, or [MacAlgorithm::Null]
+    ///   to use the default scheme of the key.
+    ///
+    /// # Errors
+    /// * if the TPM does not support the scheme or the scheme is not compatible with
+    ///   the key, the TPM error is returned.
+    pub fn mac(
+        &mut self,
+        handle: ObjectHandle,
+        buffer: MaxBuffer,
+        in_scheme: MacAlgorithm,
+    ) -> Result<Digest> {
+        let mut out_mac_ptr = null_mut();
+        ReturnCode::ensure_success(
+            unsafe {
+                Esys_HMAC(
+                    self.mut_context(),
+                    handle.into(),
+                    self.required_session_1()?,
+                    self.optional_session_2(),
+                    self.optional_session_3(),
+                    &buffer.into(),
+                    in_scheme.into(),
+                    &mut out_mac_ptr,
+                )
+            },
+            |ret| {
+                error!("Error in mac: {:#010X}", ret);
+            },
+        )?;
+        Digest::try_from(Context::ffi_data_to_owned(out_mac_ptr))
+    }
 }
@@ -346,3 +346,16 @@ pub mod session {
     impl_handle_conversion!(SessionHandle, ObjectHandle);
     impl_handle_conversion!(SessionHandle, AuthHandle);
 }
+
+/// Attached component handle module
+pub mod ac {
+    use super::object::ObjectHandle;
+    impl_basic_handle!(
+        /// Attached Component Handle
+        ///
+        /// Represents an esys resource handle
+        /// for an attached component.
+        AcHandle
+    );
+    impl_handle_conversion!(AcHandle, ObjectHandle);
+}
@@ -8,6 +8,7 @@
 /////////////////////////////////////////////////////////
 /// ESYS Handles
 /////////////////////////////////////////////////////////
+pub use handle::ac::AcHandle;
 pub use handle::auth::AuthHandle;
 pub use handle::key::KeyHandle;
 pub use handle::nv_index::NvIndexHandle;
@@ -3,9 +3,9 @@
 use crate::{
     constants::AlgorithmIdentifier,
     tss2_esys::{
-        TPMI_ALG_ASYM, TPMI_ALG_ECC_SCHEME, TPMI_ALG_HASH, TPMI_ALG_KDF, TPMI_ALG_KEYEDHASH_SCHEME,
-        TPMI_ALG_PUBLIC, TPMI_ALG_RSA_DECRYPT, TPMI_ALG_RSA_SCHEME, TPMI_ALG_SIG_SCHEME,
-        TPMI_ALG_SYM, TPMI_ALG_SYM_MODE, TPMI_ALG_SYM_OBJECT,
+        TPM2_ALG_ID, TPMI_ALG_ASYM, TPMI_ALG_ECC_SCHEME, TPMI_ALG_HASH, TPMI_ALG_KDF,
+        TPMI_ALG_KEYEDHASH_SCHEME, TPMI_ALG_PUBLIC, TPMI_ALG_RSA_DECRYPT, TPMI_ALG_RSA_SCHEME,
+        TPMI_ALG_SIG_SCHEME, TPMI_ALG_SYM, TPMI_ALG_SYM_MODE, TPMI_ALG_SYM_OBJECT,
     },
     Error, Result, WrapperErrorKind,
 };
@@ -684,3 +684,90 @@ impl TryFrom<TPMI_ALG_RSA_DECRYPT> for RsaDecryptAlgorithm {
         RsaDecryptAlgorithm::try_from(AlgorithmIdentifier::try_from(tpmi_alg_rsa_decrypt)?)
     }
 }
+
+/// Enum representing the MAC algorithm interface type.
+///
+/// # Details
+/// This corresponds to TPMI_ALG_MAC_SCHEME. The interface type
+/// is not available in all versions of the TSS so the conversions
+/// are made to and from the underlying TPM2_ALG_ID.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MacAlgorithm {
+    Sha1,
+    Sha256,
+    Sha384,
+    Sha512,
+    Sm3_256,
+    Sha3_256,
+    Sha3_384,
+    Sha3_512,
+    Cmac,
+    Null,
+}
+
+impl From<HashingAlgorithm> for MacAlgorithm {
+    fn from(hashing_algorithm: HashingAlgorithm) -> Self {
+        match hashing_algorithm {
+            HashingAlgorithm::Sha1 => MacAlgorithm::Sha1,
+            HashingAlgorithm::Sha256 => MacAlgorithm::Sha256,
+            HashingAlgorithm::Sha384 => MacAlgorithm::Sha384,
+            HashingAlgorithm::Sha512 => MacAlgorithm::Sha512,
+            HashingAlgorithm::Sm3_256 => MacAlgorithm::Sm3_256,
+            HashingAlgorithm::Sha3_256 => MacAlgorithm::Sha3_256,
+            HashingAlgorithm::Sha3_384 => MacAlgorithm::Sha3_384,
+            HashingAlgorithm::Sha3_512 => MacAlgorithm::Sha3_512,
+            HashingAlgorithm::Null => MacAlgorithm::Null,
+        }
+    }
+}
+
+impl From<MacAlgorithm> for AlgorithmIdentifier {
+    fn from(mac_algorithm: MacAlgorithm) -> Self {
+        match mac_algorithm {
+            MacAlgorithm::Sha1 => AlgorithmIdentifier::Sha1,
+            MacAlgorithm::Sha256 => AlgorithmIdentifier::Sha256,
+            MacAlgorithm::Sha384 => AlgorithmIdentifier::Sha384,
+            MacAlgorithm::Sha512 => AlgorithmIdentifier::Sha512,
+            MacAlgorithm::Sm3_256 => AlgorithmIdentifier::Sm3_256,
+            MacAlgorithm::Sha3_256 => AlgorithmIdentifier::Sha3_256,
+            MacAlgorithm::Sha3_384 => AlgorithmIdentifier::Sha3_384,
+            MacAlgorithm::Sha3_512 => AlgorithmIdentifier::Sha3_512,
+            MacAlgorithm::Cmac => AlgorithmIdentifier::Cmac,
+            MacAlgorithm::Null => AlgorithmIdentifier::Null,
+        }
+    }
+}
+
+impl TryFrom<AlgorithmIdentifier> for MacAlgorithm {
+    type Error = Error;
+
+    fn try_from(algorithm_identifier: AlgorithmIdentifier) -> Result<Self> {
+        match algorithm_identifier {
+            AlgorithmIdentifier::Sha1 => Ok(MacAlgorithm::Sha1),
+            AlgorithmIdentifier::Sha256 => Ok(MacAlgorithm::Sha256),
+            AlgorithmIdentifier::Sha384 => Ok(MacAlgorithm::Sha384),
+            AlgorithmIdentifier::Sha512 => Ok(MacAlgorithm::Sha512),
+            AlgorithmIdentifier::Sm3_256 => Ok(MacAlgorithm::Sm3_256),
+            AlgorithmIdentifier::Sha3_256 => Ok(MacAlgorithm::Sha3_256),
+            AlgorithmIdentifier::Sha3_384 => Ok(MacAlgorithm::Sha3_384),
+            AlgorithmIdentifier::Sha3_512 => Ok(MacAlgorithm::Sha3_512),
+            AlgorithmIdentifier::Cmac => Ok(MacAlgorithm::Cmac),
+            AlgorithmIdentifier::Null => Ok(MacAlgorithm::Null),
+            _ => Err(Error::local_error(WrapperErrorKind::InvalidParam)),
+        }
+    }
+}
+
+impl From<MacAlgorithm> for TPM2_ALG_ID {
+    fn from(mac_algorithm: MacAlgorithm) -> Self {
+        AlgorithmIdentifier::from(mac_algorithm).into()
+    }
+}
+
+impl TryFrom<TPM2_ALG_ID> for MacAlgorithm {
+    type Error = Error;
+
+    fn try_from(tpm_alg_id: TPM2_ALG_ID) -> Result<Self> {
+        MacAlgorithm::try_from(AlgorithmIdentifier::try_from(tpm_alg_id)?)
+    }
+}
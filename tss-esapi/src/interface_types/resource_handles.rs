@@ -1,12 +1,15 @@
 // Copyright 2020 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
+    constants::tss::{TPM2_RH_ACT_0, TPM2_RH_ACT_F},
     handles::{
         AttachedComponentTpmHandle, AuthHandle, NvIndexHandle, NvIndexTpmHandle, ObjectHandle,
         PermanentTpmHandle, TpmHandle,
     },
+    tss2_esys::TPM2_HANDLE,
     Error, Result, WrapperErrorKind,
 };
+use log::error;
 use std::convert::TryFrom;
 //////////////////////////////////////////////////////////////////////////////////
 /// Hierarchy
@@ -451,7 +454,92 @@ impl From<AttachedComponent> for AttachedComponentTpmHandle {
 }
 
 //////////////////////////////////////////////////////////////////////////////////
-// Act (authenticated timers)
-//
-// TODO: Figure out how to implement this. This is some kind of counter.
+/// Act
+///
+/// Type representing one of the authenticated countdown timers (ACT)
+/// in a TPM 2.0.
+///
+/// # Details
+/// This corresponds to TPMI_RH_ACT. The TPM can have up to 16
+/// timers which are identified by an index in the range 0 to 15.
 //////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Act {
+    tpm_handle: PermanentTpmHandle,
+}
+
+impl Act {
+    /// The largest valid ACT index.
+    pub const MAX_INDEX: u8 = (TPM2_RH_ACT_F - TPM2_RH_ACT_0) as u8;
+
+    /// Creates a new Act from the index of the timer.
+    ///
+    /// # Errors
+    /// * if the index is larger than [Act::MAX_INDEX], an `InvalidParam` wrapper
+    ///   error is returned.
+    pub fn new(index: u8) -> Result<Act> {
+        if index > Act::MAX_INDEX {
+            error!("Invalid ACT index {} (> {})", index, Act::MAX_INDEX);
+            return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+        }
+        PermanentTpmHandle::new(TPM2_RH_ACT_0 + u32::from(index))
+            .map(|tpm_handle| Act { tpm_handle })
+    }
+
+    /// Returns the index of the timer.
+    pub fn index(&self) -> u8 {
+        (TPM2_HANDLE::from(self.tpm_handle) - TPM2_RH_ACT_0) as u8
+    }
+}
+
+impl From<Act> for PermanentTpmHandle {
+    fn from(act: Act) -> PermanentTpmHandle {
+        act.tpm_handle
+    }
+}
+
+impl From<Act> for TpmHandle {
+    fn from(act: Act) -> TpmHandle {
+        TpmHandle::Permanent(act.tpm_handle)
+    }
+}
+
+impl TryFrom<PermanentTpmHandle> for Act {
+    type Error = Error;
+
+    fn try_from(permanent_tpm_handle: PermanentTpmHandle) -> Result<Act> {
+        let value = TPM2_HANDLE::from(permanent_tpm_handle);
+        if !(TPM2_RH_ACT_0..=TPM2_RH_ACT_F).contains(&value) {
+            error!("TPM handle {:#010X} is not an ACT handle", value);
+            return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+        }
+        Ok(Act {
+            tpm_handle: permanent_tpm_handle,
+        })
+    }
+}
+
+impl TryFrom<TpmHandle> for Act {
+    type Error = Error;
+
+    fn try_from(tpm_handle: TpmHandle) -> Result<Act> {
+        match tpm_handle {
+            TpmHandle::Permanent(permanent_tpm_handle) => Act::try_from(permanent_tpm_handle),
+            _ => Err(Error::local_error(WrapperErrorKind::InvalidParam)),
+        }
+    }
+}
+
+#[cfg(has_esys_act_set_timeout)]
+impl From<Act> for AuthHandle {
+    fn from(act: Act) -> AuthHandle {
+        AuthHandle::from(crate::tss2_esys::ESYS_TR_RH_ACT_FIRST + u32::from(act.index()))
+    }
+}
+
+#[cfg(has_esys_act_set_timeout)]
+impl From<Act> for ObjectHandle {
+    fn from(act: Act) -> ObjectHandle {
+        ObjectHandle::from(crate::tss2_esys::ESYS_TR_RH_ACT_FIRST + u32::from(act.index()))
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{constants::AttachedComponentTag, tss2_esys::TPMS_AC_OUTPUT, Error, Result};
+use std::convert::TryFrom;
+
+/// Structure holding tagged data that has been
+/// returned by an attached component.
+///
+/// # Details
+/// This corresponds to the TPMS_AC_OUTPUT.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AcOutput {
+    tag: AttachedComponentTag,
+    data: u32,
+}
+
+impl AcOutput {
+    /// Creates a new AcOutput
+    pub const fn new(tag: AttachedComponentTag, data: u32) -> Self {
+        AcOutput { tag, data }
+    }
+
+    /// Returns the tag of the data.
+    pub const fn tag(&self) -> AttachedComponentTag {
+        self.tag
+    }
+
+    /// Returns the data.
+    pub const fn data(&self) -> u32 {
+        self.data
+    }
+}
+
+impl From<AcOutput> for TPMS_AC_OUTPUT {
+    fn from(ac_output: AcOutput) -> Self {
+        TPMS_AC_OUTPUT {
+            tag: ac_output.tag.into(),
+            data: ac_output.data,
+        }
+    }
+}
+
+impl TryFrom<TPMS_AC_OUTPUT> for AcOutput {
+    type Error = Error;
+
+    fn try_from(tpms_ac_output: TPMS_AC_OUTPUT) -> Result<Self> {
+        Ok(AcOutput {
+            tag: AttachedComponentTag::try_from(tpms_ac_output.tag)?,
+            data: tpms_ac_output.data,
+        })
+    }
+}
@@ -218,6 +218,10 @@ pub mod encrypted_secret {
     named_field_buffer_type!(EncryptedSecret, 256, TPM2B_ENCRYPTED_SECRET, secret);
 }
 
+pub mod event {
+    buffer_type!(Event, 1024, TPM2B_EVENT);
+}
+
 pub mod id_object {
     named_field_buffer_type!(IdObject, 256, TPM2B_ID_OBJECT, credential);
 }
@@ -248,6 +252,10 @@ pub mod nonce {
     buffer_type!(Nonce, 64, TPM2B_NONCE);
 }
 
+pub mod operand {
+    buffer_type!(Operand, 64, TPM2B_OPERAND);
+}
+
 pub mod private {
     use tss_esapi_sys::_PRIVATE;
     buffer_type!(Private, ::std::mem::size_of::<_PRIVATE>(), TPM2B_PRIVATE);
@@ -374,6 +382,66 @@ pub mod symmetric_key {
     buffer_type!(SymmetricKey, TPM2_MAX_SYM_KEY_BYTES as usize, TPM2B_SYM_KEY);
 }
 
+pub mod template {
+    use crate::{
+        structures::Public,
+        traits::Marshall,
+        tss2_esys::{TPM2_LABEL_MAX_BUFFER, TPMT_PUBLIC},
+    };
+    buffer_type!(
+        Template,
+        ::std::mem::size_of::<TPMT_PUBLIC>(),
+        TPM2B_TEMPLATE
+    );
+
+    impl Template {
+        /// Creates a template for an object that is derived from a derivation parent.
+        ///
+        /// # Details
+        /// The template consists of the marshalled `public` structure in which the
+        /// unique field has been replaced by a TPMS_DERIVE structure containing
+        /// the `label` and `context`. The unique field of `public` is ignored.
+        ///
+        /// # Errors
+        /// * if `label` or `context` is larger than TPM2_LABEL_MAX_BUFFER
+        ///   a `WrongParamSize` wrapper error is returned.
+        pub fn derived(public: &Public, label: &[u8], context: &[u8]) -> Result<Self> {
+            if label.len() > TPM2_LABEL_MAX_BUFFER as usize
+                || context.len() > TPM2_LABEL_MAX_BUFFER as usize
+            {
+                error!(
+                    "Invalid derive label or context size(> {})",
+                    TPM2_LABEL_MAX_BUFFER
+                );
+                return Err(Error::local_error(WrapperErrorKind::WrongParamSize));
+            }
+            // The unique field is the last field of the marshalled public structure.
+            let unique_size = match public {
+                Public::Rsa { unique, .. } => 2 + unique.len(),
+                Public::KeyedHash { unique, .. } | Public::SymCipher { unique, .. } => {
+                    2 + unique.len()
+                }
+                Public::Ecc { unique, .. } => 4 + unique.x().len() + unique.y().len(),
+            };
+            let mut template = public.marshall()?;
+            template.truncate(template.len() - unique_size);
+            for value in [label, context] {
+                template.extend_from_slice(&(value.len() as u16).to_be_bytes());
+                template.extend_from_slice(value);
+            }
+            Template::try_from(template)
+        }
+    }
+
+    impl TryFrom<Public> for Template {
+        type Error = Error;
+
+        fn try_from(public: Public) -> Result<Self> {
+            Template::try_from(public.marshall()?)
+        }
+    }
+}
+
 pub mod timeout {
     buffer_type!(Timeout, 8, TPM2B_TIMEOUT);
 }
@@ -1,5 +1,11 @@
 // Copyright 2020 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+#[cfg(has_tpms_act_data)]
+use crate::structures::ActDataList;
+#[cfg(has_tpms_tagged_policy)]
+use crate::structures::TaggedPolicyList;
+#[cfg(not(all(has_tpms_act_data, has_tpms_tagged_policy)))]
+use crate::WrapperErrorKind;
 use crate::{
     constants::CapabilityType,
     structures::{
@@ -7,8 +13,9 @@ use crate::{
         HandleList, PcrSelectionList, TaggedPcrPropertyList, TaggedTpmPropertyList,
     },
     tss2_esys::{TPM2_CAP, TPM2_MAX_CAP_BUFFER, TPMS_CAPABILITY_DATA, TPMU_CAPABILITIES},
-    Error, Result, WrapperErrorKind,
+    Error, Result,
 };
+#[cfg(not(all(has_tpms_act_data, has_tpms_tagged_policy)))]
 use log::error;
 use std::convert::{TryFrom, TryInto};
 use std::mem::size_of;
@@ -30,9 +37,10 @@ pub enum CapabilityData {
     TpmProperties(TaggedTpmPropertyList),
     PcrProperties(TaggedPcrPropertyList),
     EccCurves(EccCurveList),
-    // These are in the TPM TPMU_CAPABILITIES, but are not defined by esapi-2.4.1
-    // AuthPolicies(),
-    // ActData(),
+    #[cfg(has_tpms_tagged_policy)]
+    AuthPolicies(TaggedPolicyList),
+    #[cfg(has_tpms_act_data)]
+    ActData(ActDataList),
 }
 
 pub const fn max_cap_size<T>() -> usize {
@@ -96,6 +104,20 @@ impl From<CapabilityData> for TPMS_CAPABILITY_DATA {
                     eccCurves: data.into(),
                 },
             },
+            #[cfg(has_tpms_tagged_policy)]
+            CapabilityData::AuthPolicies(data) => TPMS_CAPABILITY_DATA {
+                capability: CapabilityType::AuthPolicies.into(),
+                data: TPMU_CAPABILITIES {
+                    authPolicies: data.into(),
+                },
+            },
+            #[cfg(has_tpms_act_data)]
+            CapabilityData::ActData(data) => TPMS_CAPABILITY_DATA {
+                capability: CapabilityType::Act.into(),
+                data: TPMU_CAPABILITIES {
+                    actData: data.into(),
+                },
+            },
         }
     }
 }
@@ -134,12 +156,22 @@ impl TryFrom<TPMS_CAPABILITY_DATA> for CapabilityData {
             CapabilityType::EccCurves => Ok(CapabilityData::EccCurves(
                 unsafe { tpms_capability_data.data.eccCurves }.try_into()?,
             )),
+            #[cfg(has_tpms_tagged_policy)]
+            CapabilityType::AuthPolicies => Ok(CapabilityData::AuthPolicies(
+                unsafe { tpms_capability_data.data.authPolicies }.try_into()?,
+            )),
+            #[cfg(not(has_tpms_tagged_policy))]
             CapabilityType::AuthPolicies => {
-                error!("AuthPolicies capability type is currently not supported");
+                error!("AuthPolicies capability type is not supported by this version of the TSS");
                 Err(Error::WrapperError(WrapperErrorKind::UnsupportedParam))
             }
+            #[cfg(has_tpms_act_data)]
+            CapabilityType::Act => Ok(CapabilityData::ActData(
+                unsafe { tpms_capability_data.data.actData }.try_into()?,
+            )),
+            #[cfg(not(has_tpms_act_data))]
             CapabilityType::Act => {
-                error!("Act capability type is currently not supported");
+                error!("Act capability type is not supported by this version of the TSS");
                 Err(Error::WrapperError(WrapperErrorKind::UnsupportedParam))
             }
         }
@@ -0,0 +1,130 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{
+    interface_types::ecc::EccCurve,
+    structures::{EccParameter, EccPoint, EccScheme, KeyDerivationFunctionScheme},
+    tss2_esys::TPMS_ALGORITHM_DETAIL_ECC,
+    Error, Result,
+};
+use std::convert::{TryFrom, TryInto};
+
+/// Structure holding the domain parameters of an ECC curve.
+///
+/// # Details
+/// This corresponds to the TPMS_ALGORITHM_DETAIL_ECC
+/// which is returned by TPM2_ECC_Parameters().
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlgorithmDetailEcc {
+    curve: EccCurve,
+    key_size: u16,
+    key_derivation_function_scheme: KeyDerivationFunctionScheme,
+    ecc_scheme: EccScheme,
+    p: EccParameter,
+    a: EccParameter,
+    b: EccParameter,
+    g_x: EccParameter,
+    g_y: EccParameter,
+    n: EccParameter,
+    h: EccParameter,
+}
+
+impl AlgorithmDetailEcc {
+    /// Returns the curve the parameters belong to.
+    pub const fn curve(&self) -> EccCurve {
+        self.curve
+    }
+
+    /// Returns the size of the key in bits.
+    pub const fn key_size(&self) -> u16 {
+        self.key_size
+    }
+
+    /// Returns the default key derivation function scheme
+    /// used by the curve.
+    pub const fn key_derivation_function_scheme(&self) -> KeyDerivationFunctionScheme {
+        self.key_derivation_function_scheme
+    }
+
+    /// Returns the default signing scheme used by the curve.
+    pub const fn ecc_scheme(&self) -> EccScheme {
+        self.ecc_scheme
+    }
+
+    /// Returns the Fp (the modulus).
+    pub const fn p(&self) -> &EccParameter {
+        &self.p
+    }
+
+    /// Returns the coefficient of the linear term in the curve equation.
+    pub const fn a(&self) -> &EccParameter {
+        &self.a
+    }
+
+    /// Returns the constant term of the curve equation.
+    pub const fn b(&self) -> &EccParameter {
+        &self.b
+    }
+
+    /// Returns the x coordinate of the base point G.
+    pub const fn g_x(&self) -> &EccParameter {
+        &self.g_x
+    }
+
+    /// Returns the y coordinate of the base point G.
+    pub const fn g_y(&self) -> &EccParameter {
+        &self.g_y
+    }
+
+    /// Returns the order of G.
+    pub const fn n(&self) -> &EccParameter {
+        &self.n
+    }
+
+    /// Returns the cofactor.
+    pub const fn h(&self) -> &EccParameter {
+        &self.h
+    }
+
+    /// Returns the base point G.
+    pub fn generator(&self) -> EccPoint {
+        EccPoint::new(self.g_x.clone(), self.g_y.clone())
+    }
+}
+
+impl From<AlgorithmDetailEcc> for TPMS_ALGORITHM_DETAIL_ECC {
+    fn from(algorithm_detail_ecc: AlgorithmDetailEcc) -> Self {
+        TPMS_ALGORITHM_DETAIL_ECC {
+            curveID: algorithm_detail_ecc.curve.into(),
+            keySize: algorithm_detail_ecc.key_size,
+            kdf: algorithm_detail_ecc.key_derivation_function_scheme.into(),
+            sign: algorithm_detail_ecc.ecc_scheme.into(),
+            p: algorithm_detail_ecc.p.into(),
+            a: algorithm_detail_ecc.a.into(),
+            b: algorithm_detail_ecc.b.into(),
+            gX: algorithm_detail_ecc.g_x.into(),
+            gY: algorithm_detail_ecc.g_y.into(),
+            n: algorithm_detail_ecc.n.into(),
+            h: algorithm_detail_ecc.h.into(),
+        }
+    }
+}
+
+impl TryFrom<TPMS_ALGORITHM_DETAIL_ECC> for AlgorithmDetailEcc {
+    type Error = Error;
+
+    fn try_from(tpms_algorithm_detail_ecc: TPMS_ALGORITHM_DETAIL_ECC) -> Result<Self> {
+        Ok(AlgorithmDetailEcc {
+            curve: EccCurve::try_from(tpms_algorithm_detail_ecc.curveID)?,
+            key_size: tpms_algorithm_detail_ecc.keySize,
+            key_derivation_function_scheme: tpms_algorithm_detail_ecc.kdf.try_into()?,
+            ecc_scheme: tpms_algorithm_detail_ecc.sign.try_into()?,
+            p: tpms_algorithm_detail_ecc.p.try_into()?,
+            a: tpms_algorithm_detail_ecc.a.try_into()?,
+            b: tpms_algorithm_detail_ecc.b.try_into()?,
+            g_x: tpms_algorithm_detail_ecc.gX.try_into()?,
+            g_y: tpms_algorithm_detail_ecc.gY.try_into()?,
+            n: tpms_algorithm_detail_ecc.n.try_into()?,
+            h: tpms_algorithm_detail_ecc.h.try_into()?,
+        })
+    }
+}
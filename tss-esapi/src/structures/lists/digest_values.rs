@@ -4,11 +4,12 @@ use crate::interface_types::algorithm::HashingAlgorithm;
 use crate::structures::Digest;
 use crate::structures::HashAgile;
 use crate::tss2_esys::TPML_DIGEST_VALUES;
-use crate::{Error, Result};
+use crate::{Error, Result, WrapperErrorKind};
+use log::error;
 use std::collections::HashMap;
 use std::convert::{TryFrom, TryInto};
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct DigestValues {
     digests: HashMap<HashingAlgorithm, Digest>,
 }
@@ -23,6 +24,26 @@ impl DigestValues {
     pub fn set(&mut self, alg: HashingAlgorithm, dig: Digest) {
         let _ = self.digests.insert(alg, dig);
     }
+
+    /// Returns the digest for the specified hashing algorithm, if any.
+    pub fn get(&self, alg: HashingAlgorithm) -> Option<&Digest> {
+        self.digests.get(&alg)
+    }
+
+    /// Returns an iterator over the hashing algorithms and their digests.
+    pub fn iter(&self) -> impl Iterator<Item = (&HashingAlgorithm, &Digest)> {
+        self.digests.iter()
+    }
+
+    /// Returns the number of digests.
+    pub fn len(&self) -> usize {
+        self.digests.len()
+    }
+
+    /// Indicates if there are no digests.
+    pub fn is_empty(&self) -> bool {
+        self.digests.is_empty()
+    }
 }
 
 impl TryFrom<DigestValues> for TPML_DIGEST_VALUES {
@@ -38,3 +59,23 @@ impl TryFrom<DigestValues> for TPML_DIGEST_VALUES {
         Ok(tss_digest_values)
     }
 }
+
+impl TryFrom<TPML_DIGEST_VALUES> for DigestValues {
+    type Error = Error;
+    fn try_from(tpml_digest_values: TPML_DIGEST_VALUES) -> Result<Self> {
+        let count = tpml_digest_values.count as usize;
+        if count > tpml_digest_values.digests.len() {
+            error!(
+                "Invalid TPML_DIGEST_VALUES count(> {})",
+                tpml_digest_values.digests.len()
+            );
+            return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+        }
+        let mut digest_values = DigestValues::new();
+        for tpmt_ha in tpml_digest_values.digests[..count].iter() {
+            let hash_agile = HashAgile::try_from(*tpmt_ha)?;
+            digest_values.set(hash_agile.algorithm(), hash_agile.digest().clone());
+        }
+        Ok(digest_values)
+    }
+}
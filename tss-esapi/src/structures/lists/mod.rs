@@ -1,6 +1,10 @@
 // Copyright 2020 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+pub mod ac_capabilities;
+#[cfg(has_tpms_act_data)]
+pub mod act_data;
 pub mod algorithm_property;
+pub mod algorithms;
 pub mod command_code;
 pub mod command_code_attributes;
 pub mod digest;
@@ -9,4 +13,6 @@ pub mod ecc_curves;
 pub mod handles;
 pub mod pcr_selection;
 pub mod tagged_pcr_property;
+#[cfg(has_tpms_tagged_policy)]
+pub mod tagged_policy;
 pub mod tagged_tpm_property;
@@ -29,21 +29,29 @@ pub use names::name::Name;
 /////////////////////////////////////////////////////////
 mod result;
 pub use result::CreateKeyResult;
+pub use result::CreateLoadedKeyResult;
 pub use result::CreatePrimaryKeyResult;
+pub use result::PcrAllocationResult;
 /////////////////////////////////////////////////////////
 /// The sized buffers section
 /////////////////////////////////////////////////////////
 mod buffers;
 pub use self::buffers::{
     attest::AttestBuffer, auth::Auth, data::Data, digest::Digest, ecc_parameter::EccParameter,
-    encrypted_secret::EncryptedSecret, id_object::IdObject, initial_value::InitialValue,
-    max_buffer::MaxBuffer, max_nv_buffer::MaxNvBuffer, nonce::Nonce, private::Private,
-    private_key_rsa::PrivateKeyRsa, private_vendor_specific::PrivateVendorSpecific,
-    public::PublicBuffer, public_key_rsa::PublicKeyRsa, sensitive::SensitiveBuffer,
+    encrypted_secret::EncryptedSecret, event::Event, id_object::IdObject,
+    initial_value::InitialValue, max_buffer::MaxBuffer, max_nv_buffer::MaxNvBuffer, nonce::Nonce,
+    operand::Operand, private::Private, private_key_rsa::PrivateKeyRsa,
+    private_vendor_specific::PrivateVendorSpecific, public::PublicBuffer,
+    public_key_rsa::PublicKeyRsa, sensitive::SensitiveBuffer,
     sensitive_create::SensitiveCreateBuffer, sensitive_data::SensitiveData,
-    symmetric_key::SymmetricKey, timeout::Timeout,
+    symmetric_key::SymmetricKey, template::Template, timeout::Timeout,
 };
 /////////////////////////////////////////////////////////
+/// The template section
+/////////////////////////////////////////////////////////
+mod template;
+pub use self::template::ObjectTemplate;
+/////////////////////////////////////////////////////////
 /// The creation section
 /////////////////////////////////////////////////////////
 mod creation;
@@ -81,6 +89,15 @@ pub mod pcr_select_size {
 /// The lists section
 /////////////////////////////////////////////////////////
 mod lists;
+pub use self::ac_capability_list::AcCapabilityList;
+pub mod ac_capability_list {
+    pub use super::lists::ac_capabilities::*;
+}
+pub use self::algorithm_list::AlgorithmList;
+pub mod algorithm_list {
+    pub use super::lists::algorithms::*;
+}
+
 pub use self::digest_list::DigestList;
 pub mod digest_list {
     pub use super::lists::digest::*;
@@ -132,6 +149,20 @@ pub mod command_code_attributes_list {
     pub use super::lists::command_code_attributes::*;
 }
 
+#[cfg(has_tpms_act_data)]
+pub use self::act_data_list::ActDataList;
+#[cfg(has_tpms_act_data)]
+pub mod act_data_list {
+    pub use super::lists::act_data::*;
+}
+
+#[cfg(has_tpms_tagged_policy)]
+pub use self::tagged_policy_list::TaggedPolicyList;
+#[cfg(has_tpms_tagged_policy)]
+pub mod tagged_policy_list {
+    pub use super::lists::tagged_policy::*;
+}
+
 pub(crate) use pcr::slot_collection::PcrSlotCollection;
 /////////////////////////////////////////////////////////
 /// The parameters section
@@ -172,7 +203,7 @@ pub use tagged::{
 /// ECC structures
 /////////////////////////////////////////////////////////
 mod ecc;
-pub use ecc::point::EccPoint;
+pub use ecc::{algorithm_detail_ecc::AlgorithmDetailEcc, point::EccPoint};
 /////////////////////////////////////////////////////////
 /// Signatures structures
 /////////////////////////////////////////////////////////
@@ -197,11 +228,20 @@ pub use clock::{clock_info::ClockInfo, time_info::TimeInfo};
 /// Property Structures
 /////////////////////////////////////////////////////////
 mod property;
+#[cfg(has_tpms_act_data)]
+pub use property::act_data::ActData;
+#[cfg(has_tpms_tagged_policy)]
+pub use property::tagged_policy::TaggedPolicy;
 pub use property::{
     algorithm_property::AlgorithmProperty, tagged_pcr_select::TaggedPcrSelect,
     tagged_property::TaggedProperty,
 };
 
+/////////////////////////////////////////////////////////
+/// Attached Component Structures
+/////////////////////////////////////////////////////////
+mod attached_component;
+pub use attached_component::output::AcOutput;
 /////////////////////////////////////////////////////////
 /// NV structures
 /////////////////////////////////////////////////////////
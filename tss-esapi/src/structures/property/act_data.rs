@@ -0,0 +1,68 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{
+    attributes::ActAttributes, handles::PermanentTpmHandle, interface_types::resource_handles::Act,
+    tss2_esys::TPMS_ACT_DATA, Error, Result,
+};
+use std::convert::TryFrom;
+
+/// Structure holding the state of an authenticated
+/// countdown timer.
+///
+/// # Details
+/// This corresponds to the TPMS_ACT_DATA.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ActData {
+    act: Act,
+    timeout: u32,
+    attributes: ActAttributes,
+}
+
+impl ActData {
+    /// Creates a new ActData
+    pub const fn new(act: Act, timeout: u32, attributes: ActAttributes) -> Self {
+        ActData {
+            act,
+            timeout,
+            attributes,
+        }
+    }
+
+    /// Returns the timer the data belongs to.
+    pub const fn act(&self) -> Act {
+        self.act
+    }
+
+    /// Returns the number of seconds remaining before
+    /// the timer expires.
+    pub const fn timeout(&self) -> u32 {
+        self.timeout
+    }
+
+    /// Returns the attributes of the timer.
+    pub const fn attributes(&self) -> ActAttributes {
+        self.attributes
+    }
+}
+
+impl From<ActData> for TPMS_ACT_DATA {
+    fn from(act_data: ActData) -> Self {
+        TPMS_ACT_DATA {
+            handle: PermanentTpmHandle::from(act_data.act).into(),
+            timeout: act_data.timeout,
+            attributes: act_data.attributes.into(),
+        }
+    }
+}
+
+impl TryFrom<TPMS_ACT_DATA> for ActData {
+    type Error = Error;
+
+    fn try_from(tpms_act_data: TPMS_ACT_DATA) -> Result<Self> {
+        Ok(ActData {
+            act: Act::try_from(PermanentTpmHandle::new(tpms_act_data.handle)?)?,
+            timeout: tpms_act_data.timeout,
+            attributes: tpms_act_data.attributes.into(),
+        })
+    }
+}
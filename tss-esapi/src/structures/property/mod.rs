@@ -1,5 +1,9 @@
 // Copyright 2022 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+#[cfg(has_tpms_act_data)]
+pub mod act_data;
 pub mod algorithm_property;
 pub mod tagged_pcr_select;
+#[cfg(has_tpms_tagged_policy)]
+pub mod tagged_policy;
 pub mod tagged_property;
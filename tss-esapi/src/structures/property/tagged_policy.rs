@@ -0,0 +1,67 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{
+    handles::PermanentTpmHandle,
+    structures::HashAgile,
+    tss2_esys::{TPMS_TAGGED_POLICY, TPMT_HA},
+    Error, Result,
+};
+use std::convert::TryFrom;
+
+/// Structure holding the authorization policy
+/// of a permanent handle.
+///
+/// # Details
+/// This corresponds to the TPMS_TAGGED_POLICY.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaggedPolicy {
+    handle: PermanentTpmHandle,
+    policy_hash: HashAgile,
+}
+
+impl TaggedPolicy {
+    /// Creates a new TaggedPolicy
+    ///
+    /// # Errors
+    /// * if the `policy_hash` cannot be converted into a TPMT_HA, the
+    ///   conversion error is returned.
+    pub fn new(handle: PermanentTpmHandle, policy_hash: HashAgile) -> Result<Self> {
+        let _ = TPMT_HA::try_from(policy_hash.clone())?;
+        Ok(TaggedPolicy {
+            handle,
+            policy_hash,
+        })
+    }
+
+    /// Returns the handle the policy is associated with.
+    pub const fn handle(&self) -> PermanentTpmHandle {
+        self.handle
+    }
+
+    /// Returns the policy hash.
+    pub const fn policy_hash(&self) -> &HashAgile {
+        &self.policy_hash
+    }
+}
+
+impl From<TaggedPolicy> for TPMS_TAGGED_POLICY {
+    fn from(tagged_policy: TaggedPolicy) -> Self {
+        TPMS_TAGGED_POLICY {
+            handle: tagged_policy.handle.into(),
+            // The policy hash is validated when the TaggedPolicy is created.
+            policyHash: TPMT_HA::try_from(tagged_policy.policy_hash)
+                .expect("Invalid policy hash in TaggedPolicy"),
+        }
+    }
+}
+
+impl TryFrom<TPMS_TAGGED_POLICY> for TaggedPolicy {
+    type Error = Error;
+
+    fn try_from(tpms_tagged_policy: TPMS_TAGGED_POLICY) -> Result<Self> {
+        Ok(TaggedPolicy {
+            handle: PermanentTpmHandle::new(tpms_tagged_policy.handle)?,
+            policy_hash: HashAgile::try_from(tpms_tagged_policy.policyHash)?,
+        })
+    }
+}
@@ -23,3 +23,26 @@ pub struct CreatePrimaryKeyResult {
     pub creation_hash: Digest,
     pub creation_ticket: CreationTicket,
 }
+
+#[allow(missing_debug_implementations)]
+pub struct CreateLoadedKeyResult {
+    pub key_handle: KeyHandle,
+    pub out_private: Private,
+    pub out_public: Public,
+}
+
+/// The result of a PCR allocation.
+///
+/// # Details
+/// This corresponds to the output parameters of TPM2_PCR_Allocate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PcrAllocationResult {
+    /// Whether the allocation will be applied on the next TPM Reset.
+    pub allocation_success: bool,
+    /// The maximum number of PCRs that may be in a bank.
+    pub max_pcr: u32,
+    /// The number of octets required to satisfy the request.
+    pub size_needed: u32,
+    /// The number of octets available for PCR banks.
+    pub size_available: u32,
+}
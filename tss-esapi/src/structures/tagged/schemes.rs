@@ -786,3 +786,29 @@ impl TryFrom<TPMT_SIG_SCHEME> for SignatureScheme {
         }
     }
 }
+
+impl TryFrom<EccScheme> for SignatureScheme {
+    type Error = Error;
+
+    /// Converts an ECC signing scheme into a signature scheme.
+    ///
+    /// # Details
+    /// For the EcDaa variant the count of the [EcDaaScheme] needs to
+    /// be the counter value returned by [crate::Context::commit].
+    ///
+    /// # Errors
+    /// Returns an InvalidParam error if the ECC scheme is not a signing scheme.
+    fn try_from(ecc_scheme: EccScheme) -> Result<Self> {
+        match ecc_scheme {
+            EccScheme::EcDsa(scheme) => Ok(SignatureScheme::EcDsa { scheme }),
+            EccScheme::EcDaa(scheme) => Ok(SignatureScheme::EcDaa { scheme }),
+            EccScheme::Sm2(scheme) => Ok(SignatureScheme::Sm2 { scheme }),
+            EccScheme::EcSchnorr(scheme) => Ok(SignatureScheme::EcSchnorr { scheme }),
+            EccScheme::Null => Ok(SignatureScheme::Null),
+            _ => {
+                error!("Cannot convert a non signing ECC scheme into a signature scheme");
+                Err(Error::local_error(WrapperErrorKind::InvalidParam))
+            }
+        }
+    }
+}
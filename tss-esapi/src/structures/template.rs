@@ -0,0 +1,51 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{
+    structures::{Public, Template},
+    tss2_esys::TPM2B_TEMPLATE,
+    Error, Result,
+};
+use std::convert::TryFrom;
+
+/// The public template of an object that is going to be created.
+///
+/// # Details
+/// A template is either a [Public] structure or an already marshalled
+/// [Template], e.g. one created with [Template::derived] for objects
+/// that are derived from a derivation parent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ObjectTemplate {
+    Public(Public),
+    Template(Template),
+}
+
+impl From<Public> for ObjectTemplate {
+    fn from(public: Public) -> Self {
+        ObjectTemplate::Public(public)
+    }
+}
+
+impl From<Template> for ObjectTemplate {
+    fn from(template: Template) -> Self {
+        ObjectTemplate::Template(template)
+    }
+}
+
+impl TryFrom<ObjectTemplate> for Template {
+    type Error = Error;
+
+    fn try_from(object_template: ObjectTemplate) -> Result<Self> {
+        match object_template {
+            ObjectTemplate::Public(public) => Template::try_from(public),
+            ObjectTemplate::Template(template) => Ok(template),
+        }
+    }
+}
+
+impl TryFrom<ObjectTemplate> for TPM2B_TEMPLATE {
+    type Error = Error;
+
+    fn try_from(object_template: ObjectTemplate) -> Result<Self> {
+        Template::try_from(object_template).map(TPM2B_TEMPLATE::from)
+    }
+}
@@ -10,6 +10,7 @@
 //! `Union`.
 use crate::attributes::ObjectAttributesBuilder;
 use crate::constants::PropertyTag;
+use crate::handles::ObjectHandle;
 use crate::interface_types::{
     algorithm::{HashingAlgorithm, PublicAlgorithm},
     ecc::EccCurve,
@@ -94,6 +95,49 @@ impl TryFrom<TpmsContext> for TPMS_CONTEXT {
     }
 }
 
+/// Rust native wrapper for serialized ESYS handle metadata.
+///
+/// This structure is intended to help with persisting the metadata of ESYS handles
+/// to persistent objects and NV indices alongside key files. It holds the bytes
+/// returned by [Context::tr_serialize] which can be used to restore the handle with
+/// [Context::tr_deserialize]. `SerializedHandle` implements the `Serialize` and
+/// `Deserialize` defined by `serde`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct SerializedHandle {
+    metadata: Vec<u8>,
+}
+
+impl SerializedHandle {
+    /// Serializes the metadata of the provided handle.
+    pub fn from_handle(context: &mut Context, object_handle: ObjectHandle) -> Result<Self> {
+        context
+            .tr_serialize(object_handle)
+            .map(|metadata| SerializedHandle { metadata })
+    }
+
+    /// Restores the handle from the metadata.
+    pub fn to_handle(&self, context: &mut Context) -> Result<ObjectHandle> {
+        context.tr_deserialize(&self.metadata)
+    }
+
+    /// Get a reference to the serialized metadata.
+    pub fn metadata(&self) -> &[u8] {
+        &self.metadata
+    }
+}
+
+impl From<Vec<u8>> for SerializedHandle {
+    fn from(metadata: Vec<u8>) -> Self {
+        SerializedHandle { metadata }
+    }
+}
+
+impl From<SerializedHandle> for Vec<u8> {
+    fn from(serialized_handle: SerializedHandle) -> Self {
+        serialized_handle.metadata
+    }
+}
+
 /// Create the [Public] structure for a restricted decryption key.
 ///
 /// * `symmetric` - Cipher to be used for decrypting children of the key
@@ -0,0 +1,68 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::convert::TryFrom;
+use tss_esapi::{
+    abstraction::clock::ClockEvents, interface_types::YesNo, structures::ClockInfo,
+    tss2_esys::TPMS_CLOCK_INFO,
+};
+
+use crate::common::create_ctx_without_session;
+
+fn clock_info(clock: u64, reset_count: u32, restart_count: u32) -> ClockInfo {
+    ClockInfo::try_from(TPMS_CLOCK_INFO {
+        clock,
+        resetCount: reset_count,
+        restartCount: restart_count,
+        safe: YesNo::Yes.into(),
+    })
+    .expect("Failed to create ClockInfo")
+}
+
+#[test]
+fn test_no_events() {
+    let events = ClockEvents::between(&clock_info(100, 1, 2), &clock_info(200, 1, 2));
+    assert!(!events.tpm_reset());
+    assert!(!events.tpm_restart());
+    assert!(!events.clock_rollback());
+    assert!(!events.any());
+}
+
+#[test]
+fn test_reset() {
+    let events = ClockEvents::between(&clock_info(100, 1, 2), &clock_info(200, 2, 0));
+    assert!(events.tpm_reset());
+    assert!(!events.tpm_restart());
+    assert!(!events.clock_rollback());
+    assert!(events.any());
+}
+
+#[test]
+fn test_restart() {
+    let events = ClockEvents::between(&clock_info(100, 1, 2), &clock_info(200, 1, 3));
+    assert!(!events.tpm_reset());
+    assert!(events.tpm_restart());
+    assert!(!events.clock_rollback());
+    assert!(events.any());
+}
+
+#[test]
+fn test_rollback() {
+    let events = ClockEvents::between(&clock_info(200, 1, 2), &clock_info(100, 2, 0));
+    assert!(events.tpm_reset());
+    assert!(!events.tpm_restart());
+    assert!(events.clock_rollback());
+}
+
+#[test]
+fn test_read_clock_snapshots() {
+    let mut context = create_ctx_without_session();
+    let earlier = *context
+        .read_clock()
+        .expect("Failed to read clock")
+        .clock_info();
+    let later = *context
+        .read_clock()
+        .expect("Failed to read clock")
+        .clock_info();
+    assert!(!ClockEvents::between(&earlier, &later).any());
+}
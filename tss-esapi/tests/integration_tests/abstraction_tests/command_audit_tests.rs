@@ -0,0 +1,130 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::convert::TryFrom;
+use tss_esapi::{
+    abstraction::command_audit::CommandAuditLog,
+    constants::CommandCode,
+    handles::KeyHandle,
+    interface_types::{
+        algorithm::HashingAlgorithm,
+        resource_handles::{Hierarchy, Provision},
+        session_handles::AuthSession,
+    },
+    structures::{Attest, CommandCodeList, Data, Signature, SignatureScheme},
+    Context, Error, WrapperErrorKind,
+};
+
+use crate::common::{create_ctx_without_session, signing_key_pub};
+
+fn get_command_audit_digest(
+    context: &mut Context,
+    sign_key_handle: KeyHandle,
+) -> (Attest, Signature) {
+    context
+        .execute_with_sessions(
+            (
+                Some(AuthSession::Password),
+                Some(AuthSession::Password),
+                None,
+            ),
+            |ctx| {
+                ctx.get_command_audit_digest(
+                    sign_key_handle,
+                    Data::default(),
+                    SignatureScheme::Null,
+                )
+            },
+        )
+        .expect("Failed to get command audit digest")
+}
+
+fn get_random(audit_log: &mut CommandAuditLog, context: &mut Context, bytes_requested: u16) {
+    let random = audit_log
+        .execute(
+            context,
+            CommandCode::GetRandom,
+            &[],
+            &bytes_requested.to_be_bytes(),
+            |ctx| ctx.get_random(bytes_requested.into()),
+        )
+        .expect("Failed to get random bytes");
+    assert_eq!(random.len(), usize::from(bytes_requested));
+}
+
+#[test]
+fn test_verify_command_audit_digest() {
+    let mut context = create_ctx_without_session();
+    context
+        .execute_with_session(Some(AuthSession::Password), |ctx| {
+            ctx.set_command_code_audit_status(
+                Provision::Owner,
+                HashingAlgorithm::Sha256,
+                CommandCodeList::new(),
+                CommandCodeList::new(),
+            )?;
+            ctx.set_command_code_audit_status(
+                Provision::Owner,
+                HashingAlgorithm::Null,
+                CommandCodeList::try_from(vec![CommandCode::GetRandom])?,
+                CommandCodeList::new(),
+            )
+        })
+        .expect("Failed to set command code audit status");
+
+    let sign_key_handle = context
+        .execute_with_nullauth_session(|ctx| {
+            ctx.create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+        })
+        .expect("Failed to create signing key")
+        .key_handle;
+
+    // Reset the audit digest of the TPM.
+    let _ = get_command_audit_digest(&mut context, sign_key_handle);
+
+    let mut audit_log = CommandAuditLog::new(HashingAlgorithm::Sha256);
+    get_random(&mut audit_log, &mut context, 8);
+    get_random(&mut audit_log, &mut context, 16);
+    assert_eq!(audit_log.commands().len(), 2);
+
+    let (attest, signature) = get_command_audit_digest(&mut context, sign_key_handle);
+    assert!(audit_log
+        .verify(&mut context, sign_key_handle, &attest, signature.clone())
+        .expect("Failed to verify command audit digest"));
+
+    // A log that is missing a command does not match.
+    let mut incomplete_audit_log = CommandAuditLog::new(HashingAlgorithm::Sha256);
+    get_random(&mut incomplete_audit_log, &mut context, 8);
+    assert!(!incomplete_audit_log
+        .verify(&mut context, sign_key_handle, &attest, signature)
+        .expect("Failed to verify command audit digest"));
+
+    context
+        .execute_with_session(Some(AuthSession::Password), |ctx| {
+            ctx.set_command_code_audit_status(
+                Provision::Owner,
+                HashingAlgorithm::Null,
+                CommandCodeList::new(),
+                CommandCodeList::try_from(vec![CommandCode::GetRandom])?,
+            )
+        })
+        .expect("Failed to clear command code audit status");
+}
+
+#[test]
+fn test_execute_wrong_command_code() {
+    let mut context = create_ctx_without_session();
+    let mut audit_log = CommandAuditLog::new(HashingAlgorithm::Sha256);
+    assert_eq!(
+        audit_log
+            .execute(
+                &mut context,
+                CommandCode::StirRandom,
+                &[],
+                &8u16.to_be_bytes(),
+                |ctx| ctx.get_random(8)
+            )
+            .unwrap_err(),
+        Error::WrapperError(WrapperErrorKind::InvalidParam)
+    );
+    assert!(audit_log.commands().is_empty());
+}
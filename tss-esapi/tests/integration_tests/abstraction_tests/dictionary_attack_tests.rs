@@ -0,0 +1,22 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use tss_esapi::{
+    abstraction::dictionary_attack::DaStatus,
+    interface_types::{resource_handles::Lockout, session_handles::AuthSession},
+};
+
+use crate::common::create_ctx_without_session;
+
+#[test]
+fn test_read_da_status() {
+    let mut context = create_ctx_without_session();
+    context
+        .execute_with_session(Some(AuthSession::Password), |ctx| {
+            ctx.dictionary_attack_lock_reset(Lockout::Lockout)
+        })
+        .expect("Failed to reset dictionary attack lockout");
+
+    let da_status = DaStatus::read(&mut context).expect("Failed to read DA status");
+    assert_eq!(0, da_status.lockout_counter());
+    assert!(!da_status.in_lockout());
+}
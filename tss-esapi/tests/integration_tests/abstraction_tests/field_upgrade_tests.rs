@@ -0,0 +1,57 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::convert::TryFrom;
+use tss_esapi::{
+    abstraction::field_upgrade,
+    constants::TpmFormatZeroError,
+    error::{ReturnCode, TpmFormatZeroResponseCode, TpmResponseCode},
+    handles::KeyHandle,
+    interface_types::{algorithm::HashingAlgorithm, resource_handles::Platform},
+    structures::{Digest, PublicKeyRsa, RsaSignature, Signature},
+    Error,
+};
+
+use crate::common::{create_ctx_with_session, create_ctx_without_session};
+
+fn assert_command_code_error(error: Error) {
+    if let Error::TssError(ReturnCode::Tpm(TpmResponseCode::FormatZero(
+        TpmFormatZeroResponseCode::Error(error),
+    ))) = error
+    {
+        assert_eq!(error.error_number(), TpmFormatZeroError::CommandCode);
+    } else {
+        panic!("Unexpected error: {}", error);
+    }
+}
+
+#[test]
+fn read_firmware_unimplemented() {
+    let mut context = create_ctx_without_session();
+    assert_command_code_error(
+        field_upgrade::read_firmware(&mut context).expect_err("Read firmware unexpectedly"),
+    );
+}
+
+#[test]
+fn upgrade_firmware_unimplemented() {
+    let mut context = create_ctx_with_session();
+    let signature = Signature::RsaSsa(
+        RsaSignature::create(
+            HashingAlgorithm::Sha256,
+            PublicKeyRsa::try_from(vec![0xff; 256]).expect("Failed to create signature"),
+        )
+        .expect("Failed to create RSA signature"),
+    );
+    let firmware = vec![0xffu8; 4096];
+    assert_command_code_error(
+        field_upgrade::upgrade_firmware(
+            &mut context,
+            Platform::Platform,
+            KeyHandle::Null,
+            Digest::try_from(vec![0xff; 32]).expect("Failed to create digest"),
+            signature,
+            firmware.as_slice(),
+        )
+        .expect_err("Upgraded firmware unexpectedly"),
+    );
+}
@@ -0,0 +1,168 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{convert::TryFrom, io::Write};
+
+use sha2::{Digest as _, Sha256};
+use tss_esapi::{
+    abstraction::hash::HashSequence,
+    attributes::ObjectAttributesBuilder,
+    constants::StructureTag,
+    interface_types::{
+        algorithm::{HashingAlgorithm, PublicAlgorithm, RsaSchemeAlgorithm},
+        key_bits::RsaKeyBits,
+        resource_handles::Hierarchy,
+    },
+    structures::{
+        KeyedHashScheme, MaxBuffer, PublicBuilder, PublicKeyRsa, PublicKeyedHashParameters,
+        PublicRsaParametersBuilder, RsaExponent, RsaScheme, SignatureScheme, Ticket,
+    },
+};
+
+use crate::common::{create_ctx_with_session, create_ctx_without_session};
+
+#[test]
+fn test_hash_sequence_large_data() {
+    let mut context = create_ctx_without_session();
+    let data: Vec<u8> = (0..(4 * MaxBuffer::MAX_SIZE + 17))
+        .map(|i| (i % 251) as u8)
+        .collect();
+
+    let mut hash_sequence =
+        HashSequence::start(&mut context, HashingAlgorithm::Sha256, Hierarchy::Owner)
+            .expect("Failed to start hash sequence");
+    // Write in uneven pieces to exercise the buffering.
+    for chunk in data.chunks(1000) {
+        hash_sequence
+            .write_all(chunk)
+            .expect("Failed to write to hash sequence");
+    }
+    let (digest, ticket) = hash_sequence
+        .finish()
+        .expect("Failed to finish hash sequence");
+
+    assert_eq!(digest.as_bytes(), Sha256::digest(&data).as_slice());
+    assert_eq!(ticket.hierarchy(), Hierarchy::Owner);
+    assert_eq!(ticket.tag(), StructureTag::Hashcheck);
+}
+
+#[test]
+fn test_hash_sequence_dropped_before_finish() {
+    let mut context = create_ctx_without_session();
+    {
+        let mut hash_sequence =
+            HashSequence::start(&mut context, HashingAlgorithm::Sha256, Hierarchy::Owner)
+                .expect("Failed to start hash sequence");
+        hash_sequence
+            .write_all(&[0xff; 64])
+            .expect("Failed to write to hash sequence");
+    }
+    // The context should still be usable and the sequence object
+    // should have been flushed.
+    let _ = HashSequence::start(&mut context, HashingAlgorithm::Sha256, Hierarchy::Owner)
+        .expect("Failed to start hash sequence")
+        .finish()
+        .expect("Failed to finish hash sequence");
+}
+
+#[test]
+fn test_hmac_sequence() {
+    let mut context = create_ctx_with_session();
+    let object_attributes = ObjectAttributesBuilder::new()
+        .with_sign_encrypt(true)
+        .with_sensitive_data_origin(true)
+        .with_user_with_auth(true)
+        .build()
+        .expect("Failed to build object attributes");
+    let key_pub = PublicBuilder::new()
+        .with_public_algorithm(PublicAlgorithm::KeyedHash)
+        .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+        .with_object_attributes(object_attributes)
+        .with_keyed_hash_parameters(PublicKeyedHashParameters::new(
+            KeyedHashScheme::HMAC_SHA_256,
+        ))
+        .with_keyed_hash_unique_identifier(Default::default())
+        .build()
+        .expect("Failed to build public structure for key.");
+    let key_handle = context
+        .create_primary(Hierarchy::Owner, key_pub, None, None, None, None)
+        .expect("Failed to create HMAC key")
+        .key_handle;
+
+    let data = vec![0x5a; 512];
+    let expected = context
+        .hmac(
+            key_handle.into(),
+            MaxBuffer::try_from(data.clone()).expect("Failed to create MaxBuffer"),
+            HashingAlgorithm::Sha256,
+        )
+        .expect("Call to hmac failed");
+
+    let mut hmac_sequence =
+        HashSequence::start_hmac(&mut context, key_handle, HashingAlgorithm::Sha256)
+            .expect("Failed to start HMAC sequence");
+    for chunk in data.chunks(100) {
+        hmac_sequence
+            .write_all(chunk)
+            .expect("Failed to write to HMAC sequence");
+    }
+    let (actual, _) = hmac_sequence
+        .finish()
+        .expect("Failed to finish HMAC sequence");
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_sign_with_restricted_key_using_hash_sequence_ticket() {
+    let mut context = create_ctx_with_session();
+    let object_attributes = ObjectAttributesBuilder::new()
+        .with_fixed_tpm(true)
+        .with_fixed_parent(true)
+        .with_sensitive_data_origin(true)
+        .with_user_with_auth(true)
+        .with_sign_encrypt(true)
+        .with_decrypt(false)
+        .with_restricted(true)
+        .build()
+        .expect("Failed to build object attributes");
+    let key_pub = PublicBuilder::new()
+        .with_public_algorithm(PublicAlgorithm::Rsa)
+        .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+        .with_object_attributes(object_attributes)
+        .with_rsa_parameters(
+            PublicRsaParametersBuilder::new()
+                .with_scheme(
+                    RsaScheme::create(RsaSchemeAlgorithm::RsaSsa, Some(HashingAlgorithm::Sha256))
+                        .expect("Failed to create RSA scheme"),
+                )
+                .with_key_bits(RsaKeyBits::Rsa2048)
+                .with_exponent(RsaExponent::default())
+                .with_is_signing_key(true)
+                .with_is_decryption_key(false)
+                .with_restricted(true)
+                .build()
+                .expect("Failed to build RSA parameters"),
+        )
+        .with_rsa_unique_identifier(PublicKeyRsa::default())
+        .build()
+        .expect("Failed to build public structure for key.");
+    let key_handle = context
+        .create_primary(Hierarchy::Owner, key_pub, None, None, None, None)
+        .expect("Failed to create restricted signing key")
+        .key_handle;
+
+    let mut hash_sequence =
+        HashSequence::start(&mut context, HashingAlgorithm::Sha256, Hierarchy::Owner)
+            .expect("Failed to start hash sequence");
+    hash_sequence
+        .write_all(&[0x42; 3000])
+        .expect("Failed to write to hash sequence");
+    let (digest, ticket) = hash_sequence
+        .finish()
+        .expect("Failed to finish hash sequence");
+
+    let _ = context
+        .sign(key_handle, digest, SignatureScheme::Null, ticket)
+        .expect("Failed to sign digest with restricted key");
+}
@@ -1,9 +1,17 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 mod ak_tests;
+mod clock_tests;
+mod command_audit_tests;
+mod dictionary_attack_tests;
 mod ek_tests;
+mod field_upgrade_tests;
+mod hash_tests;
 mod nv_tests;
 mod pcr_data_tests;
 mod pcr_tests;
+mod policy_digest_tests;
+mod policy_ticket_tests;
 mod public_tests;
 mod transient_key_context_tests;
+mod x509_tests;
@@ -8,16 +8,18 @@ use std::{
 use tss_esapi::{
     abstraction::nv,
     attributes::NvIndexAttributesBuilder,
-    handles::{NvIndexHandle, NvIndexTpmHandle},
+    constants::{CommandCode, NvIndexType, SessionType},
+    handles::{NvIndexHandle, NvIndexTpmHandle, SessionHandle},
     interface_types::{
         algorithm::HashingAlgorithm,
-        resource_handles::{NvAuth, Provision},
+        resource_handles::{NvAuth, Platform, Provision},
+        session_handles::{AuthSession, PolicySession},
     },
-    structures::{MaxNvBuffer, NvPublicBuilder},
-    Context,
+    structures::{MaxNvBuffer, NvPublic, NvPublicBuilder, SymmetricDefinition},
+    Context, Error, WrapperErrorKind,
 };
 
-use crate::common::create_ctx_with_session;
+use crate::common::{create_ctx_with_session, create_ctx_without_session};
 
 fn write_nv_index(context: &mut Context, nv_index: NvIndexTpmHandle) -> NvIndexHandle {
     // Create owner nv public.
@@ -181,3 +183,255 @@ fn write() {
         .nv_undefine_space(Provision::Owner, owner_nv_index_handle.into())
         .expect("Call to nv_undefine_space failed");
 }
+
+fn owner_nv_public(
+    nv_index: NvIndexTpmHandle,
+    nv_index_type: NvIndexType,
+    size: usize,
+) -> NvPublic {
+    let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+        .with_owner_write(true)
+        .with_owner_read(true)
+        .with_nv_index_type(nv_index_type)
+        .build()
+        .expect("Failed to create owner nv index attributes");
+    NvPublicBuilder::new()
+        .with_nv_index(nv_index)
+        .with_index_name_algorithm(HashingAlgorithm::Sha256)
+        .with_index_attributes(owner_nv_index_attributes)
+        .with_data_area_size(size)
+        .build()
+        .expect("Failed to build NvPublic for owner")
+}
+
+fn undefine_nv_index(context: &mut Context, nv_index: NvIndexTpmHandle) {
+    let owner_nv_index_handle = context
+        .execute_without_session(|ctx| ctx.tr_from_tpm_public(nv_index.into()))
+        .expect("Call to tr_from_tpm_public failed");
+    context
+        .nv_undefine_space(Provision::Owner, owner_nv_index_handle.into())
+        .expect("Call to nv_undefine_space failed");
+}
+
+#[test]
+fn counter() {
+    let mut context = create_ctx_without_session();
+    context.set_sessions((Some(AuthSession::Password), None, None));
+
+    let nv_index = NvIndexTpmHandle::new(0x01500030).unwrap();
+
+    let mut counter = nv::NvOpenOptions::NewIndex {
+        nv_public: owner_nv_public(nv_index, NvIndexType::Counter, 8),
+        auth_handle: NvAuth::Owner,
+    }
+    .open_counter(&mut context)
+    .unwrap();
+
+    let result = counter
+        .increment()
+        .and_then(|_| counter.read())
+        .and_then(|first| {
+            counter.increment()?;
+            Ok((first, counter.read()?))
+        });
+    drop(counter);
+
+    undefine_nv_index(&mut context, nv_index);
+
+    let (first, second) = result.expect("Failed to increment and read counter");
+    assert_eq!(first + 1, second);
+}
+
+#[test]
+fn bit_field() {
+    let mut context = create_ctx_without_session();
+    context.set_sessions((Some(AuthSession::Password), None, None));
+
+    let nv_index = NvIndexTpmHandle::new(0x01500031).unwrap();
+
+    let mut bit_field = nv::NvOpenOptions::NewIndex {
+        nv_public: owner_nv_public(nv_index, NvIndexType::Bits, 8),
+        auth_handle: NvAuth::Owner,
+    }
+    .open_bit_field(&mut context)
+    .unwrap();
+
+    let result = bit_field
+        .set_bits(0b0011)
+        .and_then(|_| bit_field.set_bits(0b1000))
+        .and_then(|_| bit_field.read());
+    drop(bit_field);
+
+    undefine_nv_index(&mut context, nv_index);
+
+    assert_eq!(result.expect("Failed to set and read bits"), 0b1011);
+}
+
+#[test]
+fn extend() {
+    let mut context = create_ctx_with_session();
+
+    let nv_index = NvIndexTpmHandle::new(0x01500032).unwrap();
+
+    let mut extend_index = nv::NvOpenOptions::NewIndex {
+        nv_public: owner_nv_public(nv_index, NvIndexType::Extend, 32),
+        auth_handle: NvAuth::Owner,
+    }
+    .open_extend(&mut context)
+    .unwrap();
+
+    let size = extend_index.size();
+    let result = extend_index
+        .extend(&[1, 2, 3, 4, 5, 6, 7])
+        .and_then(|_| extend_index.read());
+    // Data that does not fit in a single NV buffer is rejected.
+    let too_large_result = extend_index.extend(&[0xff; 4096]);
+    drop(extend_index);
+
+    undefine_nv_index(&mut context, nv_index);
+
+    assert_eq!(size, 32);
+    assert_eq!(result.expect("Failed to extend and read index").len(), 32);
+    assert_eq!(
+        too_large_result,
+        Err(Error::WrapperError(WrapperErrorKind::InvalidParam))
+    );
+}
+
+#[test]
+fn open_with_wrong_type() {
+    let mut context = create_ctx_with_session();
+
+    let nv_index = NvIndexTpmHandle::new(0x01500033).unwrap();
+
+    // An ordinary index can not be opened as a counter.
+    let new_index_result = nv::NvOpenOptions::NewIndex {
+        nv_public: owner_nv_public(nv_index, NvIndexType::Ordinary, 8),
+        auth_handle: NvAuth::Owner,
+    }
+    .open_counter(&mut context)
+    .map(|_| ());
+    assert_eq!(
+        new_index_result,
+        Err(Error::WrapperError(WrapperErrorKind::InvalidParam))
+    );
+
+    let _ = context
+        .nv_define_space(
+            Provision::Owner,
+            None,
+            owner_nv_public(nv_index, NvIndexType::Ordinary, 8),
+        )
+        .expect("Call to nv_define_space failed");
+
+    let existing_index_result = nv::NvOpenOptions::ExistingIndex {
+        nv_index_handle: nv_index,
+        auth_handle: NvAuth::Owner,
+    }
+    .open_bit_field(&mut context)
+    .map(|_| ());
+
+    undefine_nv_index(&mut context, nv_index);
+
+    assert_eq!(
+        existing_index_result,
+        Err(Error::WrapperError(WrapperErrorKind::InvalidParam))
+    );
+}
+
+#[test]
+fn open_counter_as_ordinary() {
+    let mut context = create_ctx_with_session();
+
+    let nv_index = NvIndexTpmHandle::new(0x01500034).unwrap();
+
+    let _ = context
+        .nv_define_space(
+            Provision::Owner,
+            None,
+            owner_nv_public(nv_index, NvIndexType::Counter, 8),
+        )
+        .expect("Call to nv_define_space failed");
+
+    // The type of the index is not checked when it is opened for reading and writing.
+    let result = nv::NvOpenOptions::ExistingIndex {
+        nv_index_handle: nv_index,
+        auth_handle: NvAuth::Owner,
+    }
+    .open(&mut context)
+    .map(|rw| rw.size());
+
+    undefine_nv_index(&mut context, nv_index);
+
+    assert_eq!(result, Ok(8));
+}
+
+#[test]
+fn undefine_space_special() {
+    let mut context = create_ctx_with_session();
+    let nv_index = NvIndexTpmHandle::new(0x01500031).unwrap();
+
+    // Compute the policy that allows the index to be deleted.
+    let trial_session = context
+        .execute_without_session(|ctx| {
+            ctx.start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Trial,
+                SymmetricDefinition::AES_128_CFB,
+                HashingAlgorithm::Sha256,
+            )
+        })
+        .expect("Failed to start trial session")
+        .expect("Received invalid handle");
+    let trial_policy_session =
+        PolicySession::try_from(trial_session).expect("Failed to convert to policy session");
+    let policy_digest = context
+        .execute_without_session(|ctx| {
+            ctx.policy_command_code(trial_policy_session, CommandCode::NvUndefineSpaceSpecial)?;
+            ctx.policy_get_digest(trial_policy_session)
+        })
+        .expect("Failed to compute policy digest");
+    context
+        .flush_context(SessionHandle::from(trial_session).into())
+        .expect("Failed to flush trial session");
+
+    let nv_index_attributes = NvIndexAttributesBuilder::new()
+        .with_auth_write(true)
+        .with_auth_read(true)
+        .with_platform_create(true)
+        .with_policy_delete(true)
+        .build()
+        .expect("Failed to create nv index attributes");
+    let nv_public = NvPublicBuilder::new()
+        .with_nv_index(nv_index)
+        .with_index_name_algorithm(HashingAlgorithm::Sha256)
+        .with_index_attributes(nv_index_attributes)
+        .with_index_auth_policy(policy_digest)
+        .with_data_area_size(32)
+        .build()
+        .expect("Failed to build NvPublic");
+
+    let nv_index_handle = context
+        .nv_define_space(Provision::Platform, None, nv_public)
+        .expect("Call to nv_define_space failed");
+
+    // Indices with the policy delete attribute can not be undefined normally.
+    let _ = context
+        .nv_undefine_space(Provision::Platform, nv_index_handle)
+        .unwrap_err();
+
+    let policy_session = nv::undefine_space_special_session(&mut context, nv_index_handle)
+        .expect("Failed to create policy session");
+    context
+        .execute_with_sessions(
+            (
+                Some(policy_session.into()),
+                Some(AuthSession::Password),
+                None,
+            ),
+            |ctx| ctx.nv_undefine_space_special(nv_index_handle, Platform::Platform),
+        )
+        .expect("Call to nv_undefine_space_special failed");
+}
@@ -2,9 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 use crate::common::create_ctx_without_session;
 
+use sha2::{Digest as _, Sha256};
+use std::io::Write;
 use tss_esapi::{
-    interface_types::algorithm::HashingAlgorithm,
-    structures::{PcrSelectionListBuilder, PcrSlot},
+    abstraction::pcr::PcrEventSequence,
+    handles::PcrHandle,
+    interface_types::{algorithm::HashingAlgorithm, session_handles::AuthSession},
+    structures::{MaxBuffer, PcrSelectionListBuilder, PcrSlot},
 };
 
 #[test]
@@ -139,3 +143,91 @@ fn test_pcr_read_all() {
                 })
         })
 }
+
+#[test]
+fn test_pcr_event_sequence() {
+    let mut context = create_ctx_without_session();
+    let data: Vec<u8> = (0..(3 * MaxBuffer::MAX_SIZE + 5))
+        .map(|i| (i % 253) as u8)
+        .collect();
+
+    // PCR23 is resettable from the locality that is used in
+    // the tests and not used by any other test.
+    context
+        .execute_with_session(Some(AuthSession::Password), |ctx| {
+            ctx.pcr_reset(PcrHandle::Pcr23)
+        })
+        .expect("Failed to reset PCR23");
+
+    let mut event_sequence =
+        PcrEventSequence::start(&mut context).expect("Failed to start event sequence");
+    for chunk in data.chunks(700) {
+        event_sequence
+            .write_all(chunk)
+            .expect("Failed to write to event sequence");
+    }
+    let digests = event_sequence
+        .finish(PcrHandle::Pcr23)
+        .expect("Failed to finish event sequence");
+
+    let event_digest = digests
+        .get(HashingAlgorithm::Sha256)
+        .expect("Missing digest for the sha256 bank");
+    assert_eq!(event_digest.as_bytes(), Sha256::digest(&data).as_slice());
+
+    let pcr_data = tss_esapi::abstraction::pcr::read_all(
+        &mut context,
+        PcrSelectionListBuilder::new()
+            .with_selection(HashingAlgorithm::Sha256, &[PcrSlot::Slot23])
+            .build()
+            .expect("Failed to build PcrSelectionList"),
+    )
+    .expect("pcr::read_all failed");
+    let mut expected_pcr_value = Sha256::new();
+    expected_pcr_value.update([0u8; 32]);
+    expected_pcr_value.update(event_digest.as_bytes());
+    assert_eq!(
+        pcr_data
+            .pcr_bank(HashingAlgorithm::Sha256)
+            .expect("Missing sha256 bank")
+            .get_digest(PcrSlot::Slot23)
+            .expect("Missing PCR23 value")
+            .as_bytes(),
+        expected_pcr_value.finalize().as_slice()
+    );
+}
+
+#[test]
+fn test_pcr_allocation_for_banks() {
+    let mut context = create_ctx_without_session();
+
+    let pcr_allocation = tss_esapi::abstraction::pcr::allocation_for_banks(
+        &mut context,
+        &[HashingAlgorithm::Sha256],
+    )
+    .expect("Call to allocation_for_banks failed");
+
+    let sha256_selection = pcr_allocation
+        .get_selections()
+        .iter()
+        .find(|pcr_selection| pcr_selection.hashing_algorithm() == HashingAlgorithm::Sha256)
+        .expect("The SHA-256 bank is not in the allocation");
+    assert!(sha256_selection.is_selected(PcrSlot::Slot0));
+    assert!(sha256_selection.is_selected(PcrSlot::Slot23));
+    assert!(pcr_allocation
+        .get_selections()
+        .iter()
+        .filter(|pcr_selection| pcr_selection.hashing_algorithm() != HashingAlgorithm::Sha256)
+        .all(|pcr_selection| pcr_selection.is_empty()));
+}
+
+#[test]
+fn test_pcr_allocation_for_unsupported_bank() {
+    let mut context = create_ctx_without_session();
+
+    let _ = tss_esapi::abstraction::pcr::allocation_for_banks(
+        &mut context,
+        &[HashingAlgorithm::Sha3_512],
+    )
+    .expect_err("Call to allocation_for_banks with an unsupported bank did not fail");
+}
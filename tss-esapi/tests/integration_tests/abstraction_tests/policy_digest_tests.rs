@@ -0,0 +1,456 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::convert::{TryFrom, TryInto};
+
+use tss_esapi::{
+    abstraction::policy_digest::PolicyDigestBuilder,
+    attributes::NvIndexAttributesBuilder,
+    constants::{
+        tss::{TPM2_ALG_SHA256, TPM2_RH_NULL, TPM2_ST_VERIFIED},
+        ArithmeticOperation, CommandCode, SessionType,
+    },
+    handles::{NvIndexHandle, NvIndexTpmHandle, SessionHandle},
+    interface_types::{
+        algorithm::HashingAlgorithm,
+        resource_handles::{Hierarchy, NvAuth, Provision},
+        session_handles::{AuthSession, PolicySession},
+    },
+    structures::{
+        Digest, DigestList, MaxNvBuffer, Name, Nonce, NvPublicBuilder, Operand,
+        PcrSelectionListBuilder, PcrSlot, PublicKeyRsa, RsaSignature, Signature,
+        SymmetricDefinition,
+    },
+    tss2_esys::TPMT_TK_VERIFIED,
+    Context, Error, Result, WrapperErrorKind,
+};
+
+use crate::common::{create_ctx_without_session, signing_key_pub};
+
+fn endorsement_name() -> Name {
+    Name::try_from(0x4000000Bu32.to_be_bytes().to_vec()).expect("Failed to create name")
+}
+
+/// Creates a name in the format of a SHA256 object name.
+fn sha256_name(value: u8) -> Name {
+    let mut name = TPM2_ALG_SHA256.to_be_bytes().to_vec();
+    name.extend_from_slice(&[value; 32]);
+    Name::try_from(name).expect("Failed to create name")
+}
+
+fn sha256_digest(value: u8) -> Digest {
+    Digest::try_from(vec![value; 32]).expect("Failed to create digest")
+}
+
+/// Runs `f` in a SHA256 trial session and returns the resulting policy digest.
+fn trial_session_digest<F>(context: &mut Context, f: F) -> Digest
+where
+    F: FnOnce(&mut Context, PolicySession) -> Result<()>,
+{
+    let trial_session = context
+        .start_auth_session(
+            None,
+            None,
+            None,
+            SessionType::Trial,
+            SymmetricDefinition::AES_128_CFB,
+            HashingAlgorithm::Sha256,
+        )
+        .expect("Failed to start trial session")
+        .expect("Received invalid handle");
+    let trial_policy_session =
+        PolicySession::try_from(trial_session).expect("Failed to convert to policy session");
+    let result = f(context, trial_policy_session)
+        .and_then(|_| context.policy_get_digest(trial_policy_session));
+    context
+        .flush_context(SessionHandle::from(trial_session).into())
+        .expect("Failed to flush trial session");
+    result.expect("Failed to compute the policy digest in the trial session")
+}
+
+/// Defines an owner NV index and writes `data` to it.
+fn define_nv_index(context: &mut Context, nv_index: u32, data: Vec<u8>) -> NvIndexHandle {
+    let nv_index_attributes = NvIndexAttributesBuilder::new()
+        .with_owner_write(true)
+        .with_owner_read(true)
+        .build()
+        .expect("Failed to create owner nv index attributes");
+    let nv_public = NvPublicBuilder::new()
+        .with_nv_index(NvIndexTpmHandle::new(nv_index).expect("Failed to create NV index"))
+        .with_index_name_algorithm(HashingAlgorithm::Sha256)
+        .with_index_attributes(nv_index_attributes)
+        .with_data_area_size(data.len())
+        .build()
+        .expect("Failed to build NvPublic for owner");
+    context
+        .execute_with_session(
+            Some(AuthSession::Password),
+            |ctx| -> Result<NvIndexHandle> {
+                let nv_index_handle = ctx.nv_define_space(Provision::Owner, None, nv_public)?;
+                ctx.nv_write(
+                    NvAuth::Owner,
+                    nv_index_handle,
+                    MaxNvBuffer::try_from(data)?,
+                    0,
+                )?;
+                Ok(nv_index_handle)
+            },
+        )
+        .expect("Failed to define NV index")
+}
+
+fn undefine_nv_index(context: &mut Context, nv_index_handle: NvIndexHandle) {
+    context
+        .execute_with_session(Some(AuthSession::Password), |ctx| {
+            ctx.nv_undefine_space(Provision::Owner, nv_index_handle)
+        })
+        .expect("Call to nv_undefine_space failed");
+}
+
+#[test]
+fn test_ek_policy_sha256() {
+    let policy_digest = PolicyDigestBuilder::new(HashingAlgorithm::Sha256)
+        .expect("Failed to create builder")
+        .policy_secret(endorsement_name(), Default::default())
+        .build()
+        .expect("Failed to build policy digest");
+    assert_eq!(
+        policy_digest.as_bytes(),
+        [
+            0x83, 0x71, 0x97, 0x67, 0x44, 0x84, 0xb3, 0xf8, 0x1a, 0x90, 0xcc, 0x8d, 0x46, 0xa5,
+            0xd7, 0x24, 0xfd, 0x52, 0xd7, 0x6e, 0x06, 0x52, 0x0b, 0x64, 0xf2, 0xa1, 0xda, 0x1b,
+            0x33, 0x14, 0x69, 0xaa,
+        ]
+    );
+}
+
+#[test]
+fn test_ek_policy_sha384() {
+    let policy_digest = PolicyDigestBuilder::new(HashingAlgorithm::Sha384)
+        .expect("Failed to create builder")
+        .policy_secret(endorsement_name(), Default::default())
+        .build()
+        .expect("Failed to build policy digest");
+    assert_eq!(
+        policy_digest.as_bytes(),
+        [
+            0x8b, 0xbf, 0x22, 0x66, 0x53, 0x7c, 0x17, 0x1c, 0xb5, 0x6e, 0x40, 0x3c, 0x4d, 0xc1,
+            0xd4, 0xb6, 0x4f, 0x43, 0x26, 0x11, 0xdc, 0x38, 0x6e, 0x6f, 0x53, 0x20, 0x50, 0xc3,
+            0x27, 0x8c, 0x93, 0x0e, 0x14, 0x3e, 0x8b, 0xb1, 0x13, 0x38, 0x24, 0xcc, 0xb4, 0x31,
+            0x05, 0x38, 0x71, 0xc6, 0xdb, 0x53,
+        ]
+    );
+}
+
+#[test]
+fn test_policy_auth_value_sha256() {
+    let policy_digest = PolicyDigestBuilder::new(HashingAlgorithm::Sha256)
+        .expect("Failed to create builder")
+        .policy_auth_value()
+        .build()
+        .expect("Failed to build policy digest");
+    assert_eq!(
+        policy_digest.as_bytes(),
+        [
+            0x8f, 0xcd, 0x21, 0x69, 0xab, 0x92, 0x69, 0x4e, 0x0c, 0x63, 0x3f, 0x1a, 0xb7, 0x72,
+            0x84, 0x2b, 0x82, 0x41, 0xbb, 0xc2, 0x02, 0x88, 0x98, 0x1f, 0xc7, 0xac, 0x1e, 0xdd,
+            0xc1, 0xfd, 0xdb, 0x0e,
+        ]
+    );
+}
+
+#[test]
+fn test_unsupported_hashing_algorithm() {
+    assert_eq!(
+        PolicyDigestBuilder::new(HashingAlgorithm::Sm3_256).unwrap_err(),
+        Error::WrapperError(WrapperErrorKind::UnsupportedParam)
+    );
+}
+
+#[test]
+fn test_policy_or_too_few_digests() {
+    let mut digest_list = DigestList::new();
+    digest_list
+        .add(Digest::try_from(vec![0xaa; 32]).expect("Failed to create digest"))
+        .expect("Failed to add digest");
+    assert_eq!(
+        PolicyDigestBuilder::new(HashingAlgorithm::Sha256)
+            .expect("Failed to create builder")
+            .policy_or(digest_list)
+            .unwrap_err(),
+        Error::WrapperError(WrapperErrorKind::InvalidParam)
+    );
+}
+
+#[test]
+fn test_matches_trial_session() {
+    let mut context = create_ctx_without_session();
+
+    let pcr_policy_digest = sha256_digest(0x11);
+    let pcr_selection_list = PcrSelectionListBuilder::new()
+        .with_selection(HashingAlgorithm::Sha256, &[PcrSlot::Slot0, PcrSlot::Slot1])
+        .build()
+        .expect("Failed to create PcrSelectionList");
+    let mut digest_list = DigestList::new();
+    digest_list
+        .add(sha256_digest(0xaa))
+        .expect("Failed to add digest");
+    digest_list
+        .add(sha256_digest(0xbb))
+        .expect("Failed to add digest");
+
+    let expected = trial_session_digest(&mut context, |ctx, session| {
+        ctx.policy_pcr(
+            session,
+            pcr_policy_digest.clone(),
+            pcr_selection_list.clone(),
+        )?;
+        ctx.policy_or(session, digest_list.clone())?;
+        ctx.policy_command_code(session, CommandCode::Unseal)?;
+        ctx.policy_auth_value(session)?;
+        ctx.policy_nv_written(session, true)
+    });
+
+    let policy_digest = PolicyDigestBuilder::new(HashingAlgorithm::Sha256)
+        .expect("Failed to create builder")
+        .policy_pcr(pcr_policy_digest, pcr_selection_list)
+        .policy_or(digest_list)
+        .expect("Failed to call policy_or")
+        .policy_command_code(CommandCode::Unseal)
+        .policy_auth_value()
+        .policy_nv_written(true)
+        .build()
+        .expect("Failed to build policy digest");
+    assert_eq!(policy_digest, expected);
+}
+
+#[test]
+fn test_policy_signed_matches_trial_session() {
+    let mut context = create_ctx_without_session();
+    let key_handle = context
+        .execute_with_session(Some(AuthSession::Password), |ctx| {
+            ctx.create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+        })
+        .expect("Failed to create signing key")
+        .key_handle;
+    let key_name = context
+        .tr_get_name(key_handle.into())
+        .expect("Failed to get the name of the signing key");
+    let policy_ref = Nonce::try_from(vec![1, 2, 3]).expect("Failed to create nonce");
+    // The signature is not checked in a trial session.
+    let signature = Signature::RsaSsa(
+        RsaSignature::create(
+            HashingAlgorithm::Sha256,
+            PublicKeyRsa::try_from(vec![0xab; 32]).expect("Failed to create RSA signature data"),
+        )
+        .expect("Failed to create RSA signature"),
+    );
+
+    let expected = trial_session_digest(&mut context, |ctx, session| {
+        ctx.policy_signed(
+            session,
+            key_handle.into(),
+            Nonce::default(),
+            Digest::default(),
+            policy_ref.clone(),
+            None,
+            signature,
+        )
+        .map(|_| ())
+    });
+    context
+        .flush_context(key_handle.into())
+        .expect("Failed to flush signing key");
+
+    let policy_digest = PolicyDigestBuilder::new(HashingAlgorithm::Sha256)
+        .expect("Failed to create builder")
+        .policy_signed(key_name, policy_ref)
+        .build()
+        .expect("Failed to build policy digest");
+    assert_eq!(policy_digest, expected);
+}
+
+#[test]
+fn test_policy_authorize_matches_trial_session() {
+    let mut context = create_ctx_without_session();
+    let policy_ref = Nonce::try_from(vec![4, 5, 6]).expect("Failed to create nonce");
+    let key_sign = sha256_name(0x42);
+
+    let expected = trial_session_digest(&mut context, |ctx, session| {
+        ctx.policy_command_code(session, CommandCode::Unseal)?;
+        // The ticket is not checked in a trial session.
+        let check_ticket = TPMT_TK_VERIFIED {
+            tag: TPM2_ST_VERIFIED,
+            hierarchy: TPM2_RH_NULL,
+            digest: Default::default(),
+        }
+        .try_into()?;
+        ctx.policy_authorize(
+            session,
+            sha256_digest(0x33),
+            policy_ref.clone(),
+            &key_sign,
+            check_ticket,
+        )
+    });
+
+    let policy_digest = PolicyDigestBuilder::new(HashingAlgorithm::Sha256)
+        .expect("Failed to create builder")
+        .policy_command_code(CommandCode::Unseal)
+        .policy_authorize(policy_ref, key_sign)
+        .build()
+        .expect("Failed to build policy digest");
+    assert_eq!(policy_digest, expected);
+}
+
+#[test]
+fn test_policy_nv_matches_trial_session() {
+    let mut context = create_ctx_without_session();
+    let nv_index_handle = define_nv_index(&mut context, 0x01500035, vec![0x01; 8]);
+    let nv_index_name = context
+        .tr_get_name(nv_index_handle.into())
+        .expect("Failed to get the name of the NV index");
+    let operand_b = Operand::try_from(vec![0x01; 4]).expect("Failed to create operand");
+
+    let expected = trial_session_digest(&mut context, |ctx, session| {
+        ctx.execute_with_session(Some(AuthSession::Password), |ctx| {
+            ctx.policy_nv(
+                session,
+                NvAuth::Owner,
+                nv_index_handle,
+                operand_b.clone(),
+                2,
+                ArithmeticOperation::UnsignedLe,
+            )
+        })
+    });
+    undefine_nv_index(&mut context, nv_index_handle);
+
+    let policy_digest = PolicyDigestBuilder::new(HashingAlgorithm::Sha256)
+        .expect("Failed to create builder")
+        .policy_nv(nv_index_name, operand_b, 2, ArithmeticOperation::UnsignedLe)
+        .build()
+        .expect("Failed to build policy digest");
+    assert_eq!(policy_digest, expected);
+}
+
+#[test]
+fn test_policy_counter_timer_matches_trial_session() {
+    let mut context = create_ctx_without_session();
+    let operand_b =
+        Operand::try_from(u64::MAX.to_be_bytes().to_vec()).expect("Failed to create operand");
+
+    let expected = trial_session_digest(&mut context, |ctx, session| {
+        ctx.policy_counter_timer(
+            session,
+            operand_b.clone(),
+            8,
+            ArithmeticOperation::UnsignedLt,
+        )
+    });
+
+    let policy_digest = PolicyDigestBuilder::new(HashingAlgorithm::Sha256)
+        .expect("Failed to create builder")
+        .policy_counter_timer(operand_b, 8, ArithmeticOperation::UnsignedLt)
+        .build()
+        .expect("Failed to build policy digest");
+    assert_eq!(policy_digest, expected);
+}
+
+#[test]
+fn test_policy_cp_hash_matches_trial_session() {
+    let mut context = create_ctx_without_session();
+
+    let expected = trial_session_digest(&mut context, |ctx, session| {
+        ctx.policy_cp_hash(session, sha256_digest(0x44))
+    });
+
+    let policy_digest = PolicyDigestBuilder::new(HashingAlgorithm::Sha256)
+        .expect("Failed to create builder")
+        .policy_cp_hash(sha256_digest(0x44))
+        .build()
+        .expect("Failed to build policy digest");
+    assert_eq!(policy_digest, expected);
+}
+
+#[test]
+fn test_policy_name_hash_matches_trial_session() {
+    let mut context = create_ctx_without_session();
+
+    let expected = trial_session_digest(&mut context, |ctx, session| {
+        ctx.policy_name_hash(session, sha256_digest(0x55))
+    });
+
+    let policy_digest = PolicyDigestBuilder::new(HashingAlgorithm::Sha256)
+        .expect("Failed to create builder")
+        .policy_name_hash(sha256_digest(0x55))
+        .build()
+        .expect("Failed to build policy digest");
+    assert_eq!(policy_digest, expected);
+}
+
+#[test]
+fn test_policy_duplication_select_matches_trial_session() {
+    let mut context = create_ctx_without_session();
+
+    for include_object in [true, false] {
+        let expected = trial_session_digest(&mut context, |ctx, session| {
+            ctx.policy_duplication_select(
+                session,
+                sha256_name(0x66),
+                sha256_name(0x77),
+                include_object,
+            )
+        });
+
+        let policy_digest = PolicyDigestBuilder::new(HashingAlgorithm::Sha256)
+            .expect("Failed to create builder")
+            .policy_duplication_select(sha256_name(0x66), sha256_name(0x77), include_object)
+            .build()
+            .expect("Failed to build policy digest");
+        assert_eq!(policy_digest, expected);
+    }
+}
+
+#[test]
+fn test_policy_template_matches_trial_session() {
+    let mut context = create_ctx_without_session();
+
+    let expected = trial_session_digest(&mut context, |ctx, session| {
+        ctx.policy_template(session, sha256_digest(0x88))
+    });
+
+    let policy_digest = PolicyDigestBuilder::new(HashingAlgorithm::Sha256)
+        .expect("Failed to create builder")
+        .policy_template(sha256_digest(0x88))
+        .build()
+        .expect("Failed to build policy digest");
+    assert_eq!(policy_digest, expected);
+}
+
+#[test]
+fn test_policy_authorize_nv_matches_trial_session() {
+    let mut context = create_ctx_without_session();
+    // The NV index contains a marshalled TPMT_HA.
+    let mut nv_data = TPM2_ALG_SHA256.to_be_bytes().to_vec();
+    nv_data.extend_from_slice(sha256_digest(0x99).as_bytes());
+    let nv_index_handle = define_nv_index(&mut context, 0x01500036, nv_data);
+    let nv_index_name = context
+        .tr_get_name(nv_index_handle.into())
+        .expect("Failed to get the name of the NV index");
+
+    let expected = trial_session_digest(&mut context, |ctx, session| {
+        ctx.policy_command_code(session, CommandCode::Unseal)?;
+        ctx.execute_with_session(Some(AuthSession::Password), |ctx| {
+            ctx.policy_authorize_nv(session, NvAuth::Owner, nv_index_handle)
+        })
+    });
+    undefine_nv_index(&mut context, nv_index_handle);
+
+    let policy_digest = PolicyDigestBuilder::new(HashingAlgorithm::Sha256)
+        .expect("Failed to create builder")
+        .policy_command_code(CommandCode::Unseal)
+        .policy_authorize_nv(nv_index_name)
+        .build()
+        .expect("Failed to build policy digest");
+    assert_eq!(policy_digest, expected);
+}
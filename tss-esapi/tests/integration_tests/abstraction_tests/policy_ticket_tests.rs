@@ -0,0 +1,95 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::{convert::TryFrom, time::Duration};
+
+use tss_esapi::{
+    abstraction::policy_ticket::CachedPolicyTicket,
+    constants::SessionType,
+    handles::AuthHandle,
+    interface_types::{algorithm::HashingAlgorithm, session_handles::PolicySession},
+    structures::{Digest, Nonce, SymmetricDefinition},
+    Context, Error, WrapperErrorKind,
+};
+
+use crate::common::create_ctx_with_session;
+
+fn start_policy_session(context: &mut Context) -> PolicySession {
+    let policy_auth_session = context
+        .start_auth_session(
+            None,
+            None,
+            None,
+            SessionType::Policy,
+            SymmetricDefinition::AES_256_CFB,
+            HashingAlgorithm::Sha256,
+        )
+        .expect("Start auth session failed")
+        .expect("Start auth session returned a NONE handle");
+    PolicySession::try_from(policy_auth_session)
+        .expect("Failed to convert auth session into policy session")
+}
+
+#[test]
+fn test_reuse_cached_policy_ticket() {
+    let mut context = create_ctx_with_session();
+
+    let first_policy_session = start_policy_session(&mut context);
+    let cached_ticket = CachedPolicyTicket::from_policy_secret(
+        &mut context,
+        first_policy_session,
+        AuthHandle::Owner,
+        Nonce::default(),
+        Digest::default(),
+        Nonce::default(),
+        Duration::from_secs(3600),
+    )
+    .expect("Failed to create cached policy ticket");
+    assert!(!cached_ticket.is_expired());
+    let expected_policy_digest = context
+        .execute_without_session(|ctx| ctx.policy_get_digest(first_policy_session))
+        .expect("Call to policy_get_digest failed");
+
+    // The ticket can be redeemed in several sessions.
+    for _ in 0..2 {
+        let policy_session = start_policy_session(&mut context);
+        let actual_policy_digest = context
+            .execute_without_session(|ctx| {
+                cached_ticket.redeem(ctx, policy_session)?;
+                ctx.policy_get_digest(policy_session)
+            })
+            .expect("Failed to redeem cached policy ticket");
+        assert_eq!(expected_policy_digest, actual_policy_digest);
+    }
+}
+
+#[test]
+fn test_expired_cached_policy_ticket() {
+    let mut context = create_ctx_with_session();
+
+    let first_policy_session = start_policy_session(&mut context);
+    let cached_ticket = CachedPolicyTicket::from_policy_secret(
+        &mut context,
+        first_policy_session,
+        AuthHandle::Owner,
+        Nonce::default(),
+        Digest::default(),
+        Nonce::default(),
+        Duration::from_secs(1),
+    )
+    .expect("Failed to create cached policy ticket");
+    let expired_ticket = CachedPolicyTicket::new(
+        cached_ticket.timeout().clone(),
+        Digest::default(),
+        Nonce::default(),
+        cached_ticket.auth_name().clone(),
+        cached_ticket.ticket().clone(),
+        Duration::from_secs(0),
+    );
+    assert!(expired_ticket.is_expired());
+
+    let policy_session = start_policy_session(&mut context);
+    assert_eq!(
+        expired_ticket.redeem(&mut context, policy_session),
+        Err(Error::WrapperError(WrapperErrorKind::InvalidParam))
+    );
+}
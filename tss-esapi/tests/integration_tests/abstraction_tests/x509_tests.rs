@@ -0,0 +1,112 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+#[cfg(has_esys_certify_x509)]
+mod test_certify_x509 {
+    use crate::common::{create_ctx_without_session, signing_key_pub};
+    use picky_asn1::date::UTCTime;
+    use picky_asn1_x509::{Certificate, Extension, Extensions, KeyUsage, Name, Validity};
+    use sha2::{Digest as _, Sha256};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        abstraction::x509::{certify_x509, PartialCertificate},
+        interface_types::{
+            algorithm::HashingAlgorithm, resource_handles::Hierarchy, session_handles::AuthSession,
+        },
+        structures::{Digest, PublicKeyRsa, RsaSignature, Signature, SignatureScheme},
+    };
+
+    fn partial_certificate() -> PartialCertificate {
+        let mut key_usage = KeyUsage::new(1);
+        key_usage.set_digital_signature(true);
+        PartialCertificate {
+            issuer: Name::new_common_name("TPM CA"),
+            validity: Validity {
+                not_before: UTCTime::new(2022, 1, 1, 0, 0, 0)
+                    .expect("Failed to create date")
+                    .into(),
+                not_after: UTCTime::new(2032, 1, 1, 0, 0, 0)
+                    .expect("Failed to create date")
+                    .into(),
+            },
+            subject: Name::new_common_name("TPM key"),
+            extensions: Extensions(vec![Extension::new_key_usage(key_usage)]),
+        }
+    }
+
+    #[test]
+    fn test_certify_x509() {
+        let mut context = create_ctx_without_session();
+        let key_handle = context
+            .execute_with_nullauth_session(|ctx| {
+                ctx.create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+            })
+            .expect("Failed to create signing key")
+            .key_handle;
+
+        let partial_certificate = partial_certificate();
+        let (certificate, tbs_digest) = context
+            .execute_with_sessions(
+                (
+                    Some(AuthSession::Password),
+                    Some(AuthSession::Password),
+                    None,
+                ),
+                |ctx| {
+                    certify_x509(
+                        ctx,
+                        key_handle.into(),
+                        key_handle,
+                        SignatureScheme::Null,
+                        partial_certificate.clone(),
+                    )
+                },
+            )
+            .expect("Failed to certify X.509");
+
+        let encoded_certificate =
+            picky_asn1_der::to_vec(&certificate).expect("Failed to encode the certificate");
+        let parsed_certificate: Certificate = picky_asn1_der::from_bytes(&encoded_certificate)
+            .expect("Failed to parse the certificate");
+        assert_eq!(parsed_certificate, certificate);
+        assert_eq!(
+            parsed_certificate.tbs_certificate.issuer,
+            partial_certificate.issuer
+        );
+        assert_eq!(
+            parsed_certificate.tbs_certificate.subject,
+            partial_certificate.subject
+        );
+        assert_eq!(
+            parsed_certificate.tbs_certificate.validity,
+            partial_certificate.validity
+        );
+
+        // The TPM signed the digest of the TBS certificate.
+        let encoded_tbs_certificate = picky_asn1_der::to_vec(&parsed_certificate.tbs_certificate)
+            .expect("Failed to encode the TBS certificate");
+        let digest = Digest::try_from(Sha256::digest(&encoded_tbs_certificate).to_vec())
+            .expect("Failed to create digest");
+        assert_eq!(digest, tbs_digest);
+
+        // Verify the signature with a key that only contains the public area.
+        let (public, _, _) = context
+            .read_public(key_handle)
+            .expect("Failed to read the public area of the signing key");
+        let verifying_key_handle = context
+            .load_external_public(public, Hierarchy::Owner)
+            .expect("Failed to load the public area of the signing key");
+        let signature = Signature::RsaSsa(
+            RsaSignature::create(
+                HashingAlgorithm::Sha256,
+                PublicKeyRsa::try_from(
+                    parsed_certificate.signature_value.0.payload_view().to_vec(),
+                )
+                .expect("Failed to create RSA signature data"),
+            )
+            .expect("Failed to create RSA signature"),
+        );
+        let _ = context
+            .verify_signature(verifying_key_handle, digest, signature)
+            .expect("The certificate signature did not verify");
+    }
+}
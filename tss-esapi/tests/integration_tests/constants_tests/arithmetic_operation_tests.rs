@@ -0,0 +1,47 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use tss_esapi::{
+    constants::{
+        tss::{
+            TPM2_EO_BITCLEAR, TPM2_EO_BITSET, TPM2_EO_EQ, TPM2_EO_NEQ, TPM2_EO_SIGNED_GE,
+            TPM2_EO_SIGNED_GT, TPM2_EO_SIGNED_LE, TPM2_EO_SIGNED_LT, TPM2_EO_UNSIGNED_GE,
+            TPM2_EO_UNSIGNED_GT, TPM2_EO_UNSIGNED_LE, TPM2_EO_UNSIGNED_LT,
+        },
+        ArithmeticOperation,
+    },
+    tss2_esys::TPM2_EO,
+};
+
+use std::convert::{From, TryFrom};
+
+macro_rules! test_valid_conversions {
+    ($tss_value:ident, ArithmeticOperation::$item:ident) => {
+        assert_eq!($tss_value, TPM2_EO::from(ArithmeticOperation::$item));
+        assert_eq!(
+            ArithmeticOperation::$item,
+            ArithmeticOperation::try_from($tss_value).unwrap()
+        );
+    };
+}
+
+#[test]
+fn test_valid_conversions() {
+    test_valid_conversions!(TPM2_EO_EQ, ArithmeticOperation::Eq);
+    test_valid_conversions!(TPM2_EO_NEQ, ArithmeticOperation::Neq);
+    test_valid_conversions!(TPM2_EO_SIGNED_GT, ArithmeticOperation::SignedGt);
+    test_valid_conversions!(TPM2_EO_UNSIGNED_GT, ArithmeticOperation::UnsignedGt);
+    test_valid_conversions!(TPM2_EO_SIGNED_LT, ArithmeticOperation::SignedLt);
+    test_valid_conversions!(TPM2_EO_UNSIGNED_LT, ArithmeticOperation::UnsignedLt);
+    test_valid_conversions!(TPM2_EO_SIGNED_GE, ArithmeticOperation::SignedGe);
+    test_valid_conversions!(TPM2_EO_UNSIGNED_GE, ArithmeticOperation::UnsignedGe);
+    test_valid_conversions!(TPM2_EO_SIGNED_LE, ArithmeticOperation::SignedLe);
+    test_valid_conversions!(TPM2_EO_UNSIGNED_LE, ArithmeticOperation::UnsignedLe);
+    test_valid_conversions!(TPM2_EO_BITSET, ArithmeticOperation::BitSet);
+    test_valid_conversions!(TPM2_EO_BITCLEAR, ArithmeticOperation::BitClear);
+}
+
+#[test]
+fn test_invalid_conversion() {
+    const INVALID_VALUE: TPM2_EO = 0x000C;
+    let _ = ArithmeticOperation::try_from(INVALID_VALUE).unwrap_err();
+}
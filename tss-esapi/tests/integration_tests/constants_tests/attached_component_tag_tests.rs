@@ -0,0 +1,57 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use tss_esapi::{
+    constants::{
+        tss::{TPM2_AT_ANY, TPM2_AT_ERROR, TPM2_AT_PV1, TPM2_AT_VEND},
+        AttachedComponentTag, VendorAttachedComponentTag,
+    },
+    tss2_esys::UINT32,
+    Error, WrapperErrorKind,
+};
+
+use std::convert::{From, TryFrom};
+
+macro_rules! test_valid_conversions {
+    ($tss_value:expr, $attached_component_tag:expr) => {
+        assert_eq!($tss_value, UINT32::from($attached_component_tag));
+        assert_eq!(
+            $attached_component_tag,
+            AttachedComponentTag::try_from($tss_value).unwrap()
+        );
+    };
+}
+
+#[test]
+fn test_valid_conversions() {
+    test_valid_conversions!(TPM2_AT_ANY, AttachedComponentTag::Any);
+    test_valid_conversions!(TPM2_AT_ERROR, AttachedComponentTag::Error);
+    test_valid_conversions!(TPM2_AT_PV1, AttachedComponentTag::Pv1);
+    for value in [TPM2_AT_VEND, TPM2_AT_VEND + 1, u32::MAX] {
+        test_valid_conversions!(
+            value,
+            AttachedComponentTag::Vendor(
+                VendorAttachedComponentTag::new(value).expect("Failed to create vendor tag")
+            )
+        );
+    }
+}
+
+#[test]
+fn test_vendor_tag() {
+    assert_eq!(
+        TPM2_AT_VEND,
+        VendorAttachedComponentTag::new(TPM2_AT_VEND)
+            .expect("Failed to create vendor tag")
+            .value()
+    );
+    assert_eq!(
+        VendorAttachedComponentTag::new(TPM2_AT_PV1).unwrap_err(),
+        Error::WrapperError(WrapperErrorKind::InvalidParam)
+    );
+}
+
+#[test]
+fn test_invalid_conversion() {
+    const INVALID_VALUE: UINT32 = 3;
+    let _ = AttachedComponentTag::try_from(INVALID_VALUE).unwrap_err();
+}
@@ -0,0 +1,42 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use tss_esapi::{
+    constants::{
+        tss::{
+            TPM2_CLOCK_COARSE_FASTER, TPM2_CLOCK_COARSE_SLOWER, TPM2_CLOCK_FINE_FASTER,
+            TPM2_CLOCK_FINE_SLOWER, TPM2_CLOCK_MEDIUM_FASTER, TPM2_CLOCK_MEDIUM_SLOWER,
+            TPM2_CLOCK_NO_CHANGE,
+        },
+        ClockAdjust,
+    },
+    tss2_esys::TPM2_CLOCK_ADJUST,
+};
+
+use std::convert::{From, TryFrom};
+
+macro_rules! test_valid_conversions {
+    ($tss_value:ident, ClockAdjust::$item:ident) => {
+        assert_eq!($tss_value, TPM2_CLOCK_ADJUST::from(ClockAdjust::$item));
+        assert_eq!(
+            ClockAdjust::$item,
+            ClockAdjust::try_from($tss_value).unwrap()
+        );
+    };
+}
+
+#[test]
+fn test_valid_conversions() {
+    test_valid_conversions!(TPM2_CLOCK_COARSE_SLOWER, ClockAdjust::CoarseSlower);
+    test_valid_conversions!(TPM2_CLOCK_MEDIUM_SLOWER, ClockAdjust::MediumSlower);
+    test_valid_conversions!(TPM2_CLOCK_FINE_SLOWER, ClockAdjust::FineSlower);
+    test_valid_conversions!(TPM2_CLOCK_NO_CHANGE, ClockAdjust::NoChange);
+    test_valid_conversions!(TPM2_CLOCK_FINE_FASTER, ClockAdjust::FineFaster);
+    test_valid_conversions!(TPM2_CLOCK_MEDIUM_FASTER, ClockAdjust::MediumFaster);
+    test_valid_conversions!(TPM2_CLOCK_COARSE_FASTER, ClockAdjust::CoarseFaster);
+}
+
+#[test]
+fn test_invalid_conversion() {
+    const INVALID_VALUE: TPM2_CLOCK_ADJUST = 4;
+    let _ = ClockAdjust::try_from(INVALID_VALUE).unwrap_err();
+}
@@ -445,3 +445,71 @@ mod test_tr_from_tpm_public {
         assert_eq!(expected, actual);
     }
 }
+
+mod test_tr_serialize {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        handles::{NvIndexTpmHandle, ObjectHandle},
+        interface_types::{algorithm::HashingAlgorithm, resource_handles::Provision},
+        structures::NvPublicBuilder,
+        utils::SerializedHandle,
+    };
+
+    #[test]
+    fn test_tr_serialize_deserialize() {
+        let mut context = create_ctx_with_session();
+        let nv_index_tpm_handle = NvIndexTpmHandle::new(0x01500305).unwrap();
+
+        let nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+        let nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index_tpm_handle)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(nv_index_attributes)
+            .with_data_area_size(32)
+            .build()
+            .expect("Failed to build NvPublic");
+        let nv_index_handle = context
+            .nv_define_space(Provision::Owner, None, nv_public)
+            .expect("Call to nv_define_space failed");
+        let expected_name = context
+            .tr_get_name(nv_index_handle.into())
+            .expect("Call to tr_get_name failed");
+
+        let serialized = context
+            .tr_serialize(nv_index_handle.into())
+            .expect("Call to tr_serialize failed");
+        let mut handle_to_be_closed: ObjectHandle = nv_index_handle.into();
+        context
+            .tr_close(&mut handle_to_be_closed)
+            .expect("Call to tr_close failed");
+
+        let restored_handle = context
+            .tr_deserialize(&serialized)
+            .expect("Call to tr_deserialize failed");
+        let actual_name = context
+            .tr_get_name(restored_handle)
+            .expect("Call to tr_get_name failed");
+
+        let serialized_handle = SerializedHandle::from_handle(&mut context, restored_handle)
+            .expect("Failed to create serialized handle");
+        assert_eq!(serialized_handle.metadata(), serialized.as_slice());
+
+        context
+            .nv_undefine_space(Provision::Owner, restored_handle.into())
+            .expect("Call to nv_undefine_space failed");
+        assert_eq!(expected_name, actual_name);
+    }
+
+    #[test]
+    fn test_tr_deserialize_invalid_data() {
+        let mut context = create_ctx_with_session();
+        let _ = context
+            .tr_deserialize(&[0x01, 0x02])
+            .expect_err("Invalid metadata was deserialized");
+    }
+}
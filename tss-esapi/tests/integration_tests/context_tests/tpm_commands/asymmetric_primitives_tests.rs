@@ -104,3 +104,111 @@ mod test_rsa_encrypt_decrypt {
         assert_eq!(z_point.x().as_bytes(), param.x().as_bytes());
     }
 }
+
+mod test_zgen_2phase {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::{
+        attributes::ObjectAttributesBuilder,
+        interface_types::{
+            algorithm::{EccSchemeAlgorithm, HashingAlgorithm, PublicAlgorithm},
+            ecc::EccCurve,
+            resource_handles::Hierarchy,
+        },
+        structures::{
+            EccPoint, EccScheme, HashScheme, KeyDerivationFunctionScheme, PublicBuilder,
+            PublicEccParametersBuilder,
+        },
+    };
+
+    #[test]
+    fn test_zgen_2phase() {
+        let mut context = create_ctx_with_session();
+        let ecc_parameters = PublicEccParametersBuilder::new()
+            .with_ecc_scheme(EccScheme::EcDh(HashScheme::new(HashingAlgorithm::Sha256)))
+            .with_curve(EccCurve::NistP256)
+            .with_is_signing_key(false)
+            .with_is_decryption_key(true)
+            .with_restricted(false)
+            .with_key_derivation_function_scheme(KeyDerivationFunctionScheme::Null)
+            .build()
+            .unwrap();
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_fixed_tpm(true)
+            .with_fixed_parent(true)
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .with_decrypt(true)
+            .with_sign_encrypt(false)
+            .with_restricted(false)
+            .build()
+            .unwrap();
+        let public = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::Ecc)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_ecc_parameters(ecc_parameters)
+            .with_ecc_unique_identifier(EccPoint::default())
+            .build()
+            .unwrap();
+        let key_handle = context
+            .create_primary(Hierarchy::Owner, public, None, None, None, None)
+            .unwrap()
+            .key_handle;
+
+        // Use the public points generated by ECDH_KeyGen as the keys of the other party.
+        let (static_z_point, static_pub_point) = context.ecdh_key_gen(key_handle).unwrap();
+        let (_, ephemeral_pub_point) = context.ecdh_key_gen(key_handle).unwrap();
+
+        let (_, counter) = context.ec_ephemeral(EccCurve::NistP256).unwrap();
+        let (z1, z2) = context
+            .zgen_2phase(
+                key_handle,
+                static_pub_point,
+                ephemeral_pub_point,
+                EccSchemeAlgorithm::EcDh,
+                counter,
+            )
+            .unwrap();
+
+        // For ECDH the first point is computed from the static keys only.
+        assert_eq!(static_z_point.x().as_bytes(), z1.x().as_bytes());
+        assert!(!z2.x().is_empty());
+    }
+}
+
+mod test_ecc_parameters {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::{
+        constants::CapabilityType, interface_types::ecc::EccCurve, structures::CapabilityData,
+    };
+
+    #[test]
+    fn test_ecc_parameters() {
+        let mut context = create_ctx_without_session();
+        let parameters = context.ecc_parameters(EccCurve::NistP256).unwrap();
+        assert_eq!(parameters.curve(), EccCurve::NistP256);
+        assert_eq!(parameters.key_size(), 256);
+        assert_eq!(parameters.p().len(), 32);
+        assert_eq!(parameters.n().len(), 32);
+        assert_eq!(parameters.h().as_bytes(), [1u8]);
+    }
+
+    #[test]
+    fn test_ecc_parameters_for_supported_curves() {
+        let mut context = create_ctx_without_session();
+        let (capability_data, _) = context
+            .get_capability(CapabilityType::EccCurves, 0, 80)
+            .unwrap();
+        let ecc_curves = match capability_data {
+            CapabilityData::EccCurves(ecc_curves) => ecc_curves.into_inner(),
+            _ => panic!("Unexpected capability data"),
+        };
+        assert!(!ecc_curves.is_empty());
+        for ecc_curve_identifier in ecc_curves {
+            let curve = EccCurve::from(ecc_curve_identifier);
+            let parameters = context.ecc_parameters(curve).unwrap();
+            assert_eq!(parameters.curve(), curve);
+            assert!(!parameters.generator().x().is_empty());
+        }
+    }
+}
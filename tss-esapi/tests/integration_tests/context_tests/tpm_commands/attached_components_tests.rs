@@ -1,2 +1,125 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+#[cfg(has_esys_attached_components)]
+mod test_ac_get_capability {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::{
+        constants::{AttachedComponentTag, TpmFormatZeroError},
+        error::{ReturnCode, TpmFormatZeroResponseCode, TpmResponseCode},
+        handles::{AcHandle, ObjectHandle},
+        Error,
+    };
+
+    #[test]
+    fn test_ac_get_capability_unimplemented() {
+        let mut context = create_ctx_without_session();
+        let error = context
+            .ac_get_capability(
+                AcHandle::from(ObjectHandle::Null),
+                AttachedComponentTag::Any,
+                1,
+            )
+            .expect_err("AC capability returned unexpectedly");
+        if let Error::TssError(ReturnCode::Tpm(TpmResponseCode::FormatZero(
+            TpmFormatZeroResponseCode::Error(error),
+        ))) = error
+        {
+            assert_eq!(error.error_number(), TpmFormatZeroError::CommandCode);
+        } else {
+            panic!("Unexpected error: {}", error);
+        }
+    }
+}
+
+#[cfg(has_esys_attached_components)]
+mod test_ac_send {
+    use crate::common::create_ctx_without_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::TpmFormatZeroError,
+        error::{ReturnCode, TpmFormatZeroResponseCode, TpmResponseCode},
+        handles::{AcHandle, ObjectHandle},
+        interface_types::{resource_handles::NvAuth, session_handles::AuthSession},
+        structures::MaxBuffer,
+        Error,
+    };
+
+    #[test]
+    fn test_ac_send_unimplemented() {
+        let mut context = create_ctx_without_session();
+        let error = context
+            .execute_with_sessions(
+                (
+                    Some(AuthSession::Password),
+                    Some(AuthSession::Password),
+                    None,
+                ),
+                |ctx| {
+                    ctx.ac_send(
+                        ObjectHandle::Null,
+                        NvAuth::Owner,
+                        AcHandle::from(ObjectHandle::Null),
+                        MaxBuffer::try_from(vec![0xff; 32]).expect("Failed to create buffer"),
+                    )
+                },
+            )
+            .expect_err("AC send succeeded unexpectedly");
+        if let Error::TssError(ReturnCode::Tpm(TpmResponseCode::FormatZero(
+            TpmFormatZeroResponseCode::Error(error),
+        ))) = error
+        {
+            assert_eq!(error.error_number(), TpmFormatZeroError::CommandCode);
+        } else {
+            panic!("Unexpected error: {}", error);
+        }
+    }
+}
+
+#[cfg(has_esys_attached_components)]
+mod test_policy_ac_send_select {
+    use crate::common::create_ctx_without_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::{SessionType, TpmFormatZeroError},
+        error::{ReturnCode, TpmFormatZeroResponseCode, TpmResponseCode},
+        handles::SessionHandle,
+        interface_types::{algorithm::HashingAlgorithm, session_handles::PolicySession},
+        structures::{Name, SymmetricDefinition},
+        Error,
+    };
+
+    #[test]
+    fn test_policy_ac_send_select_unimplemented() {
+        let mut context = create_ctx_without_session();
+        let trial_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Trial,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let policy_session =
+            PolicySession::try_from(trial_session).expect("Failed to convert auth session");
+
+        let name = Name::try_from(vec![0xff; 34]).expect("Failed to create name");
+        let error = context
+            .policy_ac_send_select(policy_session, name.clone(), name.clone(), name, false)
+            .expect_err("Policy AC send select succeeded unexpectedly");
+        context
+            .flush_context(SessionHandle::from(trial_session).into())
+            .expect("Failed to flush the trial session");
+
+        if let Error::TssError(ReturnCode::Tpm(TpmResponseCode::FormatZero(
+            TpmFormatZeroResponseCode::Error(error),
+        ))) = error
+        {
+            assert_eq!(error.error_number(), TpmFormatZeroError::CommandCode);
+        } else {
+            panic!("Unexpected error: {}", error);
+        }
+    }
+}
@@ -1,20 +1,23 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 mod test_quote {
-    use crate::common::{create_ctx_with_session, decryption_key_pub, signing_key_pub};
+    use crate::common::{
+        create_ctx_with_session, create_ctx_without_session, decryption_key_pub, signing_key_pub,
+    };
     use std::convert::TryFrom;
     use tss_esapi::{
-        constants::StructureTag,
-        handles::KeyHandle,
+        attributes::SessionAttributesBuilder,
+        constants::{SessionType, StructureTag},
+        handles::{KeyHandle, SessionHandle},
         interface_types::{
             algorithm::{HashingAlgorithm, SignatureSchemeAlgorithm},
             resource_handles::Hierarchy,
-            session_handles::AuthSession,
+            session_handles::{AuthSession, HmacSession},
             structure_tags::AttestationType,
         },
         structures::{
             AttestInfo, Data, HashScheme, MaxBuffer, PcrSelectionListBuilder, PcrSlot,
-            SignatureScheme, Ticket,
+            SignatureScheme, SymmetricDefinition, Ticket,
         },
         traits::Marshall,
     };
@@ -166,4 +169,263 @@ mod test_quote {
 
         assert_eq!(signature.algorithm(), SignatureSchemeAlgorithm::Null);
     }
+
+    #[test]
+    fn certify_creation() {
+        let mut context = create_ctx_with_session();
+        let qualifying_data = vec![0xff; 16];
+
+        let sign_key_handle = context
+            .create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+            .unwrap()
+            .key_handle;
+        let obj_key = context
+            .create_primary(
+                Hierarchy::Owner,
+                decryption_key_pub(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        let obj_name = context
+            .tr_get_name(obj_key.key_handle.into())
+            .expect("Failed to get the name of the object");
+        let obj_key_handle = obj_key.key_handle;
+        let creation_hash = obj_key.creation_hash;
+        let creation_ticket = obj_key.creation_ticket;
+
+        let (attest, _signature) = context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.certify_creation(
+                    sign_key_handle,
+                    obj_key_handle.into(),
+                    Data::try_from(qualifying_data.clone()).unwrap(),
+                    creation_hash.clone(),
+                    SignatureScheme::Null,
+                    creation_ticket,
+                )
+            })
+            .expect("Failed to certify creation");
+
+        assert_eq!(attest.attestation_type(), AttestationType::Creation);
+        assert_eq!(attest.extra_data().as_bytes(), qualifying_data);
+        match attest.attested() {
+            AttestInfo::Creation { info } => {
+                assert_eq!(info.object_name(), &obj_name);
+                assert_eq!(info.creation_hash(), &creation_hash);
+            }
+            _ => {
+                panic!("Attested did not contain the expected variant.")
+            }
+        }
+    }
+
+    #[test]
+    fn get_time() {
+        let mut context = create_ctx_without_session();
+        let qualifying_data = vec![0xff; 16];
+
+        let sign_key_handle = context
+            .execute_with_nullauth_session(|ctx| {
+                ctx.create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+            })
+            .unwrap()
+            .key_handle;
+
+        let (attest, _signature) = context
+            .execute_with_sessions(
+                (
+                    Some(AuthSession::Password),
+                    Some(AuthSession::Password),
+                    None,
+                ),
+                |ctx| {
+                    ctx.get_time(
+                        sign_key_handle,
+                        Data::try_from(qualifying_data.clone()).unwrap(),
+                        SignatureScheme::Null,
+                    )
+                },
+            )
+            .expect("Failed to get time");
+
+        assert_eq!(attest.attestation_type(), AttestationType::Time);
+        assert_eq!(attest.extra_data().as_bytes(), qualifying_data);
+        assert!(matches!(attest.attested(), AttestInfo::Time { .. }));
+    }
+
+    #[test]
+    fn get_command_audit_digest() {
+        let mut context = create_ctx_without_session();
+
+        let sign_key_handle = context
+            .execute_with_nullauth_session(|ctx| {
+                ctx.create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+            })
+            .unwrap()
+            .key_handle;
+
+        let (attest, _signature) = context
+            .execute_with_sessions(
+                (
+                    Some(AuthSession::Password),
+                    Some(AuthSession::Password),
+                    None,
+                ),
+                |ctx| {
+                    ctx.get_command_audit_digest(
+                        sign_key_handle,
+                        Data::default(),
+                        SignatureScheme::Null,
+                    )
+                },
+            )
+            .expect("Failed to get command audit digest");
+
+        assert_eq!(attest.attestation_type(), AttestationType::CommandAudit);
+        assert!(matches!(attest.attested(), AttestInfo::CommandAudit { .. }));
+    }
+
+    #[test]
+    fn get_session_audit_digest() {
+        let mut context = create_ctx_without_session();
+
+        let sign_key_handle = context
+            .execute_with_nullauth_session(|ctx| {
+                ctx.create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+            })
+            .unwrap()
+            .key_handle;
+
+        let audit_auth_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Hmac,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Failed to start auth session")
+            .expect("Start auth session returned a NONE handle");
+        let (session_attributes, session_attributes_mask) = SessionAttributesBuilder::new()
+            .with_continue_session(true)
+            .with_audit(true)
+            .build();
+        context
+            .tr_sess_set_attributes(
+                audit_auth_session,
+                session_attributes,
+                session_attributes_mask,
+            )
+            .expect("Failed to set attributes on session");
+
+        // Audit a command using the session
+        let _ = context
+            .execute_with_session(Some(audit_auth_session), |ctx| ctx.get_random(8))
+            .expect("Failed to get random bytes");
+
+        let audit_session = HmacSession::try_from(audit_auth_session)
+            .expect("Failed to convert auth session into hmac session");
+        let result = context.execute_with_sessions(
+            (
+                Some(AuthSession::Password),
+                Some(AuthSession::Password),
+                None,
+            ),
+            |ctx| {
+                ctx.get_session_audit_digest(
+                    sign_key_handle,
+                    audit_session,
+                    Data::default(),
+                    SignatureScheme::Null,
+                )
+            },
+        );
+
+        context
+            .flush_context(SessionHandle::from(audit_auth_session).into())
+            .expect("Failed to flush audit session");
+
+        let (attest, _signature) = result.expect("Failed to get session audit digest");
+        assert_eq!(attest.attestation_type(), AttestationType::SessionAudit);
+        match attest.attested() {
+            AttestInfo::SessionAudit { info } => {
+                assert_eq!(info.session_digest().len(), 32);
+            }
+            _ => {
+                panic!("Attested did not contain the expected variant.")
+            }
+        }
+    }
+}
+
+#[cfg(has_esys_certify_x509)]
+mod test_certify_x509 {
+    use crate::common::{create_ctx_without_session, signing_key_pub};
+    use picky_asn1::date::UTCTime;
+    use picky_asn1_x509::{Extension, Extensions, KeyUsage, Name, Validity};
+    use tss_esapi::{
+        abstraction::x509::PartialCertificate,
+        interface_types::{
+            algorithm::SignatureSchemeAlgorithm, resource_handles::Hierarchy,
+            session_handles::AuthSession,
+        },
+        structures::SignatureScheme,
+    };
+
+    #[test]
+    fn certify_x509() {
+        let mut context = create_ctx_without_session();
+
+        let sign_key_handle = context
+            .execute_with_nullauth_session(|ctx| {
+                ctx.create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+            })
+            .unwrap()
+            .key_handle;
+
+        let mut key_usage = KeyUsage::new(1);
+        key_usage.set_digital_signature(true);
+        let partial_certificate = PartialCertificate {
+            issuer: Name::new_common_name("TPM CA"),
+            validity: Validity {
+                not_before: UTCTime::new(2022, 1, 1, 0, 0, 0)
+                    .expect("Failed to create date")
+                    .into(),
+                not_after: UTCTime::new(2032, 1, 1, 0, 0, 0)
+                    .expect("Failed to create date")
+                    .into(),
+            },
+            subject: Name::new_common_name("TPM key"),
+            extensions: Extensions(vec![Extension::new_key_usage(key_usage)]),
+        }
+        .to_der()
+        .expect("Failed to encode the partial certificate");
+
+        let (added_to_certificate, tbs_digest, signature) = context
+            .execute_with_sessions(
+                (
+                    Some(AuthSession::Password),
+                    Some(AuthSession::Password),
+                    None,
+                ),
+                |ctx| {
+                    ctx.certify_x509(
+                        sign_key_handle.into(),
+                        sign_key_handle,
+                        SignatureScheme::Null,
+                        partial_certificate,
+                    )
+                },
+            )
+            .expect("Failed to certify X.509");
+
+        // The added fields are a DER encoded SEQUENCE.
+        assert_eq!(added_to_certificate.as_bytes()[0], 0x30);
+        assert_eq!(tbs_digest.len(), 32);
+        assert_eq!(signature.algorithm(), SignatureSchemeAlgorithm::RsaSsa);
+    }
 }
@@ -1,2 +1,43 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+#[cfg(all(has_esys_act_set_timeout, has_tpms_act_data))]
+mod test_act_set_timeout {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::{
+        constants::CapabilityType,
+        handles::PermanentTpmHandle,
+        interface_types::{resource_handles::Act, session_handles::AuthSession},
+        structures::CapabilityData,
+    };
+
+    #[test]
+    fn test_act_set_timeout() {
+        let mut context = create_ctx_without_session();
+        let act = Act::new(0).expect("Failed to create Act");
+
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.act_set_timeout(act, 60)
+            })
+            .expect("Failed to set ACT timeout");
+
+        let (capability_data, _) = context
+            .get_capability(CapabilityType::Act, PermanentTpmHandle::from(act).into(), 1)
+            .expect("Failed to get ACT capability");
+        if let CapabilityData::ActData(act_data_list) = capability_data {
+            let act_data = act_data_list
+                .find(act)
+                .expect("The ACT data was not returned");
+            assert!(act_data.timeout() <= 60);
+            assert!(!act_data.attributes().signaled());
+        } else {
+            panic!("Got wrong type of capability data: {:?}", capability_data);
+        }
+
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.act_set_timeout(act, 0)
+            })
+            .expect("Failed to stop ACT");
+    }
+}
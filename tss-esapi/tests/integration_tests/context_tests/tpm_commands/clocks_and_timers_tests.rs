@@ -1,2 +1,83 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_read_clock {
+    use crate::common::create_ctx_without_session;
+
+    #[test]
+    fn test_read_clock() {
+        let mut context = create_ctx_without_session();
+        let first = context.read_clock().expect("Failed to read clock");
+        let second = context.read_clock().expect("Failed to read clock");
+        assert!(first.clock_info().clock() <= second.clock_info().clock());
+        assert_eq!(
+            first.clock_info().reset_count(),
+            second.clock_info().reset_count()
+        );
+        assert_eq!(
+            first.clock_info().restart_count(),
+            second.clock_info().restart_count()
+        );
+    }
+}
+
+mod test_clock_set {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::interface_types::{resource_handles::Provision, session_handles::AuthSession};
+
+    #[test]
+    fn test_clock_set() {
+        let mut context = create_ctx_without_session();
+        let current_clock = context
+            .read_clock()
+            .expect("Failed to read clock")
+            .clock_info()
+            .clock();
+        let new_clock = current_clock + 1000000;
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.clock_set(Provision::Owner, new_clock)
+            })
+            .expect("Failed to set clock");
+        let clock = context
+            .read_clock()
+            .expect("Failed to read clock")
+            .clock_info()
+            .clock();
+        assert!(clock >= new_clock);
+    }
+
+    #[test]
+    fn test_clock_set_backwards() {
+        let mut context = create_ctx_without_session();
+        let current_clock = context
+            .read_clock()
+            .expect("Failed to read clock")
+            .clock_info()
+            .clock();
+        let _ = context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.clock_set(Provision::Owner, current_clock / 2)
+            })
+            .expect_err("Setting the clock backwards did not fail");
+    }
+}
+
+mod test_clock_rate_adjust {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::{
+        constants::ClockAdjust,
+        interface_types::{resource_handles::Provision, session_handles::AuthSession},
+    };
+
+    #[test]
+    fn test_clock_rate_adjust() {
+        let mut context = create_ctx_without_session();
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.clock_rate_adjust(Provision::Owner, ClockAdjust::FineFaster)?;
+                ctx.clock_rate_adjust(Provision::Owner, ClockAdjust::FineSlower)?;
+                ctx.clock_rate_adjust(Provision::Owner, ClockAdjust::NoChange)
+            })
+            .expect("Failed to adjust clock rate");
+    }
+}
@@ -1,2 +1,56 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_set_command_code_audit_status {
+    use crate::common::create_ctx_without_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::{tss::TPM2_CC_FIRST, CapabilityType, CommandCode},
+        interface_types::{
+            algorithm::HashingAlgorithm, resource_handles::Provision, session_handles::AuthSession,
+        },
+        structures::{CapabilityData, CommandCodeList},
+    };
+
+    fn audited_commands(context: &mut tss_esapi::Context) -> Vec<CommandCode> {
+        let (capability_data, _) = context
+            .get_capability(CapabilityType::AuditCommands, TPM2_CC_FIRST, 80)
+            .expect("Failed to get audited commands");
+        match capability_data {
+            CapabilityData::AuditCommands(command_code_list) => command_code_list.into_inner(),
+            _ => panic!("Unexpected capability data"),
+        }
+    }
+
+    #[test]
+    fn test_set_and_clear_audit_status() {
+        let mut context = create_ctx_without_session();
+        let command_code_list = CommandCodeList::try_from(vec![CommandCode::GetRandom]).unwrap();
+
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.set_command_code_audit_status(
+                    Provision::Owner,
+                    HashingAlgorithm::Null,
+                    command_code_list.clone(),
+                    CommandCodeList::new(),
+                )
+            })
+            .expect("Failed to add command to the audit list");
+        assert!(audited_commands(&mut context).contains(&CommandCode::GetRandom));
+
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.set_command_code_audit_status(
+                    Provision::Owner,
+                    HashingAlgorithm::Null,
+                    CommandCodeList::new(),
+                    command_code_list,
+                )
+            })
+            .expect("Failed to remove command from the audit list");
+        let audited_commands = audited_commands(&mut context);
+        assert!(!audited_commands.contains(&CommandCode::GetRandom));
+        // TPM2_SetCommandCodeAuditStatus is always audited.
+        assert!(audited_commands.contains(&CommandCode::SetCommandCodeAuditStatus));
+    }
+}
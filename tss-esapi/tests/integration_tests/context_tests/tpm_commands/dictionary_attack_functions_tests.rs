@@ -1,2 +1,52 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_dictionary_attack_lock_reset {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::interface_types::{resource_handles::Lockout, session_handles::AuthSession};
+
+    #[test]
+    fn test_dictionary_attack_lock_reset() {
+        let mut context = create_ctx_without_session();
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.dictionary_attack_lock_reset(Lockout::Lockout)
+            })
+            .expect("Failed to reset dictionary attack lockout");
+    }
+}
+
+mod test_dictionary_attack_parameters {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::{
+        abstraction::dictionary_attack::DaStatus,
+        interface_types::{resource_handles::Lockout, session_handles::AuthSession},
+    };
+
+    #[test]
+    fn test_dictionary_attack_parameters() {
+        let mut context = create_ctx_without_session();
+        let original = DaStatus::read(&mut context).expect("Failed to read DA status");
+
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.dictionary_attack_parameters(Lockout::Lockout, 10, 20, 30)
+            })
+            .expect("Failed to set dictionary attack parameters");
+        let updated = DaStatus::read(&mut context).expect("Failed to read DA status");
+
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.dictionary_attack_parameters(
+                    Lockout::Lockout,
+                    original.max_tries(),
+                    original.recovery_time(),
+                    original.lockout_recovery(),
+                )
+            })
+            .expect("Failed to restore dictionary attack parameters");
+
+        assert_eq!(10, updated.max_tries());
+        assert_eq!(20, updated.recovery_time());
+        assert_eq!(30, updated.lockout_recovery());
+    }
+}
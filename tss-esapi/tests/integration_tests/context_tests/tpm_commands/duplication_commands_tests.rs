@@ -303,3 +303,228 @@ mod test_duplicate {
         eprintln!("P: {:?}", private);
     }
 }
+
+mod test_rewrap {
+    use crate::common::{create_ctx_with_session, create_ctx_without_session, decryption_key_pub};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::{ObjectAttributesBuilder, SessionAttributesBuilder},
+        constants::{CommandCode, SessionType},
+        handles::ObjectHandle,
+        interface_types::{
+            algorithm::{HashingAlgorithm, PublicAlgorithm},
+            ecc::EccCurve,
+            resource_handles::Hierarchy,
+            session_handles::{AuthSession, PolicySession},
+        },
+        structures::{
+            Digest, EccPoint, EccScheme, KeyDerivationFunctionScheme, Public, PublicBuilder,
+            PublicEccParametersBuilder, SymmetricDefinition, SymmetricDefinitionObject,
+        },
+        Context,
+    };
+
+    fn storage_parent_pub() -> Public {
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_fixed_tpm(true)
+            .with_fixed_parent(true)
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .with_decrypt(true)
+            .with_restricted(true)
+            .build()
+            .expect("Attributes to be valid");
+        PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::Ecc)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_ecc_parameters(
+                PublicEccParametersBuilder::new_restricted_decryption_key(
+                    SymmetricDefinitionObject::AES_128_CFB,
+                    EccCurve::NistP256,
+                )
+                .build()
+                .expect("Params to be valid"),
+            )
+            .with_ecc_unique_identifier(EccPoint::default())
+            .build()
+            .expect("public to be valid")
+    }
+
+    fn duplicable_key_pub(auth_policy: Digest) -> Public {
+        // Fixed TPM and Fixed Parent should be "false" for an object
+        // to be eligible for duplication
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_fixed_tpm(false)
+            .with_fixed_parent(false)
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .with_decrypt(true)
+            .with_sign_encrypt(true)
+            .with_restricted(false)
+            .build()
+            .expect("Attributes to be valid");
+        PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::Ecc)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_auth_policy(auth_policy)
+            .with_ecc_parameters(
+                PublicEccParametersBuilder::new()
+                    .with_ecc_scheme(EccScheme::Null)
+                    .with_curve(EccCurve::NistP256)
+                    .with_is_signing_key(false)
+                    .with_is_decryption_key(true)
+                    .with_restricted(false)
+                    .with_key_derivation_function_scheme(KeyDerivationFunctionScheme::Null)
+                    .build()
+                    .expect("Params to be valid"),
+            )
+            .with_ecc_unique_identifier(EccPoint::default())
+            .build()
+            .expect("public to be valid")
+    }
+
+    fn start_policy_session(context: &mut Context, session_type: SessionType) -> PolicySession {
+        let session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                session_type,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let (session_attributes, session_attributes_mask) = SessionAttributesBuilder::new()
+            .with_decrypt(true)
+            .with_encrypt(true)
+            .build();
+        context
+            .tr_sess_set_attributes(session, session_attributes, session_attributes_mask)
+            .expect("tr_sess_set_attributes call failed");
+        let policy_session =
+            PolicySession::try_from(session).expect("Failed to convert into policy session");
+        context
+            .policy_command_code(policy_session, CommandCode::Duplicate)
+            .expect("Policy command code");
+        policy_session
+    }
+
+    #[test]
+    fn test_duplicate_rewrap_and_import() {
+        // Compute a policy that allows the key to be duplicated.
+        let mut context = create_ctx_without_session();
+        let trial_session = start_policy_session(&mut context, SessionType::Trial);
+        let digest = context
+            .policy_get_digest(trial_session)
+            .expect("Could retrieve digest");
+        drop(context);
+
+        let mut context = create_ctx_with_session();
+        let hmac_session = context.sessions().0;
+
+        // The original parent, the intermediate parent that the escrow service
+        // holds the duplicate under and the final parent that the key is rewrapped for.
+        let original_parent = context
+            .create_primary(
+                Hierarchy::Owner,
+                storage_parent_pub(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .expect("Failed to create original parent")
+            .key_handle;
+        let intermediate_parent: ObjectHandle = context
+            .create_primary(
+                Hierarchy::Endorsement,
+                storage_parent_pub(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .expect("Failed to create intermediate parent")
+            .key_handle
+            .into();
+        let final_parent: ObjectHandle = context
+            .create_primary(
+                Hierarchy::Owner,
+                decryption_key_pub(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .expect("Failed to create final parent")
+            .key_handle
+            .into();
+
+        let result = context
+            .create(
+                original_parent,
+                duplicable_key_pub(digest),
+                None,
+                None,
+                None,
+                None,
+            )
+            .expect("Failed to create key");
+        let key_public = result.out_public;
+        let key_handle: ObjectHandle = context
+            .load(original_parent, result.out_private, key_public.clone())
+            .expect("Failed to load key")
+            .into();
+        let key_name = context
+            .tr_get_name(key_handle)
+            .expect("Failed to get name of key");
+
+        // Duplicate the key to the intermediate parent.
+        let policy_session = start_policy_session(&mut context, SessionType::Policy);
+        let (_, duplicate, sym_seed) = context
+            .execute_with_session(Some(AuthSession::from(policy_session)), |ctx| {
+                ctx.duplicate(
+                    key_handle,
+                    intermediate_parent,
+                    None,
+                    SymmetricDefinitionObject::Null,
+                )
+            })
+            .expect("Failed to duplicate key");
+
+        // Rewrap the duplicate for the final parent.
+        let (rewrapped_duplicate, rewrapped_sym_seed) = context
+            .execute_with_session(hmac_session, |ctx| {
+                ctx.rewrap(
+                    intermediate_parent,
+                    final_parent,
+                    duplicate,
+                    key_name,
+                    sym_seed,
+                )
+            })
+            .expect("Failed to rewrap duplicate");
+
+        // Import and load the key under the final parent.
+        let private = context
+            .execute_with_session(hmac_session, |ctx| {
+                ctx.import(
+                    final_parent,
+                    None,
+                    key_public.clone(),
+                    rewrapped_duplicate,
+                    rewrapped_sym_seed,
+                    SymmetricDefinitionObject::Null,
+                )
+            })
+            .expect("Failed to import rewrapped duplicate");
+        let _ = context
+            .execute_with_session(hmac_session, |ctx| {
+                ctx.load(final_parent.into(), private, key_public)
+            })
+            .expect("Failed to load imported key");
+    }
+}
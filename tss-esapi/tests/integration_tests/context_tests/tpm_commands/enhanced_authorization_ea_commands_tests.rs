@@ -141,6 +141,96 @@ mod test_policy_secret {
     }
 }
 
+mod test_policy_ticket {
+    use crate::common::create_ctx_with_session;
+    use std::{convert::TryFrom, time::Duration};
+    use tss_esapi::{
+        constants::{SessionType, StructureTag},
+        handles::{AuthHandle, ObjectHandle},
+        interface_types::{algorithm::HashingAlgorithm, session_handles::PolicySession},
+        structures::{Digest, Nonce, SymmetricDefinition, Ticket},
+        Error, WrapperErrorKind,
+    };
+
+    fn start_policy_session(context: &mut tss_esapi::Context) -> PolicySession {
+        let policy_auth_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Policy,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        PolicySession::try_from(policy_auth_session)
+            .expect("Failed to convert auth session into policy session")
+    }
+
+    #[test]
+    fn test_policy_ticket() {
+        let mut context = create_ctx_with_session();
+        let cp_hash_a = Digest::default();
+        let policy_ref = Nonce::try_from(vec![1, 2, 3]).unwrap();
+
+        let first_policy_session = start_policy_session(&mut context);
+        let (timeout, ticket) = context
+            .policy_secret_with_ticket(
+                first_policy_session,
+                AuthHandle::Owner,
+                Nonce::default(),
+                cp_hash_a.clone(),
+                policy_ref.clone(),
+                Duration::from_secs(3600),
+            )
+            .expect("Call to policy_secret_with_ticket failed");
+        assert_eq!(ticket.tag(), StructureTag::AuthSecret);
+        let expected_policy_digest = context
+            .execute_without_session(|ctx| ctx.policy_get_digest(first_policy_session))
+            .expect("Call to policy_get_digest failed");
+
+        let auth_name = context
+            .tr_get_name(ObjectHandle::from(AuthHandle::Owner))
+            .expect("Failed to get name of the owner hierarchy");
+        let second_policy_session = start_policy_session(&mut context);
+        let actual_policy_digest = context
+            .execute_without_session(|ctx| {
+                ctx.policy_ticket(
+                    second_policy_session,
+                    timeout,
+                    cp_hash_a,
+                    policy_ref,
+                    auth_name,
+                    ticket,
+                )?;
+                ctx.policy_get_digest(second_policy_session)
+            })
+            .expect("Failed to redeem ticket");
+
+        assert_eq!(expected_policy_digest, actual_policy_digest);
+    }
+
+    #[test]
+    fn test_policy_ticket_zero_expiration() {
+        let mut context = create_ctx_with_session();
+        let policy_session = start_policy_session(&mut context);
+        assert_eq!(
+            context
+                .policy_secret_with_ticket(
+                    policy_session,
+                    AuthHandle::Owner,
+                    Nonce::default(),
+                    Digest::default(),
+                    Nonce::default(),
+                    Duration::from_millis(500),
+                )
+                .unwrap_err(),
+            Error::WrapperError(WrapperErrorKind::InvalidParam)
+        );
+    }
+}
+
 mod test_policy_or {
     use crate::common::{create_ctx_without_session, get_pcr_policy_digest};
     use std::convert::TryFrom;
@@ -897,3 +987,276 @@ mod test_policy_template {
         assert_eq!(expected_policy_template, policy_digest);
     }
 }
+
+mod test_policy_nv {
+    use crate::common::create_ctx_without_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        constants::{ArithmeticOperation, NvIndexType, SessionType},
+        handles::{NvIndexTpmHandle, SessionHandle},
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{NvAuth, Provision},
+            session_handles::{AuthSession, PolicySession},
+        },
+        structures::{NvPublicBuilder, Operand, SymmetricDefinition},
+    };
+
+    #[test]
+    fn test_policy_nv() {
+        let mut context = create_ctx_without_session();
+        let nv_index = NvIndexTpmHandle::new(0x01500022).unwrap();
+
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .with_nv_index_type(NvIndexType::Counter)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(8)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+
+        let owner_nv_index_handle = context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.nv_define_space(Provision::Owner, None, owner_nv_public)
+            })
+            .expect("Call to nv_define_space failed");
+
+        let policy_auth_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Policy,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let policy_session = PolicySession::try_from(policy_auth_session)
+            .expect("Failed to convert auth session into policy session");
+
+        let result = context.execute_with_session(
+            Some(AuthSession::Password),
+            |ctx| -> tss_esapi::Result<tss_esapi::Result<()>> {
+                ctx.nv_increment(NvAuth::Owner, owner_nv_index_handle)?;
+                let counter_value = ctx.nv_read(NvAuth::Owner, owner_nv_index_handle, 8, 0)?;
+                let operand_b = Operand::try_from(counter_value.to_vec())?;
+                // The counter is greater than or equal to the value that was read.
+                ctx.policy_nv(
+                    policy_session,
+                    NvAuth::Owner,
+                    owner_nv_index_handle,
+                    operand_b.clone(),
+                    0,
+                    ArithmeticOperation::UnsignedGe,
+                )?;
+                // The counter is not greater than the value that was read.
+                Ok(ctx.policy_nv(
+                    policy_session,
+                    NvAuth::Owner,
+                    owner_nv_index_handle,
+                    operand_b,
+                    0,
+                    ArithmeticOperation::UnsignedGt,
+                ))
+            },
+        );
+
+        context
+            .flush_context(SessionHandle::from(policy_auth_session).into())
+            .expect("Failed to flush policy session");
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.nv_undefine_space(Provision::Owner, owner_nv_index_handle)
+            })
+            .expect("Call to nv_undefine_space failed");
+
+        let _ = result
+            .expect("Failed to evaluate policy NV with unsigned GE")
+            .expect_err("Call to policy_nv with unsigned GT did not fail as expected");
+    }
+}
+
+mod test_policy_counter_timer {
+    use crate::common::create_ctx_without_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::{ArithmeticOperation, SessionType},
+        handles::SessionHandle,
+        interface_types::{algorithm::HashingAlgorithm, session_handles::PolicySession},
+        structures::{Operand, SymmetricDefinition},
+    };
+
+    // Offset of the clock field in the marshalled TPMS_TIME_INFO.
+    const CLOCK_OFFSET: u16 = 8;
+
+    #[test]
+    fn test_policy_counter_timer() {
+        let mut context = create_ctx_without_session();
+        let policy_auth_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Policy,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let policy_session = PolicySession::try_from(policy_auth_session)
+            .expect("Failed to convert auth session into policy session");
+
+        let deadline =
+            Operand::try_from(u64::MAX.to_be_bytes().to_vec()).expect("Failed to create operand");
+
+        // The TPM clock should be before the deadline.
+        context
+            .policy_counter_timer(
+                policy_session,
+                deadline.clone(),
+                CLOCK_OFFSET,
+                ArithmeticOperation::UnsignedLt,
+            )
+            .expect("Call to policy_counter_timer failed");
+
+        // The TPM clock cannot be after the deadline.
+        let _ = context
+            .policy_counter_timer(
+                policy_session,
+                deadline,
+                CLOCK_OFFSET,
+                ArithmeticOperation::UnsignedGe,
+            )
+            .expect_err("Call to policy_counter_timer did not fail as expected");
+
+        context
+            .flush_context(SessionHandle::from(policy_auth_session).into())
+            .expect("Failed to flush policy session");
+    }
+}
+
+mod test_policy_authorize_nv {
+    use crate::common::create_ctx_without_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        constants::{tss::TPM2_ALG_SHA256, CommandCode, SessionType},
+        handles::{NvIndexTpmHandle, SessionHandle},
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{NvAuth, Provision},
+            session_handles::{AuthSession, PolicySession},
+        },
+        structures::{MaxNvBuffer, NvPublicBuilder, SymmetricDefinition},
+    };
+
+    #[test]
+    fn test_policy_authorize_nv() {
+        let mut context = create_ctx_without_session();
+
+        // Calculate the policy digest that is going to be stored in the NV index.
+        let trial_policy_auth_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Trial,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let trial_policy_session = PolicySession::try_from(trial_policy_auth_session)
+            .expect("Failed to convert auth session into policy session");
+        context
+            .policy_command_code(trial_policy_session, CommandCode::Unseal)
+            .expect("Call to policy_command_code failed");
+        let authorized_policy = context
+            .policy_get_digest(trial_policy_session)
+            .expect("Call to policy_get_digest failed");
+        context
+            .flush_context(SessionHandle::from(trial_policy_auth_session).into())
+            .expect("Failed to flush trial session");
+
+        // The NV index contains a marshalled TPMT_HA.
+        let mut nv_data = TPM2_ALG_SHA256.to_be_bytes().to_vec();
+        nv_data.extend_from_slice(authorized_policy.as_bytes());
+
+        let nv_index = NvIndexTpmHandle::new(0x01500023).unwrap();
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(nv_data.len())
+            .build()
+            .expect("Failed to build NvPublic for owner");
+        let owner_nv_index_handle = context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.nv_define_space(Provision::Owner, None, owner_nv_public)
+            })
+            .expect("Call to nv_define_space failed");
+
+        let policy_auth_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Policy,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let policy_session = PolicySession::try_from(policy_auth_session)
+            .expect("Failed to convert auth session into policy session");
+
+        let nv_write_result = context.execute_with_session(Some(AuthSession::Password), |ctx| {
+            ctx.nv_write(
+                NvAuth::Owner,
+                owner_nv_index_handle,
+                MaxNvBuffer::try_from(nv_data)?,
+                0,
+            )
+        });
+        // The policy digest does not match the authorized policy yet.
+        let policy_authorize_nv_failure = context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.policy_authorize_nv(policy_session, NvAuth::Owner, owner_nv_index_handle)
+            });
+        let policy_command_code_result =
+            context.policy_command_code(policy_session, CommandCode::Unseal);
+        let policy_authorize_nv_result = context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.policy_authorize_nv(policy_session, NvAuth::Owner, owner_nv_index_handle)
+            });
+
+        context
+            .flush_context(SessionHandle::from(policy_auth_session).into())
+            .expect("Failed to flush policy session");
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.nv_undefine_space(Provision::Owner, owner_nv_index_handle)
+            })
+            .expect("Call to nv_undefine_space failed");
+
+        nv_write_result.expect("Call to nv_write failed");
+        let _ = policy_authorize_nv_failure
+            .expect_err("Call to policy_authorize_nv did not fail as expected");
+        policy_command_code_result.expect("Call to policy_command_code failed");
+        policy_authorize_nv_result.expect("Call to policy_authorize_nv failed");
+    }
+}
@@ -1,2 +1,110 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_commit {
+    use crate::common::create_ctx_with_session;
+    use std::convert::{TryFrom, TryInto};
+    use tss_esapi::{
+        attributes::ObjectAttributesBuilder,
+        constants::tss::{TPM2_RH_NULL, TPM2_ST_HASHCHECK},
+        interface_types::{
+            algorithm::{HashingAlgorithm, PublicAlgorithm},
+            ecc::EccCurve,
+            resource_handles::Hierarchy,
+        },
+        structures::{
+            Digest, EcDaaScheme, EccPoint, EccScheme, KeyDerivationFunctionScheme, PublicBuilder,
+            PublicEccParametersBuilder, Signature, SignatureScheme,
+        },
+        tss2_esys::TPMT_TK_HASHCHECK,
+    };
+
+    #[test]
+    fn test_commit_and_sign_ecdaa() {
+        let mut context = create_ctx_with_session();
+        let ecc_parameters = PublicEccParametersBuilder::new()
+            .with_ecc_scheme(EccScheme::EcDaa(EcDaaScheme::new(
+                HashingAlgorithm::Sha256,
+                0,
+            )))
+            .with_curve(EccCurve::BnP256)
+            .with_is_signing_key(true)
+            .with_is_decryption_key(false)
+            .with_restricted(false)
+            .with_key_derivation_function_scheme(KeyDerivationFunctionScheme::Null)
+            .build()
+            .unwrap();
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_fixed_tpm(true)
+            .with_fixed_parent(true)
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .with_sign_encrypt(true)
+            .build()
+            .unwrap();
+        let public = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::Ecc)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_ecc_parameters(ecc_parameters)
+            .with_ecc_unique_identifier(EccPoint::default())
+            .build()
+            .unwrap();
+        let key_handle = context
+            .create_primary(Hierarchy::Owner, public, None, None, None, None)
+            .unwrap()
+            .key_handle;
+
+        let (k, l, e, counter) = context.commit(key_handle, None, None, None).unwrap();
+        assert!(k.x().is_empty());
+        assert!(l.x().is_empty());
+        assert!(!e.x().is_empty());
+
+        let validation = TPMT_TK_HASHCHECK {
+            tag: TPM2_ST_HASHCHECK,
+            hierarchy: TPM2_RH_NULL,
+            digest: Default::default(),
+        };
+        let signature = context
+            .sign(
+                key_handle,
+                Digest::try_from(vec![0xAB; 32]).unwrap(),
+                SignatureScheme::try_from(EccScheme::EcDaa(EcDaaScheme::new(
+                    HashingAlgorithm::Sha256,
+                    counter,
+                )))
+                .unwrap(),
+                validation.try_into().unwrap(),
+            )
+            .unwrap();
+        assert!(matches!(signature, Signature::EcDaa(_)));
+
+        // The commit value can only be used once.
+        let _ = context
+            .sign(
+                key_handle,
+                Digest::try_from(vec![0xAB; 32]).unwrap(),
+                SignatureScheme::try_from(EccScheme::EcDaa(EcDaaScheme::new(
+                    HashingAlgorithm::Sha256,
+                    counter,
+                )))
+                .unwrap(),
+                validation.try_into().unwrap(),
+            )
+            .unwrap_err();
+    }
+}
+
+mod test_ec_ephemeral {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::interface_types::ecc::EccCurve;
+
+    #[test]
+    fn test_ec_ephemeral() {
+        let mut context = create_ctx_without_session();
+        let (first_point, first_counter) = context.ec_ephemeral(EccCurve::NistP256).unwrap();
+        let (second_point, second_counter) = context.ec_ephemeral(EccCurve::NistP256).unwrap();
+        assert_eq!(first_point.x().len(), 32);
+        assert_ne!(first_counter, second_counter);
+        assert_ne!(first_point.x().as_bytes(), second_point.x().as_bytes());
+    }
+}
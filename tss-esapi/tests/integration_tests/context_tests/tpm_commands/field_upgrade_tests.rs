@@ -1,2 +1,94 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_field_upgrade_start {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::TpmFormatZeroError,
+        error::{ReturnCode, TpmFormatZeroResponseCode, TpmResponseCode},
+        handles::KeyHandle,
+        interface_types::{algorithm::HashingAlgorithm, resource_handles::Platform},
+        structures::{Digest, PublicKeyRsa, RsaSignature, Signature},
+        Error,
+    };
+
+    #[test]
+    fn test_field_upgrade_start_unimplemented() {
+        let mut context = create_ctx_with_session();
+        let signature = Signature::RsaSsa(
+            RsaSignature::create(
+                HashingAlgorithm::Sha256,
+                PublicKeyRsa::try_from(vec![0xff; 256]).expect("Failed to create signature"),
+            )
+            .expect("Failed to create RSA signature"),
+        );
+        let error = context
+            .field_upgrade_start(
+                Platform::Platform,
+                KeyHandle::Null,
+                Digest::try_from(vec![0xff; 32]).expect("Failed to create digest"),
+                signature,
+            )
+            .expect_err("Field upgrade started unexpectedly");
+        if let Error::TssError(ReturnCode::Tpm(TpmResponseCode::FormatZero(
+            TpmFormatZeroResponseCode::Error(error),
+        ))) = error
+        {
+            assert_eq!(error.error_number(), TpmFormatZeroError::CommandCode);
+        } else {
+            panic!("Unexpected error: {}", error);
+        }
+    }
+}
+
+mod test_field_upgrade_data {
+    use crate::common::create_ctx_without_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::TpmFormatZeroError,
+        error::{ReturnCode, TpmFormatZeroResponseCode, TpmResponseCode},
+        structures::MaxBuffer,
+        Error,
+    };
+
+    #[test]
+    fn test_field_upgrade_data_unimplemented() {
+        let mut context = create_ctx_without_session();
+        let error = context
+            .field_upgrade_data(MaxBuffer::try_from(vec![0xff; 32]).unwrap())
+            .expect_err("Field upgrade data accepted unexpectedly");
+        if let Error::TssError(ReturnCode::Tpm(TpmResponseCode::FormatZero(
+            TpmFormatZeroResponseCode::Error(error),
+        ))) = error
+        {
+            assert_eq!(error.error_number(), TpmFormatZeroError::CommandCode);
+        } else {
+            panic!("Unexpected error: {}", error);
+        }
+    }
+}
+
+mod test_firmware_read {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::{
+        constants::TpmFormatZeroError,
+        error::{ReturnCode, TpmFormatZeroResponseCode, TpmResponseCode},
+        Error,
+    };
+
+    #[test]
+    fn test_firmware_read_unimplemented() {
+        let mut context = create_ctx_without_session();
+        let error = context
+            .firmware_read(0)
+            .expect_err("Firmware read succeeded unexpectedly");
+        if let Error::TssError(ReturnCode::Tpm(TpmResponseCode::FormatZero(
+            TpmFormatZeroResponseCode::Error(error),
+        ))) = error
+        {
+            assert_eq!(error.error_number(), TpmFormatZeroError::CommandCode);
+        } else {
+            panic!("Unexpected error: {}", error);
+        }
+    }
+}
@@ -1,2 +1,183 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+
+mod test_hash_sequence {
+    use crate::common::create_ctx_without_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        interface_types::{
+            algorithm::HashingAlgorithm, resource_handles::Hierarchy, session_handles::AuthSession,
+        },
+        structures::{Auth, MaxBuffer, Ticket},
+    };
+
+    #[test]
+    fn test_hash_sequence_matches_hash() {
+        let mut context = create_ctx_without_session();
+        let data = "There is no spoon".as_bytes();
+
+        let (expected_digest, _) = context
+            .hash(
+                MaxBuffer::try_from(data.to_vec()).unwrap(),
+                HashingAlgorithm::Sha256,
+                Hierarchy::Owner,
+            )
+            .unwrap();
+
+        let sequence_handle = context
+            .hash_sequence_start(HashingAlgorithm::Sha256, None)
+            .unwrap();
+        let (digest, ticket) = context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.sequence_update(
+                    sequence_handle,
+                    MaxBuffer::try_from(data[..5].to_vec()).unwrap(),
+                )?;
+                ctx.sequence_complete(
+                    sequence_handle,
+                    MaxBuffer::try_from(data[5..].to_vec()).unwrap(),
+                    Hierarchy::Owner,
+                )
+            })
+            .unwrap();
+
+        assert_eq!(expected_digest, digest);
+        assert_eq!(ticket.hierarchy(), Hierarchy::Owner);
+    }
+
+    #[test]
+    fn test_hash_sequence_with_auth() {
+        let mut context = create_ctx_without_session();
+        let auth = Auth::try_from(vec![1, 2, 3, 4]).unwrap();
+
+        let sequence_handle = context
+            .hash_sequence_start(HashingAlgorithm::Sha256, Some(auth.clone()))
+            .unwrap();
+        context
+            .tr_set_auth(sequence_handle, auth)
+            .expect("Failed to set auth on sequence handle");
+        let _ = context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.sequence_complete(sequence_handle, MaxBuffer::default(), Hierarchy::Null)
+            })
+            .unwrap();
+    }
+}
+
+mod test_hmac_sequence {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::ObjectAttributesBuilder,
+        interface_types::{
+            algorithm::{HashingAlgorithm, PublicAlgorithm},
+            resource_handles::Hierarchy,
+        },
+        structures::{KeyedHashScheme, MaxBuffer, PublicBuilder, PublicKeyedHashParameters},
+    };
+
+    #[test]
+    fn test_hmac_sequence_matches_hmac() {
+        let mut context = create_ctx_with_session();
+
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_sign_encrypt(true)
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .build()
+            .expect("Failed to build object attributes");
+
+        let key_pub = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::KeyedHash)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_keyed_hash_parameters(PublicKeyedHashParameters::new(
+                KeyedHashScheme::HMAC_SHA_256,
+            ))
+            .with_keyed_hash_unique_identifier(Default::default())
+            .build()
+            .expect("Failed to build public structure for key.");
+
+        let key_handle = context
+            .create_primary(Hierarchy::Owner, key_pub, None, None, None, None)
+            .unwrap()
+            .key_handle;
+
+        let data = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let expected_hmac = context
+            .hmac(
+                key_handle.into(),
+                MaxBuffer::try_from(data.clone()).unwrap(),
+                HashingAlgorithm::Sha256,
+            )
+            .unwrap();
+
+        let sequence_handle = context
+            .hmac_start(key_handle.into(), HashingAlgorithm::Sha256, None)
+            .unwrap();
+        context
+            .sequence_update(
+                sequence_handle,
+                MaxBuffer::try_from(data[..3].to_vec()).unwrap(),
+            )
+            .unwrap();
+        let (hmac, _) = context
+            .sequence_complete(
+                sequence_handle,
+                MaxBuffer::try_from(data[3..].to_vec()).unwrap(),
+                Hierarchy::Null,
+            )
+            .unwrap();
+
+        assert_eq!(expected_hmac, hmac);
+    }
+}
+
+mod test_event_sequence {
+    use crate::common::create_ctx_without_session;
+    use sha2::{Digest as _, Sha256};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        handles::PcrHandle,
+        interface_types::{algorithm::HashingAlgorithm, session_handles::AuthSession},
+        structures::MaxBuffer,
+    };
+
+    #[test]
+    fn test_event_sequence_complete() {
+        let mut context = create_ctx_without_session();
+        let data = "There is no spoon".as_bytes();
+
+        let sequence_handle = context
+            .hash_sequence_start(HashingAlgorithm::Null, None)
+            .unwrap();
+        let digests = context
+            .execute_with_sessions(
+                (
+                    Some(AuthSession::Password),
+                    Some(AuthSession::Password),
+                    None,
+                ),
+                |ctx| {
+                    ctx.sequence_update(
+                        sequence_handle,
+                        MaxBuffer::try_from(data[..5].to_vec()).unwrap(),
+                    )?;
+                    ctx.event_sequence_complete(
+                        PcrHandle::Pcr16,
+                        sequence_handle,
+                        MaxBuffer::try_from(data[5..].to_vec()).unwrap(),
+                    )
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            digests
+                .get(HashingAlgorithm::Sha256)
+                .expect("Missing digest for the sha256 bank")
+                .as_bytes(),
+            Sha256::digest(data).as_slice()
+        );
+    }
+}
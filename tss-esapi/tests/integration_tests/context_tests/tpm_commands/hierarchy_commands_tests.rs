@@ -123,3 +123,136 @@ mod test_change_auth {
             .unwrap();
     }
 }
+
+mod test_hierarchy_control {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::{
+        constants::{
+            tss::{
+                TPM2_PT_STARTUP_CLEAR, TPMA_STARTUP_CLEAR_EHENABLE, TPMA_STARTUP_CLEAR_PHENABLENV,
+                TPMA_STARTUP_CLEAR_SHENABLE,
+            },
+            CapabilityType, PropertyTag,
+        },
+        handles::AuthHandle,
+        interface_types::resource_handles::Enables,
+        structures::CapabilityData,
+        Context,
+    };
+
+    // The property is read using get_capability because get_tpm_property
+    // caches the values.
+    fn startup_clear(context: &mut Context) -> u32 {
+        let (capability_data, _) = context
+            .execute_without_session(|ctx| {
+                ctx.get_capability(CapabilityType::TpmProperties, TPM2_PT_STARTUP_CLEAR, 1)
+            })
+            .expect("Failed to call get_capability");
+        match capability_data {
+            CapabilityData::TpmProperties(properties) => properties
+                .find(PropertyTag::StartupClear)
+                .expect("StartupClear property was not returned")
+                .value(),
+            _ => panic!("Unexpected capability data"),
+        }
+    }
+
+    #[test]
+    fn test_hierarchy_control() {
+        let mut context = create_ctx_with_session();
+        let test_cases = [
+            (Enables::Owner, TPMA_STARTUP_CLEAR_SHENABLE),
+            (Enables::Endorsement, TPMA_STARTUP_CLEAR_EHENABLE),
+            (Enables::PlatformNv, TPMA_STARTUP_CLEAR_PHENABLENV),
+        ];
+        for (enable, bit) in test_cases {
+            assert_ne!(0, startup_clear(&mut context) & bit);
+            context
+                .hierarchy_control(AuthHandle::Platform, enable, false)
+                .expect("Failed to disable hierarchy");
+            let disabled = startup_clear(&mut context);
+            context
+                .hierarchy_control(AuthHandle::Platform, enable, true)
+                .expect("Failed to enable hierarchy");
+            assert_eq!(0, disabled & bit);
+            assert_ne!(0, startup_clear(&mut context) & bit);
+        }
+    }
+
+    #[test]
+    fn test_hierarchy_control_enable_without_platform_auth() {
+        let mut context = create_ctx_with_session();
+        context
+            .hierarchy_control(AuthHandle::Endorsement, Enables::Endorsement, false)
+            .expect("Failed to disable endorsement hierarchy");
+        let _ = context
+            .hierarchy_control(AuthHandle::Endorsement, Enables::Endorsement, true)
+            .expect_err("Enabling a hierarchy without platform authorization did not fail");
+        context
+            .hierarchy_control(AuthHandle::Platform, Enables::Endorsement, true)
+            .expect("Failed to enable endorsement hierarchy");
+        assert_ne!(0, startup_clear(&mut context) & TPMA_STARTUP_CLEAR_EHENABLE);
+    }
+}
+
+mod test_set_primary_policy {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        handles::AuthHandle, interface_types::algorithm::HashingAlgorithm, structures::Digest,
+    };
+
+    #[test]
+    fn test_set_primary_policy() {
+        let mut context = create_ctx_with_session();
+        let policy_digest = Digest::try_from(vec![0xEE; 32]).expect("Failed to create digest");
+        context
+            .set_primary_policy(AuthHandle::Owner, policy_digest, HashingAlgorithm::Sha256)
+            .expect("Failed to set primary policy");
+        context
+            .set_primary_policy(AuthHandle::Owner, Digest::default(), HashingAlgorithm::Null)
+            .expect("Failed to remove primary policy");
+    }
+
+    #[test]
+    fn test_set_primary_policy_wrong_size() {
+        let mut context = create_ctx_with_session();
+        let policy_digest = Digest::try_from(vec![0xEE; 20]).expect("Failed to create digest");
+        let _ = context
+            .set_primary_policy(AuthHandle::Owner, policy_digest, HashingAlgorithm::Sha256)
+            .expect_err("Setting a policy with the wrong digest size did not fail");
+    }
+}
+
+mod test_change_seeds {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        handles::ObjectHandle, interface_types::resource_handles::Platform, structures::Auth,
+    };
+
+    #[test]
+    fn test_change_pps() {
+        let mut context = create_ctx_with_session();
+        context
+            .change_pps(Platform::Platform)
+            .expect("Failed to change the platform primary seed");
+    }
+
+    #[test]
+    fn test_change_eps_wrong_auth() {
+        // Changing the endorsement primary seed would change the endorsement keys
+        // used by the tests that run after this one on the same TPM, so only the
+        // error path is tested.
+        let mut context = create_ctx_with_session();
+        context
+            .tr_set_auth(
+                ObjectHandle::Platform,
+                Auth::try_from(vec![0xAB; 16]).expect("Failed to create auth"),
+            )
+            .expect("Failed to set the auth of the platform hierarchy");
+        let _ = context
+            .change_eps(Platform::Platform)
+            .expect_err("Changing the endorsement primary seed with the wrong auth did not fail");
+    }
+}
@@ -146,6 +146,36 @@ mod test_pcr_extend_reset {
     }
 }
 
+mod test_pcr_event {
+    use crate::common::create_ctx_with_session;
+    use sha2::{Digest as _, Sha256};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        handles::PcrHandle, interface_types::algorithm::HashingAlgorithm, structures::Event,
+    };
+
+    #[test]
+    fn test_pcr_event() {
+        let mut context = create_ctx_with_session();
+        let data = "There is no spoon".as_bytes();
+
+        let digests = context
+            .pcr_event(
+                PcrHandle::Pcr16,
+                Event::try_from(data.to_vec()).expect("Failed to create Event"),
+            )
+            .expect("Call to pcr_event failed");
+
+        assert_eq!(
+            digests
+                .get(HashingAlgorithm::Sha256)
+                .expect("Missing digest for the sha256 bank")
+                .as_bytes(),
+            Sha256::digest(data).as_slice()
+        );
+    }
+}
+
 mod test_pcr_read {
     use crate::common::create_ctx_without_session;
     use tss_esapi::{
@@ -245,3 +275,95 @@ mod test_pcr_read {
         assert_ne!(pcr_selection_list_in, pcr_selection_list_out);
     }
 }
+
+mod test_pcr_allocate {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::{
+        constants::CapabilityType,
+        interface_types::{resource_handles::Platform, session_handles::AuthSession},
+        structures::{CapabilityData, PcrSelectionList},
+    };
+
+    #[test]
+    fn test_pcr_allocate_current_allocation() {
+        let mut context = create_ctx_without_session();
+        let (capability_data, _) = context
+            .get_capability(
+                CapabilityType::AssignedPcr,
+                0,
+                PcrSelectionList::MAX_SIZE as u32,
+            )
+            .expect("Failed to call get_capability");
+        let current_allocation = match capability_data {
+            CapabilityData::AssignedPcr(pcr_selection_list) => pcr_selection_list,
+            _ => panic!("Unexpected capability data"),
+        };
+
+        // Requesting the current allocation leaves the TPM unchanged.
+        let allocation_result = context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.pcr_allocate(Platform::Platform, current_allocation)
+            })
+            .expect("Failed to call pcr_allocate");
+        assert!(allocation_result.allocation_success);
+        assert!(allocation_result.max_pcr >= 24);
+        assert!(allocation_result.size_needed <= allocation_result.size_available);
+    }
+}
+
+mod test_pcr_set_auth {
+    use crate::common::create_ctx_without_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        handles::{PcrHandle, PcrTpmHandle},
+        interface_types::{
+            algorithm::HashingAlgorithm, resource_handles::Platform, session_handles::AuthSession,
+        },
+        structures::{Auth, Digest},
+    };
+
+    // The reference implementation of the TPM has a single authorization
+    // group and a single policy group that both contain PCR 20 to 22.
+
+    #[test]
+    fn test_pcr_set_auth_value() {
+        let mut context = create_ctx_without_session();
+        let auth = Auth::try_from(vec![0xA5; 16]).expect("Failed to create auth");
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.pcr_set_auth_value(PcrHandle::Pcr20, auth.clone())
+            })
+            .expect("Failed to set PCR auth value");
+        context
+            .tr_set_auth(PcrHandle::Pcr20.into(), auth)
+            .expect("Failed to call tr_set_auth");
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.pcr_set_auth_value(PcrHandle::Pcr20, Auth::default())
+            })
+            .expect("Failed to restore PCR auth value");
+    }
+
+    #[test]
+    fn test_pcr_set_auth_policy() {
+        let mut context = create_ctx_without_session();
+        let pcr_num = PcrTpmHandle::new(20).expect("Failed to create PCR TPM handle");
+        let policy_digest = Digest::try_from(vec![0x5A; 32]).expect("Failed to create digest");
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.pcr_set_auth_policy(
+                    Platform::Platform,
+                    policy_digest,
+                    HashingAlgorithm::Sha256,
+                    pcr_num,
+                )?;
+                ctx.pcr_set_auth_policy(
+                    Platform::Platform,
+                    Digest::default(),
+                    HashingAlgorithm::Null,
+                    pcr_num,
+                )
+            })
+            .expect("Failed to set PCR auth policy");
+    }
+}
@@ -1,2 +1,47 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_pp_commands {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::CommandCode, interface_types::resource_handles::Platform,
+        structures::CommandCodeList,
+    };
+
+    #[test]
+    fn test_get_pp_commands() {
+        let mut context = create_ctx_with_session();
+        let pp_commands = context
+            .get_pp_commands()
+            .expect("Failed to get PP commands");
+        // TPM2_PP_Commands always requires physical presence.
+        assert!(pp_commands.contains(&CommandCode::PpCommands));
+    }
+
+    #[test]
+    fn test_pp_commands_clear_always_required() {
+        let mut context = create_ctx_with_session();
+        let clear_list = CommandCodeList::try_from(vec![CommandCode::PpCommands])
+            .expect("Failed to create command code list");
+        let _ = context
+            .pp_commands(Platform::Platform, CommandCodeList::new(), clear_list)
+            .unwrap_err();
+        assert!(context
+            .get_pp_commands()
+            .expect("Failed to get PP commands")
+            .contains(&CommandCode::PpCommands));
+    }
+}
+
+mod test_set_algorithm_set {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::interface_types::resource_handles::Platform;
+
+    #[test]
+    fn test_set_algorithm_set() {
+        let mut context = create_ctx_with_session();
+        context
+            .set_algorithm_set(Platform::Platform, 0)
+            .expect("Failed to set algorithm set");
+    }
+}
@@ -421,3 +421,484 @@ mod test_nv_increment {
         assert_eq!(first_value + 1, second_value);
     }
 }
+
+mod test_nv_extend {
+    use crate::common::create_ctx_with_session;
+    use sha2::{Digest as _, Sha256};
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        constants::nv_index_type::NvIndexType,
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{NvAuth, Provision},
+        },
+        structures::{MaxNvBuffer, NvPublicBuilder},
+    };
+
+    #[test]
+    fn test_nv_extend() {
+        let mut context = create_ctx_with_session();
+        let nv_index = NvIndexTpmHandle::new(0x01500029).unwrap();
+
+        // Create owner nv public.
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .with_nv_index_type(NvIndexType::Extend)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(32)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+
+        let owner_nv_index_handle = context
+            .nv_define_space(Provision::Owner, None, owner_nv_public)
+            .expect("Call to nv_define_space failed");
+
+        let value = [1, 2, 3, 4, 5, 6, 7];
+        let data = MaxNvBuffer::from_bytes(&value).expect("Failed to create MaxNvBuffer");
+
+        // Extend the data using Owner authorization
+        let nv_extend_result = context.nv_extend(NvAuth::Owner, owner_nv_index_handle, data);
+
+        // Read the digest using Owner authorization
+        let nv_read_result = context.nv_read(NvAuth::Owner, owner_nv_index_handle, 32, 0);
+
+        context
+            .nv_undefine_space(Provision::Owner, owner_nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        // Process results
+        nv_extend_result.expect("Call to nv_extend failed");
+        let actual_digest = nv_read_result.expect("Call to nv_read failed");
+
+        // The index is initialized with zeros so the expected value
+        // is the hash of 32 zero bytes concatenated with the data.
+        let mut hasher = Sha256::new();
+        hasher.update([0u8; 32]);
+        hasher.update(value);
+        assert_eq!(actual_digest.as_bytes(), hasher.finalize().as_slice());
+    }
+}
+
+mod test_nv_set_bits {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        constants::nv_index_type::NvIndexType,
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{NvAuth, Provision},
+            session_handles::AuthSession,
+        },
+        structures::NvPublicBuilder,
+    };
+
+    #[test]
+    fn test_nv_set_bits() {
+        let mut context = create_ctx_without_session();
+        let nv_index = NvIndexTpmHandle::new(0x0150002A).unwrap();
+
+        // Create owner nv public.
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .with_nv_index_type(NvIndexType::Bits)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(8)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+
+        let (owner_nv_index_handle, result) =
+            context.execute_with_session(Some(AuthSession::Password), |ctx| {
+                let owner_nv_index_handle = ctx
+                    .nv_define_space(Provision::Owner, None, owner_nv_public)
+                    .expect("Call to nv_define_space failed");
+                let result = ctx
+                    .nv_set_bits(NvAuth::Owner, owner_nv_index_handle, 0b0001)
+                    .and_then(|_| ctx.nv_set_bits(NvAuth::Owner, owner_nv_index_handle, 0b0100))
+                    .and_then(|_| ctx.nv_read(NvAuth::Owner, owner_nv_index_handle, 8, 0));
+                (owner_nv_index_handle, result)
+            });
+
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.nv_undefine_space(Provision::Owner, owner_nv_index_handle)
+            })
+            .expect("Call to nv_undefine_space failed");
+
+        // The bit field is an 8 byte unsigned big-endian value.
+        let data = result.expect("Failed to set and read bits");
+        assert_eq!(data.as_bytes(), 0b0101u64.to_be_bytes());
+    }
+}
+
+mod test_nv_write_lock {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{NvAuth, Provision},
+            session_handles::AuthSession,
+        },
+        structures::{MaxNvBuffer, NvPublicBuilder},
+    };
+
+    #[test]
+    fn test_nv_write_lock() {
+        let mut context = create_ctx_without_session();
+        let nv_index = NvIndexTpmHandle::new(0x0150002B).unwrap();
+
+        // Create owner nv public.
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .with_write_stclear(true)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(32)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+
+        let data =
+            MaxNvBuffer::from_bytes(&[1, 2, 3, 4, 5, 6, 7]).expect("Failed to create MaxNvBuffer");
+
+        let (owner_nv_index_handle, write_result, write_lock_result, locked_write_result) = context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                let owner_nv_index_handle = ctx
+                    .nv_define_space(Provision::Owner, None, owner_nv_public)
+                    .expect("Call to nv_define_space failed");
+                let write_result =
+                    ctx.nv_write(NvAuth::Owner, owner_nv_index_handle, data.clone(), 0);
+                let write_lock_result = ctx.nv_write_lock(NvAuth::Owner, owner_nv_index_handle);
+                let locked_write_result =
+                    ctx.nv_write(NvAuth::Owner, owner_nv_index_handle, data.clone(), 0);
+                (
+                    owner_nv_index_handle,
+                    write_result,
+                    write_lock_result,
+                    locked_write_result,
+                )
+            });
+
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.nv_undefine_space(Provision::Owner, owner_nv_index_handle)
+            })
+            .expect("Call to nv_undefine_space failed");
+
+        write_result.expect("Call to nv_write failed");
+        write_lock_result.expect("Call to nv_write_lock failed");
+        let _ = locked_write_result.expect_err("Call to nv_write on locked index did not fail");
+    }
+}
+
+mod test_nv_global_write_lock {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::interface_types::{resource_handles::Provision, session_handles::AuthSession};
+
+    #[test]
+    fn test_nv_global_write_lock() {
+        let mut context = create_ctx_without_session();
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.nv_global_write_lock(Provision::Owner)
+            })
+            .expect("Call to nv_global_write_lock failed");
+    }
+}
+
+mod test_nv_read_lock {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{NvAuth, Provision},
+            session_handles::AuthSession,
+        },
+        structures::{MaxNvBuffer, NvPublicBuilder},
+    };
+
+    #[test]
+    fn test_nv_read_lock() {
+        let mut context = create_ctx_without_session();
+        let nv_index = NvIndexTpmHandle::new(0x0150002C).unwrap();
+
+        // Create owner nv public.
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .with_read_stclear(true)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(32)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+
+        let data =
+            MaxNvBuffer::from_bytes(&[1, 2, 3, 4, 5, 6, 7]).expect("Failed to create MaxNvBuffer");
+
+        let (owner_nv_index_handle, read_result, read_lock_result, locked_read_result) = context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                let owner_nv_index_handle = ctx
+                    .nv_define_space(Provision::Owner, None, owner_nv_public)
+                    .expect("Call to nv_define_space failed");
+                let read_result = ctx
+                    .nv_write(NvAuth::Owner, owner_nv_index_handle, data.clone(), 0)
+                    .and_then(|_| ctx.nv_read(NvAuth::Owner, owner_nv_index_handle, 7, 0));
+                let read_lock_result = ctx.nv_read_lock(NvAuth::Owner, owner_nv_index_handle);
+                let locked_read_result = ctx.nv_read(NvAuth::Owner, owner_nv_index_handle, 7, 0);
+                (
+                    owner_nv_index_handle,
+                    read_result,
+                    read_lock_result,
+                    locked_read_result,
+                )
+            });
+
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.nv_undefine_space(Provision::Owner, owner_nv_index_handle)
+            })
+            .expect("Call to nv_undefine_space failed");
+
+        assert_eq!(data, read_result.expect("Failed to write and read data"));
+        read_lock_result.expect("Call to nv_read_lock failed");
+        let _ = locked_read_result.expect_err("Call to nv_read on locked index did not fail");
+    }
+}
+
+mod test_nv_change_auth {
+    use crate::common::create_ctx_without_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        constants::{CommandCode, SessionType},
+        handles::{NvIndexTpmHandle, SessionHandle},
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{NvAuth, Provision},
+            session_handles::{AuthSession, PolicySession},
+        },
+        structures::{Auth, MaxNvBuffer, NvPublicBuilder, SymmetricDefinition},
+    };
+
+    #[test]
+    fn test_nv_change_auth() {
+        let mut context = create_ctx_without_session();
+        let nv_index = NvIndexTpmHandle::new(0x0150002D).unwrap();
+
+        // Create the policy that allows the auth value to be changed.
+        let trial_policy_auth_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Trial,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let trial_policy_session = PolicySession::try_from(trial_policy_auth_session)
+            .expect("Failed to convert auth session into policy session");
+        context
+            .policy_command_code(trial_policy_session, CommandCode::NvChangeAuth)
+            .expect("Call to policy_command_code failed");
+        let change_auth_policy = context
+            .policy_get_digest(trial_policy_session)
+            .expect("Call to policy_get_digest failed");
+        context
+            .flush_context(SessionHandle::from(trial_policy_auth_session).into())
+            .expect("Failed to flush trial policy session");
+
+        let nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_auth_write(true)
+            .with_auth_read(true)
+            .build()
+            .expect("Failed to create nv index attributes");
+
+        let nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(nv_index_attributes)
+            .with_index_auth_policy(change_auth_policy)
+            .with_data_area_size(32)
+            .build()
+            .expect("Failed to build NvPublic");
+
+        let nv_index_handle = context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.nv_define_space(Provision::Owner, None, nv_public)
+            })
+            .expect("Call to nv_define_space failed");
+
+        let policy_auth_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Policy,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let policy_session = PolicySession::try_from(policy_auth_session)
+            .expect("Failed to convert auth session into policy session");
+
+        let new_auth = Auth::try_from(vec![1, 2, 3, 4]).expect("Failed to create auth");
+        let change_auth_result = context
+            .policy_command_code(policy_session, CommandCode::NvChangeAuth)
+            .and_then(|_| {
+                context.execute_with_session(Some(policy_auth_session), |ctx| {
+                    ctx.nv_change_auth(nv_index_handle, new_auth.clone())
+                })
+            });
+
+        // Use the new auth value to write to the index.
+        let write_result = context
+            .tr_set_auth(nv_index_handle.into(), new_auth)
+            .and_then(|_| {
+                context.execute_with_session(Some(AuthSession::Password), |ctx| {
+                    ctx.nv_write(
+                        NvAuth::NvIndex(nv_index_handle),
+                        nv_index_handle,
+                        MaxNvBuffer::from_bytes(&[1, 2, 3])?,
+                        0,
+                    )
+                })
+            });
+
+        context
+            .flush_context(SessionHandle::from(policy_auth_session).into())
+            .expect("Failed to flush policy session");
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.nv_undefine_space(Provision::Owner, nv_index_handle)
+            })
+            .expect("Call to nv_undefine_space failed");
+
+        change_auth_result.expect("Call to nv_change_auth failed");
+        write_result.expect("Call to nv_write with the new auth value failed");
+    }
+}
+
+mod test_nv_certify {
+    use crate::common::{create_ctx_without_session, signing_key_pub};
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{Hierarchy, NvAuth, Provision},
+            session_handles::AuthSession,
+            structure_tags::AttestationType,
+        },
+        structures::{AttestInfo, Data, MaxNvBuffer, NvPublicBuilder, SignatureScheme},
+    };
+
+    #[test]
+    fn test_nv_certify() {
+        let mut context = create_ctx_without_session();
+        let nv_index = NvIndexTpmHandle::new(0x0150002E).unwrap();
+
+        let sign_key_handle = context
+            .execute_with_nullauth_session(|ctx| {
+                ctx.create_primary(Hierarchy::Owner, signing_key_pub(), None, None, None, None)
+            })
+            .expect("Failed to create signing key")
+            .key_handle;
+
+        // Create owner nv public.
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(32)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+
+        let data =
+            MaxNvBuffer::from_bytes(&[1, 2, 3, 4, 5, 6, 7]).expect("Failed to create MaxNvBuffer");
+
+        let owner_nv_index_handle = context
+            .execute_with_session(Some(AuthSession::Password), |ctx| -> tss_esapi::Result<_> {
+                let owner_nv_index_handle =
+                    ctx.nv_define_space(Provision::Owner, None, owner_nv_public)?;
+                ctx.nv_write(NvAuth::Owner, owner_nv_index_handle, data.clone(), 0)?;
+                Ok(owner_nv_index_handle)
+            })
+            .expect("Failed to define and write NV index");
+
+        let nv_certify_result = context.execute_with_sessions(
+            (
+                Some(AuthSession::Password),
+                Some(AuthSession::Password),
+                None,
+            ),
+            |ctx| {
+                ctx.nv_certify(
+                    sign_key_handle,
+                    NvAuth::Owner,
+                    owner_nv_index_handle,
+                    Data::default(),
+                    SignatureScheme::Null,
+                    7,
+                    0,
+                )
+            },
+        );
+
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.nv_undefine_space(Provision::Owner, owner_nv_index_handle)
+            })
+            .expect("Call to nv_undefine_space failed");
+
+        let (attest, _signature) = nv_certify_result.expect("Call to nv_certify failed");
+        assert_eq!(attest.attestation_type(), AttestationType::Nv);
+        match attest.attested() {
+            AttestInfo::Nv { info } => {
+                assert_eq!(info.offset(), 0);
+                assert_eq!(info.nv_contents(), &data);
+            }
+            _ => {
+                panic!("Attested did not contain the expected variant.")
+            }
+        }
+    }
+}
@@ -426,3 +426,148 @@ mod test_unseal {
         assert!(unsealed == testbytes);
     }
 }
+
+mod test_create_loaded {
+    use crate::common::{create_ctx_with_session, decryption_key_pub, signing_key_pub};
+    use tss_esapi::{
+        attributes::ObjectAttributesBuilder,
+        handles::ObjectHandle,
+        interface_types::{
+            algorithm::{HashingAlgorithm, KeyDerivationFunction, PublicAlgorithm},
+            resource_handles::Hierarchy,
+        },
+        structures::{
+            HmacScheme, KeyedHashScheme, Public, PublicBuilder, PublicKeyedHashParameters,
+            Template, XorScheme,
+        },
+    };
+
+    fn derivation_parent_pub() -> Public {
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_fixed_tpm(true)
+            .with_fixed_parent(true)
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .with_restricted(true)
+            .with_decrypt(true)
+            .build()
+            .expect("Failed to build object attributes");
+        PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::KeyedHash)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_keyed_hash_parameters(PublicKeyedHashParameters::new(KeyedHashScheme::Xor {
+                xor_scheme: XorScheme::new(
+                    HashingAlgorithm::Sha256,
+                    KeyDerivationFunction::Kdf1Sp800_108,
+                ),
+            }))
+            .with_keyed_hash_unique_identifier(Default::default())
+            .build()
+            .expect("Failed to build derivation parent public")
+    }
+
+    fn derived_hmac_key_pub() -> Public {
+        // Derived objects are not allowed to have sensitiveDataOrigin set.
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_fixed_tpm(true)
+            .with_fixed_parent(true)
+            .with_user_with_auth(true)
+            .with_sign_encrypt(true)
+            .build()
+            .expect("Failed to build object attributes");
+        PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::KeyedHash)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_keyed_hash_parameters(PublicKeyedHashParameters::new(KeyedHashScheme::Hmac {
+                hmac_scheme: HmacScheme::new(HashingAlgorithm::Sha256),
+            }))
+            .with_keyed_hash_unique_identifier(Default::default())
+            .build()
+            .expect("Failed to build derived key public")
+    }
+
+    #[test]
+    fn test_create_loaded_primary() {
+        let mut context = create_ctx_with_session();
+        let result = context
+            .create_loaded(
+                Hierarchy::Owner.into(),
+                decryption_key_pub().into(),
+                None,
+                None,
+            )
+            .expect("Failed to call create_loaded");
+        let (read_public, _, _) = context
+            .read_public(result.key_handle)
+            .expect("Failed to read public");
+        assert_eq!(result.out_public, read_public);
+    }
+
+    #[test]
+    fn test_create_loaded_ordinary_key() {
+        let mut context = create_ctx_with_session();
+        let primary_key_handle = context
+            .create_primary(
+                Hierarchy::Owner,
+                decryption_key_pub(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .expect("Failed to create primary key")
+            .key_handle;
+        let result = context
+            .create_loaded(
+                primary_key_handle.into(),
+                signing_key_pub().into(),
+                None,
+                None,
+            )
+            .expect("Failed to call create_loaded");
+
+        // The private part can be used to load the key again.
+        context
+            .flush_context(result.key_handle.into())
+            .expect("Failed to flush key");
+        let _ = context
+            .load(primary_key_handle, result.out_private, result.out_public)
+            .expect("Failed to load key");
+    }
+
+    #[test]
+    fn test_create_loaded_derived_key() {
+        let mut context = create_ctx_with_session();
+        let derivation_parent: ObjectHandle = context
+            .create_loaded(
+                Hierarchy::Owner.into(),
+                derivation_parent_pub().into(),
+                None,
+                None,
+            )
+            .expect("Failed to create derivation parent")
+            .key_handle
+            .into();
+
+        let mut derive = |label: &[u8], derive_context: &[u8]| {
+            let template = Template::derived(&derived_hmac_key_pub(), label, derive_context)
+                .expect("Failed to create derived template");
+            let result = context
+                .create_loaded(derivation_parent, template.into(), None, None)
+                .expect("Failed to create derived key");
+            context
+                .flush_context(result.key_handle.into())
+                .expect("Failed to flush derived key");
+            result.out_public
+        };
+
+        let first = derive(b"label", b"context");
+        let second = derive(b"label", b"context");
+        let third = derive(b"label", b"other context");
+        // The same label and context produce the same key.
+        assert_eq!(first, second);
+        assert_ne!(first, third);
+    }
+}
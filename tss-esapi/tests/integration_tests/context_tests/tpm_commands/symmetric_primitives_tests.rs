@@ -157,6 +157,130 @@ mod test_encrypt_decrypt_2 {
     }
 }
 
+mod test_encrypt_decrypt {
+    use crate::common::create_ctx_with_session;
+    use std::convert::{TryFrom, TryInto};
+    use tss_esapi::{
+        abstraction::cipher::{self, Cipher},
+        attributes::ObjectAttributesBuilder,
+        handles::KeyHandle,
+        interface_types::{
+            algorithm::{HashingAlgorithm, PublicAlgorithm, SymmetricMode},
+            resource_handles::Hierarchy,
+        },
+        structures::{InitialValue, MaxBuffer, PublicBuilder, SymmetricCipherParameters},
+        Context,
+    };
+
+    fn create_aes_128_cfb_key(context: &mut Context) -> KeyHandle {
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_fixed_tpm(true)
+            .with_fixed_parent(true)
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .with_sign_encrypt(true)
+            .with_decrypt(true)
+            .build()
+            .expect("Failed to create object attributes for symmetric key");
+
+        let public = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::SymCipher)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_symmetric_cipher_parameters(SymmetricCipherParameters::new(
+                Cipher::aes_128_cfb()
+                    .try_into()
+                    .expect("Failed to create symmteric cipher parameters from cipher"),
+            ))
+            .with_symmetric_cipher_unique_identifier(Default::default())
+            .build()
+            .expect("Failed to create public for symmetric key");
+
+        context
+            .create_primary(Hierarchy::Owner, public, None, None, None, None)
+            .expect("Failed to create symmetric key")
+            .key_handle
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_with_aes_128_cfb_symmetric_key() {
+        let mut context = create_ctx_with_session();
+        let key_handle = create_aes_128_cfb_key(&mut context);
+
+        let initial_value =
+            InitialValue::try_from(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16])
+                .expect("Failed to create InitialValue from data");
+        let data = MaxBuffer::try_from(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 16])
+            .expect("Failed to create MaxBuffer from data");
+
+        let (encrypted_data, _) = context
+            .encrypt_decrypt(
+                key_handle,
+                false,
+                SymmetricMode::Cfb,
+                data.clone(),
+                initial_value.clone(),
+            )
+            .expect("Call to encrypt_decrypt failed when encrypting data");
+        assert_ne!(data, encrypted_data);
+
+        let (decrypted_data, _) = context
+            .encrypt_decrypt(
+                key_handle,
+                true,
+                SymmetricMode::Cfb,
+                encrypted_data,
+                initial_value,
+            )
+            .expect("Call to encrypt_decrypt failed when decrypting data");
+        assert_eq!(data, decrypted_data);
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_abstraction_matches_encrypt_decrypt_2() {
+        let mut context = create_ctx_with_session();
+        let key_handle = create_aes_128_cfb_key(&mut context);
+
+        let initial_value =
+            InitialValue::try_from(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16])
+                .expect("Failed to create InitialValue from data");
+        let data = MaxBuffer::try_from(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 16])
+            .expect("Failed to create MaxBuffer from data");
+
+        let (expected_encrypted_data, _) = context
+            .encrypt_decrypt_2(
+                key_handle,
+                false,
+                SymmetricMode::Cfb,
+                data.clone(),
+                initial_value.clone(),
+            )
+            .expect("Call to encrypt_decrypt_2 failed when encrypting data");
+
+        let (encrypted_data, _) = cipher::encrypt_decrypt(
+            &mut context,
+            key_handle,
+            false,
+            SymmetricMode::Cfb,
+            data.clone(),
+            initial_value.clone(),
+        )
+        .expect("Failed to encrypt data");
+        assert_eq!(expected_encrypted_data, encrypted_data);
+
+        let (decrypted_data, _) = cipher::encrypt_decrypt(
+            &mut context,
+            key_handle,
+            true,
+            SymmetricMode::Cfb,
+            encrypted_data,
+            initial_value,
+        )
+        .expect("Failed to decrypt data");
+        assert_eq!(data, decrypted_data);
+    }
+}
+
 mod test_hash {
     use crate::common::create_ctx_without_session;
     use std::convert::TryFrom;
@@ -235,3 +359,126 @@ mod test_hmac {
             .unwrap();
     }
 }
+
+mod test_mac {
+    use crate::common::create_ctx_with_session;
+    use sha2::{Digest as _, Sha256};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::ObjectAttributesBuilder,
+        interface_types::{
+            algorithm::{HashingAlgorithm, MacAlgorithm, PublicAlgorithm},
+            resource_handles::Hierarchy,
+        },
+        structures::{
+            Digest, KeyedHashScheme, MaxBuffer, PublicBuilder, PublicKeyedHashParameters,
+            Sensitive, SymmetricCipherParameters, SymmetricDefinitionObject, SymmetricKey,
+        },
+    };
+
+    #[test]
+    fn test_mac_matches_hmac() {
+        let mut context = create_ctx_with_session();
+
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_sign_encrypt(true)
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .build()
+            .expect("Failed to build object attributes");
+
+        let key_pub = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::KeyedHash)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_keyed_hash_parameters(PublicKeyedHashParameters::new(
+                KeyedHashScheme::HMAC_SHA_256,
+            ))
+            .with_keyed_hash_unique_identifier(Default::default())
+            .build()
+            .expect("Failed to build public structure for key.");
+
+        let key = context
+            .create_primary(Hierarchy::Owner, key_pub, None, None, None, None)
+            .unwrap();
+
+        let buf = MaxBuffer::try_from(vec![1, 2, 3, 4]).unwrap();
+        let expected = context
+            .hmac(key.key_handle.into(), buf.clone(), HashingAlgorithm::Sha256)
+            .unwrap();
+        let mac = context
+            .mac(key.key_handle.into(), buf, MacAlgorithm::Sha256)
+            .unwrap();
+        assert_eq!(expected, mac);
+    }
+
+    #[test]
+    fn test_mac_with_cmac() {
+        // Example 2 of the AES-CMAC test vectors in RFC 4493.
+        let key = vec![
+            0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf,
+            0x4f, 0x3c,
+        ];
+        let message = vec![
+            0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93,
+            0x17, 0x2a,
+        ];
+        let expected_mac = vec![
+            0x07, 0x0a, 0x16, 0xb4, 0x6b, 0x4d, 0x41, 0x44, 0xf7, 0x9b, 0xdd, 0x9d, 0xd0, 0x4a,
+            0x28, 0x7c,
+        ];
+
+        let mut context = create_ctx_with_session();
+
+        // The unique identifier of a symmetric key is the digest of the seed
+        // value followed by the key.
+        let seed_value = vec![0x5a; 32];
+        let unique_identifier = Digest::try_from(
+            Sha256::new()
+                .chain_update(&seed_value)
+                .chain_update(&key)
+                .finalize()
+                .to_vec(),
+        )
+        .expect("Failed to create unique identifier");
+
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_user_with_auth(true)
+            .with_sign_encrypt(true)
+            .with_decrypt(true)
+            .build()
+            .expect("Failed to build object attributes");
+
+        let key_pub = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::SymCipher)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_symmetric_cipher_parameters(SymmetricCipherParameters::new(
+                SymmetricDefinitionObject::AES_128_CFB,
+            ))
+            .with_symmetric_cipher_unique_identifier(unique_identifier)
+            .build()
+            .expect("Failed to build public structure for key.");
+        let key_priv = Sensitive::Symmetric {
+            auth_value: Default::default(),
+            seed_value: Digest::try_from(seed_value).expect("Failed to create seed value"),
+            sensitive: SymmetricKey::try_from(key).expect("Failed to create symmetric key"),
+        };
+
+        let key_handle = context
+            .load_external(key_priv, key_pub, Hierarchy::Null)
+            .expect("Failed to load the AES key");
+
+        let mac = context
+            .mac(
+                key_handle.into(),
+                MaxBuffer::try_from(message).expect("Failed to create buffer"),
+                MacAlgorithm::Cmac,
+            )
+            .expect("Call to mac failed with CMAC");
+        context
+            .flush_context(key_handle.into())
+            .expect("Failed to flush the AES key");
+        assert_eq!(expected_mac, mac.as_bytes());
+    }
+}
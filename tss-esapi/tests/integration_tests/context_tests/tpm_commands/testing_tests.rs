@@ -20,3 +20,64 @@ mod test_get_test_result {
         rc.unwrap();
     }
 }
+
+mod test_incremental_self_test {
+    use crate::common::create_ctx_without_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{constants::AlgorithmIdentifier, structures::AlgorithmList};
+
+    #[test]
+    fn test_incremental_self_test() {
+        let mut context = create_ctx_without_session();
+        let to_test =
+            AlgorithmList::try_from(vec![AlgorithmIdentifier::Sha256, AlgorithmIdentifier::Rsa])
+                .expect("Failed to create algorithm list");
+        let to_do_list = context
+            .incremental_self_test(to_test)
+            .expect("Failed to run incremental self test");
+        assert!(!to_do_list.contains(&AlgorithmIdentifier::Sha256));
+        assert!(!to_do_list.contains(&AlgorithmIdentifier::Rsa));
+
+        let _ = context
+            .incremental_self_test(AlgorithmList::new())
+            .expect("Failed to get the algorithms that need testing");
+    }
+}
+
+mod test_set_self_test_retry {
+    use crate::common::create_ctx_without_session;
+
+    #[test]
+    fn test_set_self_test_retry() {
+        let mut context = create_ctx_without_session();
+        context
+            .set_self_test_retry(true)
+            .expect("Failed to enable the self test retry mode");
+        let random = context
+            .get_random(16)
+            .expect("Failed to get random bytes with the retry mode enabled");
+        assert_eq!(random.len(), 16);
+
+        context
+            .set_self_test_retry(false)
+            .expect("Failed to disable the self test retry mode");
+        let random = context
+            .get_random(16)
+            .expect("Failed to get random bytes with the retry mode disabled");
+        assert_eq!(random.len(), 16);
+    }
+
+    #[test]
+    fn test_set_self_test_retry_twice() {
+        let mut context = create_ctx_without_session();
+        context
+            .set_self_test_retry(true)
+            .expect("Failed to enable the self test retry mode");
+        context
+            .set_self_test_retry(true)
+            .expect("Failed to enable the self test retry mode again");
+        context
+            .self_test(false)
+            .expect("Failed to run self test with the retry mode enabled");
+    }
+}
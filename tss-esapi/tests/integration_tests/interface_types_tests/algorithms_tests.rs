@@ -389,3 +389,57 @@ mod symmetric_object_tests {
         )
     }
 }
+
+mod mac_algorithm_tests {
+    use super::*;
+    use tss_esapi::{
+        constants::{
+            tss::{
+                TPM2_ALG_CMAC, TPM2_ALG_NULL, TPM2_ALG_SHA1, TPM2_ALG_SHA256, TPM2_ALG_SHA384,
+                TPM2_ALG_SHA3_256, TPM2_ALG_SHA3_384, TPM2_ALG_SHA3_512, TPM2_ALG_SHA512,
+                TPM2_ALG_SM3_256,
+            },
+            AlgorithmIdentifier,
+        },
+        interface_types::algorithm::{HashingAlgorithm, MacAlgorithm},
+    };
+    #[test]
+    fn test_mac_algorithm_conversion() {
+        test_conversion!(TPM2_ALG_SHA1, MacAlgorithm::Sha1);
+        test_conversion!(TPM2_ALG_SHA256, MacAlgorithm::Sha256);
+        test_conversion!(TPM2_ALG_SHA384, MacAlgorithm::Sha384);
+        test_conversion!(TPM2_ALG_SHA512, MacAlgorithm::Sha512);
+        test_conversion!(TPM2_ALG_SM3_256, MacAlgorithm::Sm3_256);
+        test_conversion!(TPM2_ALG_SHA3_256, MacAlgorithm::Sha3_256);
+        test_conversion!(TPM2_ALG_SHA3_384, MacAlgorithm::Sha3_384);
+        test_conversion!(TPM2_ALG_SHA3_512, MacAlgorithm::Sha3_512);
+        test_conversion!(TPM2_ALG_CMAC, MacAlgorithm::Cmac);
+        test_conversion!(TPM2_ALG_NULL, MacAlgorithm::Null);
+    }
+
+    #[test]
+    fn test_conversion_from_hashing_algorithm() {
+        assert_eq!(
+            MacAlgorithm::Sha256,
+            MacAlgorithm::from(HashingAlgorithm::Sha256)
+        );
+        assert_eq!(
+            MacAlgorithm::Null,
+            MacAlgorithm::from(HashingAlgorithm::Null)
+        );
+    }
+
+    #[test]
+    fn test_conversion_of_incorrect_algorithm() {
+        test_invalid_tpm_alg_conversion!(
+            TPM2_ALG_RSA,
+            MacAlgorithm,
+            WrapperErrorKind::InvalidParam
+        );
+        test_invalid_algorithm_conversion!(
+            AlgorithmIdentifier::Aes,
+            MacAlgorithm,
+            WrapperErrorKind::InvalidParam
+        )
+    }
+}
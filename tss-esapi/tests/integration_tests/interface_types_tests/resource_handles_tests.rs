@@ -4,8 +4,8 @@ use std::convert::TryFrom;
 use tss_esapi::{
     handles::{AuthHandle, NvIndexHandle, ObjectHandle, PermanentTpmHandle, TpmHandle},
     interface_types::resource_handles::{
-        Clear, Enables, Endorsement, Hierarchy, HierarchyAuth, Lockout, NvAuth, Owner, Platform,
-        Provision,
+        Act, Clear, Enables, Endorsement, Hierarchy, HierarchyAuth, Lockout, NvAuth, Owner,
+        Platform, Provision,
     },
     tss2_esys::ESYS_TR,
 };
@@ -297,3 +297,38 @@ mod test_lockout {
         );
     }
 }
+
+mod test_act {
+    use super::*;
+    #[test]
+    fn test_new() {
+        for index in 0..=Act::MAX_INDEX {
+            let act = Act::new(index).expect("Failed to create Act");
+            assert_eq!(act.index(), index);
+        }
+        let _ = Act::new(Act::MAX_INDEX + 1).expect_err("Act with invalid index was created");
+    }
+
+    #[test]
+    fn test_conversions() {
+        let first = Act::new(0).expect("Failed to create Act");
+        assert_eq!(
+            TpmHandle::from(first),
+            TpmHandle::Permanent(PermanentTpmHandle::AuthenticatedTimersFirst)
+        );
+        let last = Act::new(Act::MAX_INDEX).expect("Failed to create Act");
+        assert_eq!(
+            TpmHandle::from(last),
+            TpmHandle::Permanent(PermanentTpmHandle::AuthenticatedTimersLast)
+        );
+        assert_eq!(
+            Act::try_from(TpmHandle::Permanent(
+                PermanentTpmHandle::AuthenticatedTimersLast
+            ))
+            .expect("Failed to convert TpmHandle into Act"),
+            last
+        );
+        let _ = Act::try_from(TpmHandle::Permanent(PermanentTpmHandle::Owner))
+            .expect_err("Owner was converted into Act");
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use tss_esapi::{
+    constants::{tss::TPM2_AT_VEND, AttachedComponentTag, VendorAttachedComponentTag},
+    structures::AcOutput,
+    tss2_esys::TPMS_AC_OUTPUT,
+};
+
+use std::convert::TryFrom;
+
+#[test]
+fn test_conversions() {
+    let expected_tag = AttachedComponentTag::Pv1;
+    let expected_data = 0xDEAD_BEEFu32;
+
+    let expected_tpms_ac_output = TPMS_AC_OUTPUT {
+        tag: expected_tag.into(),
+        data: expected_data,
+    };
+
+    let ac_output = AcOutput::try_from(expected_tpms_ac_output)
+        .expect("Failed to convert TPMS_AC_OUTPUT into AcOutput");
+
+    assert_eq!(expected_tag, ac_output.tag());
+    assert_eq!(expected_data, ac_output.data());
+
+    let actual_tpms_ac_output = TPMS_AC_OUTPUT::from(ac_output);
+    assert_eq!(expected_tpms_ac_output.tag, actual_tpms_ac_output.tag);
+    assert_eq!(expected_tpms_ac_output.data, actual_tpms_ac_output.data);
+}
+
+#[test]
+fn test_vendor_tag_conversion() {
+    let ac_output = AcOutput::try_from(TPMS_AC_OUTPUT {
+        tag: TPM2_AT_VEND + 1,
+        data: 42,
+    })
+    .expect("Failed to convert TPMS_AC_OUTPUT with a vendor tag into AcOutput");
+    assert_eq!(
+        AttachedComponentTag::Vendor(
+            VendorAttachedComponentTag::new(TPM2_AT_VEND + 1).expect("Failed to create vendor tag")
+        ),
+        ac_output.tag()
+    );
+    assert_eq!(TPM2_AT_VEND + 1, TPMS_AC_OUTPUT::from(ac_output).tag);
+}
+
+#[test]
+fn test_invalid_conversion() {
+    let _ = AcOutput::try_from(TPMS_AC_OUTPUT { tag: 3, data: 0 }).unwrap_err();
+}
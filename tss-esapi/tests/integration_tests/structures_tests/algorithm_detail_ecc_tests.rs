@@ -0,0 +1,93 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use tss_esapi::{
+    interface_types::{algorithm::HashingAlgorithm, ecc::EccCurve},
+    structures::{
+        AlgorithmDetailEcc, EccParameter, EccScheme, HashScheme, KeyDerivationFunctionScheme,
+    },
+    tss2_esys::TPMS_ALGORITHM_DETAIL_ECC,
+};
+
+use std::convert::TryFrom;
+
+fn ecc_parameter(bytes: &[u8]) -> EccParameter {
+    EccParameter::try_from(bytes.to_vec()).expect("Failed to create EccParameter")
+}
+
+#[test]
+fn test_conversion() {
+    let expected_curve = EccCurve::NistP256;
+    let expected_key_size = 256u16;
+    let expected_key_derivation_function_scheme =
+        KeyDerivationFunctionScheme::Kdf1Sp800_56a(HashScheme::new(HashingAlgorithm::Sha256));
+    let expected_ecc_scheme = EccScheme::EcDsa(HashScheme::new(HashingAlgorithm::Sha256));
+    let expected_p = ecc_parameter(&[0x01; 32]);
+    let expected_a = ecc_parameter(&[0x02; 32]);
+    let expected_b = ecc_parameter(&[0x03; 32]);
+    let expected_g_x = ecc_parameter(&[0x04; 32]);
+    let expected_g_y = ecc_parameter(&[0x05; 32]);
+    let expected_n = ecc_parameter(&[0x06; 32]);
+    let expected_h = ecc_parameter(&[0x01]);
+
+    let tpms_algorithm_detail_ecc = TPMS_ALGORITHM_DETAIL_ECC {
+        curveID: expected_curve.into(),
+        keySize: expected_key_size,
+        kdf: expected_key_derivation_function_scheme.into(),
+        sign: expected_ecc_scheme.into(),
+        p: expected_p.clone().into(),
+        a: expected_a.clone().into(),
+        b: expected_b.clone().into(),
+        gX: expected_g_x.clone().into(),
+        gY: expected_g_y.clone().into(),
+        n: expected_n.clone().into(),
+        h: expected_h.clone().into(),
+    };
+
+    let algorithm_detail_ecc = AlgorithmDetailEcc::try_from(tpms_algorithm_detail_ecc)
+        .expect("Failed to convert TPMS_ALGORITHM_DETAIL_ECC into AlgorithmDetailEcc");
+
+    assert_eq!(expected_curve, algorithm_detail_ecc.curve());
+    assert_eq!(expected_key_size, algorithm_detail_ecc.key_size());
+    assert_eq!(
+        expected_key_derivation_function_scheme,
+        algorithm_detail_ecc.key_derivation_function_scheme()
+    );
+    assert_eq!(expected_ecc_scheme, algorithm_detail_ecc.ecc_scheme());
+    assert_eq!(&expected_p, algorithm_detail_ecc.p());
+    assert_eq!(&expected_a, algorithm_detail_ecc.a());
+    assert_eq!(&expected_b, algorithm_detail_ecc.b());
+    assert_eq!(&expected_g_x, algorithm_detail_ecc.g_x());
+    assert_eq!(&expected_g_y, algorithm_detail_ecc.g_y());
+    assert_eq!(&expected_n, algorithm_detail_ecc.n());
+    assert_eq!(&expected_h, algorithm_detail_ecc.h());
+    assert_eq!(&expected_g_x, algorithm_detail_ecc.generator().x());
+    assert_eq!(&expected_g_y, algorithm_detail_ecc.generator().y());
+
+    let actual_tpms_algorithm_detail_ecc: TPMS_ALGORITHM_DETAIL_ECC =
+        algorithm_detail_ecc.clone().into();
+    assert_eq!(
+        algorithm_detail_ecc,
+        AlgorithmDetailEcc::try_from(actual_tpms_algorithm_detail_ecc)
+            .expect("Failed to convert TPMS_ALGORITHM_DETAIL_ECC into AlgorithmDetailEcc"),
+    );
+}
+
+#[test]
+fn test_invalid_curve() {
+    let tpms_algorithm_detail_ecc = TPMS_ALGORITHM_DETAIL_ECC {
+        curveID: 0xFFFF,
+        keySize: 256,
+        kdf: KeyDerivationFunctionScheme::Null.into(),
+        sign: EccScheme::Null.into(),
+        p: Default::default(),
+        a: Default::default(),
+        b: Default::default(),
+        gX: Default::default(),
+        gY: Default::default(),
+        n: Default::default(),
+        h: Default::default(),
+    };
+    let _ = AlgorithmDetailEcc::try_from(tpms_algorithm_detail_ecc)
+        .expect_err("Conversion of an invalid curve did not fail");
+}
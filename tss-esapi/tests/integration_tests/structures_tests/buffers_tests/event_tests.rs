@@ -0,0 +1,25 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::convert::TryFrom;
+use tss_esapi::structures::Event;
+use tss_esapi::tss2_esys::TPM2B_EVENT;
+
+#[test]
+fn test_max_sized_data() {
+    let _ = Event::try_from([0xff; 1024].to_vec()).unwrap();
+}
+
+#[test]
+fn test_to_large_data() {
+    let _ = Event::try_from([0xff; 1025].to_vec()).unwrap_err();
+}
+
+#[test]
+fn test_conversion() {
+    let data = vec![1, 2, 3, 4, 5, 6, 7, 8];
+    let event = Event::try_from(data.clone()).unwrap();
+    let tss_event = TPM2B_EVENT::from(event.clone());
+    assert_eq!(tss_event.size as usize, data.len());
+    assert_eq!(&tss_event.buffer[..data.len()], &data[..]);
+    assert_eq!(event, Event::try_from(tss_event).unwrap());
+}
@@ -4,8 +4,10 @@ mod attest_buffer_tests;
 mod auth_tests;
 mod data_tests;
 mod digest_tests;
+mod event_tests;
 mod max_buffer_tests;
 mod nonce_tests;
 mod public;
 mod sensitive;
 mod sensitive_create_buffer_tests;
+mod template_tests;
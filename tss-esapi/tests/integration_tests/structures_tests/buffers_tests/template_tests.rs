@@ -0,0 +1,76 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::convert::TryFrom;
+use tss_esapi::{
+    structures::{Public, Template},
+    traits::Marshall,
+    Error, WrapperErrorKind,
+};
+
+const TEMPLATE_MAX_SIZE: usize = 612;
+
+#[test]
+fn test_max_sized_data() {
+    let _ = Template::try_from(vec![0xffu8; TEMPLATE_MAX_SIZE])
+        .expect("Failed to parse buffer of maximum size as Template");
+}
+
+#[test]
+fn test_to_large_data() {
+    assert_eq!(
+        Template::try_from(vec![0xffu8; TEMPLATE_MAX_SIZE + 1])
+            .expect_err("Converting a buffer that is to large did not produce an error"),
+        Error::WrapperError(WrapperErrorKind::WrongParamSize),
+        "Wrong kind of error when converting a buffer with size {} to Template",
+        TEMPLATE_MAX_SIZE + 1
+    );
+}
+
+#[test]
+fn test_from_public() {
+    crate::common::publics().iter().for_each(|public| {
+        let template =
+            Template::try_from(public.clone()).expect("Failed to convert Public to Template");
+        assert_eq!(
+            public.marshall().expect("Failed to marshall Public"),
+            template.as_bytes()
+        );
+    });
+}
+
+#[test]
+fn test_derived() {
+    let label = b"label";
+    let context = b"context";
+    crate::common::publics().iter().for_each(|public| {
+        let marshalled_public = public.marshall().expect("Failed to marshall Public");
+        let unique_size = match public {
+            Public::Rsa { unique, .. } => 2 + unique.len(),
+            Public::KeyedHash { unique, .. } | Public::SymCipher { unique, .. } => 2 + unique.len(),
+            Public::Ecc { unique, .. } => 4 + unique.x().len() + unique.y().len(),
+        };
+        let mut expected = marshalled_public[..marshalled_public.len() - unique_size].to_vec();
+        expected.extend_from_slice(&[0x00, 0x05]);
+        expected.extend_from_slice(label);
+        expected.extend_from_slice(&[0x00, 0x07]);
+        expected.extend_from_slice(context);
+
+        let template =
+            Template::derived(public, label, context).expect("Failed to create derived template");
+        assert_eq!(expected, template.as_bytes());
+    });
+}
+
+#[test]
+fn test_derived_label_too_large() {
+    let public = crate::common::publics()[0].clone();
+    assert_eq!(
+        Template::derived(&public, &[0xff; 33], &[]),
+        Err(Error::WrapperError(WrapperErrorKind::WrongParamSize))
+    );
+    assert_eq!(
+        Template::derived(&public, &[], &[0xff; 33]),
+        Err(Error::WrapperError(WrapperErrorKind::WrongParamSize))
+    );
+}
@@ -141,34 +141,32 @@ fn test_ecc_curves() {
     }
 }
 
-// For these tests to work the tpm2-tss library need to have the
-// authPolicies field in the TPMU_CAPABILITIES union.
-// #[test]
-// fn test_auth_policies() {
-//     let mut context = create_ctx_without_session();
-
-//     let (capabs, _more) = context
-//         .get_capability(CapabilityType::AuthPolicies, 0, 80)
-//         .unwrap();
-
-//     if let CapabilityData::AuthPolicies(list) = capabs {
-//         assert!(!list.is_empty());
-//     } else {
-//         panic!("Got wrong type of capability data: {:?}", capabs);
-//     }
-// }
-
-// For these tests to work the tpm2-tss library need to have the
-// actData field in the TPMU_CAPABILITIES union.
-// #[test]
-// fn test_act() {
-//     let mut context = create_ctx_without_session();
-
-//     let (capabs, _more) = context.get_capability(CapabilityType::Act, 0, 80).unwrap();
-
-//     if let CapabilityData::ActData(list) = capabs {
-//         assert!(!list.is_empty());
-//     } else {
-//         panic!("Got wrong type of capability data: {:?}", capabs);
-//     }
-// }
+#[cfg(has_tpms_tagged_policy)]
+#[test]
+fn test_auth_policies() {
+    let mut context = create_ctx_without_session();
+
+    let (capabs, _more) = context
+        .get_capability(CapabilityType::AuthPolicies, 0, 80)
+        .unwrap();
+
+    if let CapabilityData::AuthPolicies(list) = capabs {
+        assert!(!list.is_empty());
+    } else {
+        panic!("Got wrong type of capability data: {:?}", capabs);
+    }
+}
+
+#[cfg(has_tpms_act_data)]
+#[test]
+fn test_act() {
+    let mut context = create_ctx_without_session();
+
+    let (capabs, _more) = context.get_capability(CapabilityType::Act, 0, 80).unwrap();
+
+    if let CapabilityData::ActData(list) = capabs {
+        assert!(!list.is_empty());
+    } else {
+        panic!("Got wrong type of capability data: {:?}", capabs);
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::convert::TryFrom;
+use tss_esapi::{
+    constants::AttachedComponentTag,
+    structures::{AcCapabilityList, AcOutput},
+    tss2_esys::{TPML_AC_CAPABILITIES, TPMS_AC_OUTPUT},
+    Error, WrapperErrorKind,
+};
+
+#[test]
+fn test_valid_conversions() {
+    let expected_ac_outputs = vec![
+        AcOutput::new(AttachedComponentTag::Any, 0),
+        AcOutput::new(AttachedComponentTag::Pv1, 0x1234_5678),
+    ];
+
+    let ac_capability_list = AcCapabilityList::try_from(expected_ac_outputs.clone())
+        .expect("Failed to convert Vec<AcOutput> into AcCapabilityList");
+    assert_eq!(expected_ac_outputs.as_slice(), ac_capability_list.as_ref());
+    assert_eq!(
+        Some(&expected_ac_outputs[1]),
+        ac_capability_list.find(AttachedComponentTag::Pv1)
+    );
+    assert_eq!(None, ac_capability_list.find(AttachedComponentTag::Error));
+
+    let tpml_ac_capabilities = TPML_AC_CAPABILITIES::from(ac_capability_list.clone());
+    assert_eq!(
+        expected_ac_outputs.len(),
+        tpml_ac_capabilities.count as usize
+    );
+
+    let ac_capability_list_from_tss = AcCapabilityList::try_from(tpml_ac_capabilities)
+        .expect("Failed to convert TPML_AC_CAPABILITIES into AcCapabilityList");
+    assert_eq!(ac_capability_list, ac_capability_list_from_tss);
+}
+
+#[test]
+fn test_invalid_conversions() {
+    assert_eq!(
+        Err(Error::WrapperError(WrapperErrorKind::InvalidParam)),
+        AcCapabilityList::try_from(vec![
+            AcOutput::new(AttachedComponentTag::Any, 0);
+            AcCapabilityList::MAX_SIZE + 1
+        ]),
+        "Converting a vector with to many elements into an AcCapabilityList did not produce the expected error",
+    );
+
+    let mut tpml_ac_capabilities = TPML_AC_CAPABILITIES {
+        count: AcCapabilityList::MAX_SIZE as u32 + 1,
+        acCapabilities: [TPMS_AC_OUTPUT::default(); 128],
+    };
+    assert_eq!(
+        Err(Error::WrapperError(WrapperErrorKind::InvalidParam)),
+        AcCapabilityList::try_from(tpml_ac_capabilities),
+        "Converting a TPML_AC_CAPABILITIES with an invalid count did not produce the expected error",
+    );
+
+    tpml_ac_capabilities.count = 1;
+    tpml_ac_capabilities.acCapabilities[0].tag = 3;
+    assert_eq!(
+        Err(Error::WrapperError(WrapperErrorKind::InvalidParam)),
+        AcCapabilityList::try_from(tpml_ac_capabilities),
+        "Converting a TPML_AC_CAPABILITIES with an invalid tag did not produce the expected error",
+    );
+}
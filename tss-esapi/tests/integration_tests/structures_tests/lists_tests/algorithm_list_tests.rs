@@ -0,0 +1,143 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::convert::TryFrom;
+use tss_esapi::{
+    constants::AlgorithmIdentifier,
+    structures::AlgorithmList,
+    tss2_esys::{TPM2_ALG_ID, TPML_ALG},
+    Error, WrapperErrorKind,
+};
+
+#[test]
+fn test_conversions() {
+    let expected_algorithms: Vec<AlgorithmIdentifier> = vec![
+        AlgorithmIdentifier::Sha256,
+        AlgorithmIdentifier::Rsa,
+        AlgorithmIdentifier::Ecc,
+    ];
+    let mut algorithm_list = AlgorithmList::new();
+    for algorithm in expected_algorithms.iter() {
+        algorithm_list
+            .add(*algorithm)
+            .expect("Failed to add algorithm to list");
+    }
+
+    assert_eq!(expected_algorithms.len(), algorithm_list.len());
+
+    expected_algorithms
+        .iter()
+        .zip(algorithm_list.as_ref().iter())
+        .for_each(|(expected, actual)| {
+            assert_eq!(
+                expected, actual,
+                "The created algorithm list did not contain the expected values"
+            );
+        });
+
+    let tpml_alg = TPML_ALG::from(algorithm_list);
+    assert_eq!(
+        expected_algorithms.len(),
+        tpml_alg.count as usize,
+        "The number of algorithms in the TPML_ALG is different than expected"
+    );
+
+    expected_algorithms
+        .iter()
+        .zip(tpml_alg.algorithms[..expected_algorithms.len()].iter())
+        .for_each(|(expected, actual)| {
+            assert_eq!(
+                TPM2_ALG_ID::from(*expected),
+                *actual,
+                "Got mismatch between expected FFI algorithm identifier and actual algorithm"
+            )
+        });
+
+    let algorithm_list =
+        AlgorithmList::try_from(tpml_alg).expect("Failed to convert from TPML_ALG");
+
+    assert_eq!(
+        expected_algorithms.len(),
+        algorithm_list.len(),
+        "Converted algorithm list has a different length"
+    );
+
+    expected_algorithms
+        .iter()
+        .zip(algorithm_list.as_ref().iter())
+        .for_each(|(expected, actual)| {
+            assert_eq!(
+                expected, actual,
+                "The converted algorithm list did not contain the expected values"
+            );
+        });
+}
+
+#[test]
+fn test_vector_conversion() {
+    let expected_algorithms: Vec<AlgorithmIdentifier> = vec![
+        AlgorithmIdentifier::Sha256,
+        AlgorithmIdentifier::Sha384,
+        AlgorithmIdentifier::Rsa,
+    ];
+
+    let algorithm_list = AlgorithmList::try_from(expected_algorithms.clone())
+        .expect("Failed to convert from vector");
+
+    expected_algorithms
+        .iter()
+        .zip(algorithm_list.as_ref().iter())
+        .for_each(|(expected, actual)| {
+            assert_eq!(
+                expected, actual,
+                "The converted algorithm list did not contain the expected values"
+            );
+        });
+
+    let converted_algorithms = Vec::<AlgorithmIdentifier>::from(algorithm_list);
+
+    assert_eq!(
+        expected_algorithms, converted_algorithms,
+        "Converted vector did not match initial vector"
+    );
+}
+
+#[test]
+fn test_add_too_many() {
+    let mut algorithm_list = AlgorithmList::new();
+    for _ in 0..AlgorithmList::MAX_SIZE {
+        algorithm_list
+            .add(AlgorithmIdentifier::Sha256)
+            .expect("Failed to add the maximum amount of algorithms");
+    }
+
+    assert_eq!(
+        Err(Error::WrapperError(WrapperErrorKind::WrongParamSize)),
+        algorithm_list.add(AlgorithmIdentifier::Sha256),
+        "Added more algorithms than should've been possible"
+    );
+}
+
+#[test]
+fn test_invalid_size_tpml() {
+    let tpml = TPML_ALG {
+        count: (AlgorithmList::MAX_SIZE + 1) as u32,
+        algorithms: [0; 128],
+    };
+
+    assert_eq!(
+        Err(Error::WrapperError(WrapperErrorKind::InvalidParam)),
+        AlgorithmList::try_from(tpml),
+        "Converting from TPML_ALG did not produce the expected failure"
+    );
+}
+
+#[test]
+fn test_invalid_size_vec() {
+    let vec = vec![AlgorithmIdentifier::Sha256; AlgorithmList::MAX_SIZE + 1];
+
+    assert_eq!(
+        Err(Error::WrapperError(WrapperErrorKind::InvalidParam)),
+        AlgorithmList::try_from(vec),
+        "Converting from vector of algorithms did not produce the expected failure"
+    );
+}
@@ -0,0 +1,49 @@
+// Copyright 2022 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::convert::TryFrom;
+use tss_esapi::{
+    constants::tss::TPM2_ALG_SHA256,
+    interface_types::algorithm::HashingAlgorithm,
+    structures::{Digest, DigestValues},
+    tss2_esys::TPML_DIGEST_VALUES,
+};
+
+#[test]
+fn test_conversions() {
+    let sha1_digest = Digest::try_from(vec![1; 20]).unwrap();
+    let sha256_digest = Digest::try_from(vec![2; 32]).unwrap();
+    let mut expected = DigestValues::new();
+    expected.set(HashingAlgorithm::Sha1, sha1_digest.clone());
+    expected.set(HashingAlgorithm::Sha256, sha256_digest.clone());
+
+    let tss_digest_values = TPML_DIGEST_VALUES::try_from(expected.clone()).unwrap();
+    assert_eq!(tss_digest_values.count, 2);
+
+    let actual = DigestValues::try_from(tss_digest_values).unwrap();
+    assert_eq!(expected, actual);
+    assert_eq!(actual.len(), 2);
+    assert_eq!(actual.get(HashingAlgorithm::Sha1), Some(&sha1_digest));
+    assert_eq!(actual.get(HashingAlgorithm::Sha256), Some(&sha256_digest));
+    assert_eq!(actual.get(HashingAlgorithm::Sha384), None);
+}
+
+#[test]
+fn test_conversion_from_tss_digest_values() {
+    let mut tss_digest_values: TPML_DIGEST_VALUES = Default::default();
+    tss_digest_values.digests[0].hashAlg = TPM2_ALG_SHA256;
+    tss_digest_values.digests[0].digest.sha256 = [0xaa; 32];
+    tss_digest_values.count = 1;
+
+    let digest_values = DigestValues::try_from(tss_digest_values).unwrap();
+    assert_eq!(digest_values.len(), 1);
+    assert!(digest_values.get(HashingAlgorithm::Sha256).is_some());
+}
+
+#[test]
+fn test_conversion_from_tss_digest_values_with_invalid_count() {
+    let tss_digest_values = TPML_DIGEST_VALUES {
+        count: 17,
+        ..Default::default()
+    };
+    let _ = DigestValues::try_from(tss_digest_values).unwrap_err();
+}
@@ -1,9 +1,12 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod ac_capability_list_tests;
+mod algorithm_list_tests;
 mod algorithm_property_list_tests;
 mod command_code_attributes_list_tests;
 mod command_code_list_tests;
 mod digest_list_tests;
+mod digest_values_tests;
 mod ecc_curve_list_tests;
 mod handle_list_tests;
 mod pcr_selection_list_builder_tests;
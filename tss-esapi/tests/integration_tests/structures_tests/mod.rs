@@ -1,5 +1,7 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod ac_output_tests;
+mod algorithm_detail_ecc_tests;
 mod algorithm_property_tests;
 mod algorithm_tests;
 mod attest_info_test;